@@ -0,0 +1,179 @@
+//! Derive macro for the `ApproxCompare` trait of [`numtest`](https://docs.rs/numtest).
+//!
+//! This crate should not be used directly. Instead, enable the `derive` feature of `numtest`,
+//! which re-exports the [`ApproxCompare`](macro@ApproxCompare) derive macro alongside the trait.
+
+// Linter setup.
+#![warn(missing_docs, warnings, clippy::all, clippy::pedantic, clippy::cargo)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Error, Expr, Index, parse_macro_input, parse_quote};
+
+/// Derives the `ApproxCompare` trait for a struct.
+///
+/// The derived implementation compares the fields one by one (in declaration order), naming each
+/// compared value by its path within the struct (e.g. `state.r[2]`). Every field must implement
+/// `ApproxCompare`, unless it is skipped.
+///
+/// # Field attributes
+///
+/// * `#[approx(skip)]` - Do not compare this field.
+/// * `#[approx(atol = ...)]`, `#[approx(rtol = ...)]`, `#[approx(decimal = ...)]`, or
+///   `#[approx(ulps = ...)]` - Compare this field (and all values nested within it) using this
+///   tolerance instead of the tolerance of the enclosing comparison.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// #[derive(ApproxCompare)]
+/// struct OrbitState {
+///     r: [f64; 3],
+///     v: [f64; 3],
+///     #[approx(atol = 1e-3)]
+///     t: f64,
+/// }
+///
+/// let state1 = OrbitState { r: [7000.0, 0.0, 0.0], v: [0.0, 7.5, 0.0], t: 60.0 };
+/// let state2 = OrbitState { r: [7000.0, 0.0, 0.0], v: [0.0, 7.5, 0.0], t: 60.0005 };
+/// assert_approx_eq!(state1, state2, atol = 1e-9);
+/// ```
+#[proc_macro_derive(ApproxCompare, attributes(approx))]
+pub fn derive_approx_compare(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Parsed `#[approx(...)]` attributes of a field.
+#[derive(Default)]
+struct FieldAttributes {
+    /// Whether the field is skipped.
+    skip: bool,
+
+    /// Tolerance overriding the tolerance of the enclosing comparison.
+    tolerance: Option<TokenStream2>,
+}
+
+impl FieldAttributes {
+    /// Parses the `#[approx(...)]` attributes of a field.
+    ///
+    /// # Arguments
+    ///
+    /// * `attrs` - Attributes of the field.
+    ///
+    /// # Returns
+    ///
+    /// Parsed attributes.
+    ///
+    /// # Errors
+    ///
+    /// If an attribute is not recognized, or if a field is both skipped and given a tolerance (or
+    /// given more than one tolerance).
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("approx")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    parsed.skip = true;
+                } else {
+                    let variant = match meta.path.get_ident().map(ToString::to_string) {
+                        Some(kind) if kind == "atol" => quote!(Atol),
+                        Some(kind) if kind == "rtol" => quote!(Rtol),
+                        Some(kind) if kind == "decimal" => quote!(Decimal),
+                        Some(kind) if kind == "ulps" => quote!(Ulps),
+                        _ => {
+                            return Err(meta.error(
+                                "expected `skip`, `atol = ...`, `rtol = ...`, `decimal = ...`, or \
+                                `ulps = ...`",
+                            ));
+                        }
+                    };
+                    if parsed.tolerance.is_some() {
+                        return Err(meta.error("a field can only have one tolerance"));
+                    }
+                    let value: Expr = meta.value()?.parse()?;
+                    parsed.tolerance = Some(quote!(::numtest::Tolerance::#variant(#value)));
+                }
+                if parsed.skip && parsed.tolerance.is_some() {
+                    return Err(meta.error("a skipped field cannot have a tolerance"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(parsed)
+    }
+}
+
+/// Expands the `ApproxCompare` derive macro.
+///
+/// # Arguments
+///
+/// * `input` - Struct the trait is derived for.
+///
+/// # Returns
+///
+/// Implementation of the `ApproxCompare` trait.
+///
+/// # Errors
+///
+/// If the input is not a struct, or if any of its fields has invalid `#[approx(...)]` attributes.
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "ApproxCompare can only be derived for structs",
+        ));
+    };
+
+    // Compare each field that is not skipped.
+    let mut comparisons = Vec::new();
+    for (position, field) in data.fields.iter().enumerate() {
+        let attributes = FieldAttributes::parse(&field.attrs)?;
+        if attributes.skip {
+            continue;
+        }
+        let (member, name) = if let Some(ident) = &field.ident {
+            (quote!(#ident), ident.to_string())
+        } else {
+            let index = Index::from(position);
+            (quote!(#index), position.to_string())
+        };
+        let tolerance = attributes.tolerance.unwrap_or_else(|| quote!(tolerance));
+        comparisons.push(quote! {
+            ::numtest::ApproxCompare::approx_compare(
+                &self.#member,
+                &other.#member,
+                #tolerance,
+                &::numtest::field_path(path, #name),
+                comparison,
+            );
+        });
+    }
+
+    // Require every type parameter to implement ApproxCompare.
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::numtest::ApproxCompare));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ident = &input.ident;
+    Ok(quote! {
+        impl #impl_generics ::numtest::ApproxCompare for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn approx_compare(
+                &self,
+                other: &Self,
+                tolerance: ::numtest::Tolerance,
+                path: &str,
+                comparison: &mut ::numtest::FieldComparison,
+            ) {
+                #(#comparisons)*
+            }
+        }
+    })
+}
@@ -0,0 +1,983 @@
+use crate::compare::{Compare, real_to_f64};
+use crate::limit::report_limit;
+use crate::report::{ComparisonReport, format_metric};
+#[cfg(any(feature = "ndarray", feature = "nalgebra"))]
+use crate::shape::{ArrayShape, Layout};
+use num_traits::{Float, NumCast};
+use std::fmt::{Display, LowerExp, Write};
+
+/// Tolerance used by [`ApproxCompare`] to compare the floats stored in a struct.
+///
+/// # Example
+///
+/// ```
+/// use numtest::Tolerance;
+///
+/// assert_eq!(Tolerance::Rtol(1e-3).to_string(), "rtol = 0.001");
+/// assert_eq!(Tolerance::Decimal(3).to_string(), "decimal = 3");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// Absolute tolerance (see [`Compare::is_equal_to_atol`]).
+    Atol(f64),
+
+    /// Relative tolerance (see [`Compare::is_equal_to_rtol`]).
+    Rtol(f64),
+
+    /// Number of decimal places (see [`Compare::is_equal_to_decimal`]).
+    Decimal(i32),
+
+    /// Maximum number of units in the last place (see [`Compare::is_equal_to_ulps`]).
+    Ulps(u64),
+}
+
+impl Display for Tolerance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tolerance::Atol(atol) => write!(f, "atol = {atol}"),
+            Tolerance::Rtol(rtol) => write!(f, "rtol = {rtol}"),
+            Tolerance::Decimal(decimal) => write!(f, "decimal = {decimal}"),
+            Tolerance::Ulps(ulps) => write!(f, "ulps = {ulps}"),
+        }
+    }
+}
+
+impl Tolerance {
+    /// Compares two floats (or complex numbers) to within this tolerance.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - First value.
+    /// * `b` - Second value.
+    ///
+    /// # Returns
+    ///
+    /// Tuple:
+    ///
+    /// 1. `true` if the two values are equal to within this tolerance, `false` otherwise.
+    /// 2. Value of the comparison metric (see [`FieldMismatch::difference`]).
+    pub(crate) fn compare<T: Compare + Copy>(self, a: T, b: T) -> (bool, f64) {
+        let to_real = |tol: f64| <T::Real as NumCast>::from(tol).unwrap_or_else(T::Real::nan);
+        match self {
+            Tolerance::Atol(atol) => {
+                let (passed, abs_diff) = a.is_equal_to_atol(b, to_real(atol));
+                (passed, real_to_f64(abs_diff))
+            }
+            Tolerance::Rtol(rtol) => {
+                let (passed, rel_diff) = a.is_equal_to_rtol(b, to_real(rtol));
+                (passed, real_to_f64(rel_diff))
+            }
+            Tolerance::Decimal(decimal) => {
+                let (passed, precision) = a.is_equal_to_decimal(b, decimal);
+                (passed, precision.into())
+            }
+            Tolerance::Ulps(max_ulps) => {
+                let (passed, ulps) = a.is_equal_to_ulps(b, max_ulps);
+                #[allow(clippy::cast_precision_loss)]
+                let ulps = ulps as f64;
+                (passed, ulps)
+            }
+        }
+    }
+}
+
+/// A value (or pair of containers) that failed an [`ApproxCompare`] comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldMismatch {
+    /// Path of the value within the compared structs (e.g. `state.r[2]`).
+    pub path: String,
+
+    /// Value in the first struct (or a description of it, e.g. `len 3`).
+    pub a: String,
+
+    /// Value in the second struct (or a description of it, e.g. `len 4`).
+    pub b: String,
+
+    /// Tolerance used to compare the value (`None` for exact and structural comparisons, e.g.
+    /// containers of different lengths).
+    pub tolerance: Option<Tolerance>,
+
+    /// Value of the comparison metric (`NaN` for exact and structural comparisons). This is the
+    /// absolute difference for absolute tolerances, the relative difference for relative
+    /// tolerances, the decimal precision for decimal comparisons, and the ULP distance for ULP
+    /// comparisons.
+    pub difference: f64,
+}
+
+/// Error describing a failed [`ApproxCompare`] comparison.
+///
+/// The [`Display`] implementation of this error prints the exact message that
+/// [`assert_approx_eq`](crate::assert_approx_eq) panics with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldComparisonError {
+    /// Number of values that were compared.
+    pub num_values: usize,
+
+    /// Values that failed the comparison.
+    pub mismatches: Vec<FieldMismatch>,
+
+    /// Full failure message.
+    pub message: String,
+}
+
+impl Display for FieldComparisonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for FieldComparisonError {}
+
+/// Recursive comparison of two structs, recording the values that failed.
+///
+/// This is passed through the [`ApproxCompare::approx_compare`] calls, which record each compared
+/// value with [`FieldComparison::compare_value`], [`FieldComparison::compare_exact`], or
+/// [`FieldComparison::record_mismatch`].
+#[derive(Debug)]
+pub struct FieldComparison {
+    /// Tolerance of the top-level comparison.
+    tolerance: Tolerance,
+
+    /// Number of values that were compared.
+    num_values: usize,
+
+    /// Worst value of the comparison metric over the values compared using the same kind of
+    /// tolerance as the top-level comparison.
+    worst_difference: f64,
+
+    /// Values that failed the comparison.
+    mismatches: Vec<FieldMismatch>,
+}
+
+/// Joins the path of a struct with the name of one of its fields.
+///
+/// # Arguments
+///
+/// * `path` - Path of the struct (empty for the top-level struct).
+/// * `field` - Name (or position, for tuple structs) of the field.
+///
+/// # Returns
+///
+/// Path of the field.
+#[doc(hidden)]
+#[must_use]
+pub fn field_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+impl FieldComparison {
+    /// Creates a new comparison.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - Tolerance of the top-level comparison.
+    ///
+    /// # Returns
+    ///
+    /// Comparison with no recorded values.
+    #[must_use]
+    pub fn new(tolerance: Tolerance) -> Self {
+        Self {
+            tolerance,
+            num_values: 0,
+            worst_difference: match tolerance {
+                Tolerance::Decimal(_) => f64::INFINITY,
+                _ => 0.0,
+            },
+            mismatches: Vec::new(),
+        }
+    }
+
+    /// Compares two floats (or complex numbers) to within a tolerance, recording a mismatch if
+    /// they are not equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the value within the compared structs.
+    /// * `a` - Value in the first struct.
+    /// * `b` - Value in the second struct.
+    /// * `tolerance` - Tolerance.
+    pub fn compare_value<T: Compare + Copy + LowerExp>(
+        &mut self,
+        path: &str,
+        a: T,
+        b: T,
+        tolerance: Tolerance,
+    ) {
+        let (passed, difference) = tolerance.compare(a, b);
+
+        // Track the worst difference over the values compared using the same kind of tolerance as
+        // the top-level comparison.
+        self.num_values += 1;
+        match (self.tolerance, tolerance) {
+            (Tolerance::Decimal(_), Tolerance::Decimal(_)) => {
+                self.worst_difference = self.worst_difference.min(difference);
+            }
+            (Tolerance::Atol(_), Tolerance::Atol(_))
+            | (Tolerance::Rtol(_), Tolerance::Rtol(_))
+            | (Tolerance::Ulps(_), Tolerance::Ulps(_)) => {
+                self.worst_difference = self.worst_difference.max(difference);
+            }
+            _ => {}
+        }
+        if !passed {
+            self.mismatches.push(FieldMismatch {
+                path: path.to_string(),
+                a: format!("{a:e}"),
+                b: format!("{b:e}"),
+                tolerance: Some(tolerance),
+                difference,
+            });
+        }
+    }
+
+    /// Compares two values exactly (e.g. integers or booleans), recording a mismatch if they are
+    /// not equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the value within the compared structs.
+    /// * `a` - Value in the first struct.
+    /// * `b` - Value in the second struct.
+    pub fn compare_exact<T: PartialEq + Display>(&mut self, path: &str, a: &T, b: &T) {
+        if a == b {
+            self.num_values += 1;
+        } else {
+            self.record_mismatch(path, a.to_string(), b.to_string());
+        }
+    }
+
+    /// Records a structural mismatch (e.g. containers of different lengths).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the mismatched value within the compared structs.
+    /// * `a` - Description of the value in the first struct.
+    /// * `b` - Description of the value in the second struct.
+    pub fn record_mismatch(&mut self, path: &str, a: String, b: String) {
+        self.num_values += 1;
+        self.mismatches.push(FieldMismatch {
+            path: path.to_string(),
+            a,
+            b,
+            tolerance: None,
+            difference: f64::NAN,
+        });
+    }
+
+    /// Finishes the comparison.
+    ///
+    /// # Returns
+    ///
+    /// Report summarizing the comparison. The worst difference is taken over the values compared
+    /// using the same kind of tolerance as the top-level comparison.
+    ///
+    /// # Errors
+    ///
+    /// If any of the values failed the comparison.
+    pub fn finish(self) -> Result<ComparisonReport, FieldComparisonError> {
+        if self.mismatches.is_empty() {
+            return Ok(ComparisonReport {
+                num_elements: self.num_values,
+                worst_difference: self.worst_difference,
+            });
+        }
+        let message = format!(
+            "\nThe two values are not approximately equal ({}).\n --> Mismatched Values: {}/{}\n\n{}",
+            self.tolerance,
+            self.mismatches.len(),
+            self.num_values,
+            mismatched_fields_str(&self.mismatches)
+        );
+        Err(FieldComparisonError {
+            num_values: self.num_values,
+            mismatches: self.mismatches,
+            message,
+        })
+    }
+}
+
+/// Get a string that can be used to print the values that failed an [`ApproxCompare`]
+/// comparison.
+///
+/// # Arguments
+///
+/// * `mismatches` - Values that failed the comparison.
+///
+/// # Returns
+///
+/// Table printing the path, the two values, the tolerance, and the difference of each mismatched
+/// value (only the first mismatched values are printed if there are more than the report limit,
+/// see [`set_report_limit`](crate::set_report_limit)).
+fn mismatched_fields_str(mismatches: &[FieldMismatch]) -> String {
+    let num_shown = report_limit().map_or(mismatches.len(), |limit| limit.min(mismatches.len()));
+    let rows: Vec<[String; 5]> = mismatches[..num_shown]
+        .iter()
+        .map(|mismatch| {
+            let (tolerance, difference) = match mismatch.tolerance {
+                Some(tolerance) => (tolerance.to_string(), format_metric(mismatch.difference)),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            [
+                mismatch.path.clone(),
+                mismatch.a.clone(),
+                mismatch.b.clone(),
+                tolerance,
+                difference,
+            ]
+        })
+        .collect();
+
+    // Width of each column.
+    let headers = ["field", "a", "b", "tolerance", "difference"];
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].len())
+                .chain(std::iter::once(headers[column].len()))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    // Formats a line of the table (the path is left-aligned and all other columns are
+    // right-aligned).
+    let line = |cells: &[&str]| -> String {
+        let mut line = format!("{:<width$}", cells[0], width = widths[0]);
+        for (cell, &width) in cells[1..].iter().zip(&widths[1..]) {
+            let _ = write!(line, "  {cell:>width$}");
+        }
+        line
+    };
+
+    // Add the header and the mismatched values.
+    let mut output = String::new();
+    let underlines: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+    let underlines: Vec<&str> = underlines.iter().map(String::as_str).collect();
+    let _ = writeln!(output, "{}", line(&headers));
+    let _ = writeln!(output, "{}", line(&underlines));
+    for row in &rows {
+        let cells: Vec<&str> = row.iter().map(String::as_str).collect();
+        let _ = writeln!(output, "{}", line(&cells));
+    }
+    if num_shown < mismatches.len() {
+        let _ = writeln!(output, "... ({} more)", mismatches.len() - num_shown);
+    }
+    output
+}
+
+/// Trait for recursively comparing structs of floats to within a tolerance.
+///
+/// This trait can be derived (requires the `derive` feature) for structs whose fields implement
+/// it. The derived implementation compares the fields one by one, naming each compared value by
+/// its path within the struct (e.g. `state.r[2]`), so that failure reports identify exactly which
+/// values differ. Fields can be annotated with `#[approx(skip)]` to skip them, or with
+/// `#[approx(atol = ...)]`, `#[approx(rtol = ...)]`, `#[approx(decimal = ...)]`, or
+/// `#[approx(ulps = ...)]` to compare them (and all values nested within them) using a different
+/// tolerance.
+///
+/// This trait is implemented for:
+///
+/// * [`f32`] and [`f64`] (and `half::f16`, `half::bf16`, and `num_complex::Complex` numbers when
+///   the `half` and `num-complex` features are enabled), compared using the [`Compare`] method
+///   corresponding to the [`Tolerance`]
+/// * integers and [`bool`], compared exactly
+/// * `[T; N]`, `[T]`, `Vec<T>`, `Option<T>`, `Box<T>`, and `&T`
+/// * [`ndarray::ArrayBase`](https://docs.rs/ndarray/latest/ndarray/struct.ArrayBase.html) and
+///   [`nalgebra::Matrix`](https://docs.rs/nalgebra/latest/nalgebra/base/struct.Matrix.html)
+///   (requires the `ndarray` and `nalgebra` features)
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use numtest::*;
+///
+/// #[derive(ApproxCompare)]
+/// struct OrbitState {
+///     r: [f64; 3],
+///     v: [f64; 3],
+///     #[approx(atol = 1e-3)]
+///     t: f64,
+///     #[approx(skip)]
+///     label: String,
+/// }
+///
+/// let state = OrbitState {
+///     r: [7000.0, 0.0, 0.0],
+///     v: [0.0, 7.5, 0.0],
+///     t: 10.0,
+///     label: "computed".to_string(),
+/// };
+/// let expected = OrbitState {
+///     r: [7000.0, 0.0, 0.0],
+///     v: [0.0, 7.6, 0.0],
+///     t: 10.0001,
+///     label: "reference".to_string(),
+/// };
+///
+/// let error = check_approx_eq!(state, expected, rtol = 1e-6).unwrap_err();
+/// assert_eq!(error.mismatches.len(), 1);
+/// assert_eq!(error.mismatches[0].path, "v[1]");
+/// # }
+/// ```
+pub trait ApproxCompare {
+    /// Compares `self` with `other` to within a tolerance, recording every value that fails the
+    /// comparison.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Value to compare against.
+    /// * `tolerance` - Tolerance (unless overridden for nested values).
+    /// * `path` - Path of `self` within the top-level struct (empty for the top-level struct).
+    /// * `comparison` - Comparison recording the compared values.
+    fn approx_compare(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+        path: &str,
+        comparison: &mut FieldComparison,
+    );
+
+    /// Compares `self` with `other` to within a tolerance.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Value to compare against.
+    /// * `tolerance` - Tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A [`ComparisonReport`] if all values are equal to within the tolerance.
+    ///
+    /// # Errors
+    ///
+    /// A [`FieldComparisonError`] naming the path of every value that failed the comparison.
+    fn check_approx_eq(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+    ) -> Result<ComparisonReport, FieldComparisonError> {
+        let mut comparison = FieldComparison::new(tolerance);
+        self.approx_compare(other, tolerance, "", &mut comparison);
+        comparison.finish()
+    }
+}
+
+/// Name used for the top-level value when it is compared directly (e.g. when comparing two
+/// floats).
+///
+/// # Arguments
+///
+/// * `path` - Path of the value.
+///
+/// # Returns
+///
+/// `path`, or `value` if `path` is empty.
+fn value_path(path: &str) -> &str {
+    if path.is_empty() { "value" } else { path }
+}
+
+// Implementing ApproxCompare trait for floats.
+macro_rules! impl_approx_compare_float {
+    ($($t:ty),*) => {$(
+        impl ApproxCompare for $t {
+            fn approx_compare(
+                &self,
+                other: &Self,
+                tolerance: Tolerance,
+                path: &str,
+                comparison: &mut FieldComparison,
+            ) {
+                comparison.compare_value(value_path(path), *self, *other, tolerance);
+            }
+        }
+    )*};
+}
+impl_approx_compare_float!(f32, f64);
+#[cfg(feature = "half")]
+impl_approx_compare_float!(half::f16, half::bf16);
+
+// Implementing ApproxCompare trait for complex numbers.
+#[cfg(feature = "num-complex")]
+impl<T> ApproxCompare for num_complex::Complex<T>
+where
+    num_complex::Complex<T>: Compare + Copy + LowerExp,
+{
+    fn approx_compare(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+        path: &str,
+        comparison: &mut FieldComparison,
+    ) {
+        comparison.compare_value(value_path(path), *self, *other, tolerance);
+    }
+}
+
+// Implementing ApproxCompare trait for integers and booleans.
+macro_rules! impl_approx_compare_exact {
+    ($($t:ty),*) => {$(
+        impl ApproxCompare for $t {
+            fn approx_compare(
+                &self,
+                other: &Self,
+                _tolerance: Tolerance,
+                path: &str,
+                comparison: &mut FieldComparison,
+            ) {
+                comparison.compare_exact(value_path(path), self, other);
+            }
+        }
+    )*};
+}
+impl_approx_compare_exact!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool
+);
+
+// Implementing ApproxCompare trait for slices.
+impl<T: ApproxCompare> ApproxCompare for [T] {
+    fn approx_compare(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+        path: &str,
+        comparison: &mut FieldComparison,
+    ) {
+        if self.len() != other.len() {
+            comparison.record_mismatch(
+                value_path(path),
+                format!("len {}", self.len()),
+                format!("len {}", other.len()),
+            );
+            return;
+        }
+        for (index, (a, b)) in self.iter().zip(other).enumerate() {
+            a.approx_compare(b, tolerance, &format!("{path}[{index}]"), comparison);
+        }
+    }
+}
+
+// Implementing ApproxCompare trait for std arrays.
+impl<T: ApproxCompare, const N: usize> ApproxCompare for [T; N] {
+    fn approx_compare(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+        path: &str,
+        comparison: &mut FieldComparison,
+    ) {
+        self.as_slice()
+            .approx_compare(other.as_slice(), tolerance, path, comparison);
+    }
+}
+
+// Implementing ApproxCompare trait for std vectors.
+impl<T: ApproxCompare> ApproxCompare for Vec<T> {
+    fn approx_compare(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+        path: &str,
+        comparison: &mut FieldComparison,
+    ) {
+        self.as_slice()
+            .approx_compare(other.as_slice(), tolerance, path, comparison);
+    }
+}
+
+// Implementing ApproxCompare trait for options.
+impl<T: ApproxCompare> ApproxCompare for Option<T> {
+    fn approx_compare(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+        path: &str,
+        comparison: &mut FieldComparison,
+    ) {
+        match (self, other) {
+            (Some(a), Some(b)) => a.approx_compare(b, tolerance, path, comparison),
+            (None, None) => {}
+            (a, b) => {
+                let describe =
+                    |value: &Option<T>| if value.is_some() { "Some" } else { "None" }.to_string();
+                comparison.record_mismatch(value_path(path), describe(a), describe(b));
+            }
+        }
+    }
+}
+
+// Implementing ApproxCompare trait for boxes.
+impl<T: ApproxCompare + ?Sized> ApproxCompare for Box<T> {
+    fn approx_compare(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+        path: &str,
+        comparison: &mut FieldComparison,
+    ) {
+        (**self).approx_compare(other, tolerance, path, comparison);
+    }
+}
+
+// Implementing ApproxCompare trait for references.
+impl<T: ApproxCompare + ?Sized> ApproxCompare for &T {
+    fn approx_compare(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+        path: &str,
+        comparison: &mut FieldComparison,
+    ) {
+        (**self).approx_compare(other, tolerance, path, comparison);
+    }
+}
+
+/// Compares two array-like structs that know their own shape element-wise.
+///
+/// # Arguments
+///
+/// * `a` - First array-like struct.
+/// * `b` - Second array-like struct.
+/// * `tolerance` - Tolerance.
+/// * `path` - Path of the array-like structs within the compared structs.
+/// * `comparison` - Comparison recording the compared values.
+#[cfg(any(feature = "ndarray", feature = "nalgebra"))]
+fn approx_compare_shaped<A>(
+    a: &A,
+    b: &A,
+    tolerance: Tolerance,
+    path: &str,
+    comparison: &mut FieldComparison,
+) where
+    A: ArrayShape,
+    A::Element: ApproxCompare,
+{
+    let layout = Layout {
+        shape: a.shape(),
+        column_major: a.is_column_major(),
+    };
+    if layout.shape != b.shape() {
+        comparison.record_mismatch(
+            value_path(path),
+            format!("shape {:?}", layout.shape),
+            format!("shape {:?}", b.shape()),
+        );
+        return;
+    }
+    for (index, (a, b)) in a.elements().zip(b.elements()).enumerate() {
+        let element_path = if layout.is_multi_dimensional() {
+            let multi_index: Vec<String> = layout
+                .multi_index(index)
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            format!("{path}[{}]", multi_index.join(", "))
+        } else {
+            format!("{path}[{index}]")
+        };
+        a.approx_compare(b, tolerance, &element_path, comparison);
+    }
+}
+
+// Implementing ApproxCompare trait for ndarray arrays.
+#[cfg(feature = "ndarray")]
+impl<S, D> ApproxCompare for ndarray::ArrayBase<S, D>
+where
+    S: ndarray::Data,
+    S::Elem: ApproxCompare,
+    D: ndarray::Dimension,
+{
+    fn approx_compare(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+        path: &str,
+        comparison: &mut FieldComparison,
+    ) {
+        approx_compare_shaped(self, other, tolerance, path, comparison);
+    }
+}
+
+// Implementing ApproxCompare trait for nalgebra matrices.
+#[cfg(feature = "nalgebra")]
+impl<T, R, C, S> ApproxCompare for nalgebra::Matrix<T, R, C, S>
+where
+    T: nalgebra::Scalar + ApproxCompare,
+    R: nalgebra::Dim,
+    C: nalgebra::Dim,
+    S: nalgebra::RawStorage<T, R, C>,
+{
+    fn approx_compare(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+        path: &str,
+        comparison: &mut FieldComparison,
+    ) {
+        approx_compare_shaped(self, other, tolerance, path, comparison);
+    }
+}
+
+/// Maps a tolerance kind to a [`Tolerance`] (used by the [`ApproxCompare`] macros).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tolerance {
+    (atol, $tol:expr) => {
+        $crate::Tolerance::Atol($tol)
+    };
+    (rtol, $tol:expr) => {
+        $crate::Tolerance::Rtol($tol)
+    };
+    (decimal, $tol:expr) => {
+        $crate::Tolerance::Decimal($tol)
+    };
+    (ulps, $tol:expr) => {
+        $crate::Tolerance::Ulps($tol)
+    };
+}
+
+/// Checks approximate equality of two structs implementing [`ApproxCompare`].
+///
+/// This macro is the non-panicking counterpart of [`assert_approx_eq`](crate::assert_approx_eq).
+///
+/// # Arguments
+///
+/// * `a` - First struct to compare.
+/// * `b` - Second struct to compare against.
+/// * `kind = tol` - Tolerance, where `kind` is one of `atol`, `rtol`, `decimal`, or `ulps` (see
+///   [`Tolerance`](crate::Tolerance)).
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if all values are equal to within the
+/// tolerance.
+///
+/// # Errors
+///
+/// A [`FieldComparisonError`](crate::FieldComparisonError) whose message is the message that
+/// [`assert_approx_eq`](crate::assert_approx_eq) panics with, naming the path of every value that
+/// failed the comparison.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let a = vec![Some(1.0), None, Some(3.0)];
+/// let b = vec![Some(1.0), None, Some(3.1)];
+/// let error = check_approx_eq!(a, b, atol = 1e-3).unwrap_err();
+/// assert_eq!(error.mismatches[0].path, "[2]");
+/// ```
+#[macro_export]
+macro_rules! check_approx_eq {
+    ($a:expr, $b:expr, $kind:ident = $tol:expr) => {
+        $crate::ApproxCompare::check_approx_eq(&$a, &$b, $crate::__tolerance!($kind, $tol))
+    };
+}
+
+/// Asserts approximate equality of two structs implementing [`ApproxCompare`].
+///
+/// # Arguments
+///
+/// * `a` - First struct to compare.
+/// * `b` - Second struct to compare against.
+/// * `kind = tol` - Tolerance, where `kind` is one of `atol`, `rtol`, `decimal`, or `ulps` (see
+///   [`Tolerance`](crate::Tolerance)).
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// If any of the values stored in the two structs are not equal to within the tolerance (or if
+/// their containers do not match, e.g. vectors of different lengths). In this case, the path,
+/// values, tolerance, and difference of every mismatched value are printed.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// assert_approx_eq!([1.0, 2.0], [1.0, 2.0 + 1e-12], rtol = 1e-10);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// assert_approx_eq!((vec![1.0, 2.0]), vec![1.0, 2.1], decimal = 3, "case {}", 1);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr, $kind:ident = $tol:expr) => {{
+        if let Err(error) = $crate::check_approx_eq!($a, $b, $kind = $tol) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $b:expr, $kind:ident = $tol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_approx_eq!($a, $b, $kind = $tol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approx_compare_floats() {
+        let report = check_approx_eq!(1.0, 1.0 + 1e-12, rtol = 1e-10).unwrap();
+        assert_eq!(report.num_elements, 1);
+        let error = check_approx_eq!(1.0_f32, 1.1, atol = 1e-3).unwrap_err();
+        assert_eq!(error.mismatches[0].path, "value");
+        assert_eq!(error.mismatches[0].tolerance, Some(Tolerance::Atol(1e-3)));
+    }
+
+    #[test]
+    fn approx_compare_containers() {
+        let a = vec![vec![1.0, 2.0], vec![3.0]];
+        let b = vec![vec![1.0, 2.5], vec![3.0, 4.0]];
+        let error = check_approx_eq!(a, b, ulps = 4).unwrap_err();
+        assert_eq!(error.num_values, 3);
+        let paths: Vec<&str> = error.mismatches.iter().map(|m| m.path.as_str()).collect();
+        assert_eq!(paths, vec!["[0][1]", "[1]"]);
+        assert_eq!(error.mismatches[1].a, "len 1");
+        assert!(check_approx_eq!(Some(1.0), None, atol = 1.0).is_err());
+        assert!(check_approx_eq!([1_usize, 2], [1, 2], atol = 0.0).is_ok());
+    }
+
+    #[test]
+    fn approx_compare_worst_difference() {
+        let report = check_approx_eq!([1.0, 2.0], [1.0, 2.02], decimal = 1).unwrap();
+        assert_eq!(report.worst_difference, 1.0);
+        let report = check_approx_eq!([1.0, 2.0], [1.5, 2.0], atol = 1.0).unwrap();
+        assert_eq!(report.worst_difference, 0.5);
+    }
+
+    #[test]
+    #[cfg(feature = "nalgebra")]
+    fn approx_compare_nalgebra() {
+        let a = nalgebra::Matrix2::new(1.0, 2.0, 3.0, 4.0);
+        let b = nalgebra::Matrix2::new(1.0, 2.0, 3.5, 4.0);
+        let error = check_approx_eq!(a, b, atol = 1e-6).unwrap_err();
+        assert_eq!(error.mismatches[0].path, "[1, 0]");
+        let a = nalgebra::Vector3::new(1.0, 2.0, 3.0);
+        let b = nalgebra::Vector3::new(1.0, 2.0, 3.5);
+        let error = check_approx_eq!(a, b, atol = 1e-6).unwrap_err();
+        assert_eq!(error.mismatches[0].path, "[2]");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe two values are not approximately equal (rtol = 0.001).\n --> Mismatched Values: 1/1\n\nfield      a      b  tolerance  difference\n-----  -----  -----  ---------  ----------\nvalue  len 3  len 4          -           -\n"
+    )]
+    fn assert_approx_eq_fail() {
+        assert_approx_eq!(vec![1.0, 2.0, 3.0], vec![1.0, 2.5, 3.0, 4.0], rtol = 1e-3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe two values are not approximately equal (rtol = 0.001).\n --> Mismatched Values: 1/4\n\nfield     a      b     tolerance  difference\n------  ---  -----  ------------  ----------\n[1][1]  4e0  4.4e0  rtol = 0.001    9.091e-2\n"
+    )]
+    fn assert_approx_eq_fail_nested() {
+        let a = [Some([1.0, 2.0]), Some([3.0, 4.0])];
+        let b = [Some([1.0, 2.0]), Some([3.0, 4.4])];
+        assert_approx_eq!(a, b, rtol = 1e-3);
+    }
+
+    #[cfg(feature = "derive")]
+    mod derive {
+        use crate::*;
+
+        #[derive(ApproxCompare)]
+        struct Body {
+            mass: f64,
+            #[approx(atol = 1e-3)]
+            position: [f64; 2],
+        }
+
+        #[derive(ApproxCompare)]
+        struct System {
+            bodies: Vec<Body>,
+            time: Option<f64>,
+            #[approx(skip)]
+            #[allow(dead_code)]
+            name: String,
+            step: usize,
+        }
+
+        #[derive(ApproxCompare)]
+        struct Pair<T>(T, #[approx(decimal = 2)] T);
+
+        fn system(mass: f64, x: f64, time: Option<f64>) -> System {
+            System {
+                bodies: vec![
+                    Body {
+                        mass: 1.0,
+                        position: [0.0, 0.0],
+                    },
+                    Body {
+                        mass,
+                        position: [x, 1.0],
+                    },
+                ],
+                time,
+                name: format!("{mass}"),
+                step: 3,
+            }
+        }
+
+        #[test]
+        fn derive_pass() {
+            let report = check_approx_eq!(
+                system(2.0, 1.0, Some(1.0)),
+                system(2.0 + 1e-12, 1.0005, Some(1.0)),
+                rtol = 1e-10
+            )
+            .unwrap();
+            assert_eq!(report.num_elements, 8);
+        }
+
+        #[test]
+        fn derive_field_paths() {
+            let error = check_approx_eq!(
+                system(2.0, 1.0, Some(1.0)),
+                system(2.1, 1.01, None),
+                rtol = 1e-10
+            )
+            .unwrap_err();
+            let paths: Vec<&str> = error.mismatches.iter().map(|m| m.path.as_str()).collect();
+            assert_eq!(
+                paths,
+                vec!["bodies[1].mass", "bodies[1].position[0]", "time"]
+            );
+            assert_eq!(error.mismatches[1].tolerance, Some(Tolerance::Atol(1e-3)));
+        }
+
+        #[test]
+        fn derive_tuple_struct() {
+            let error =
+                check_approx_eq!(Pair(1.0, 1.0), Pair(1.001, 1.05), rtol = 1e-1).unwrap_err();
+            assert_eq!(error.mismatches.len(), 1);
+            assert_eq!(error.mismatches[0].path, "1");
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "step 3\nThe two values are not approximately equal (rtol = 0.000001).\n --> Mismatched Values: 1/8\n\nfield                    a      b     tolerance  difference\n---------------------  ---  -----  ------------  ----------\nbodies[1].position[0]  1e0  1.5e0  atol = 0.001        5e-1\n"
+        )]
+        fn derive_assert_fail() {
+            assert_approx_eq!(
+                system(2.0, 1.0, Some(1.0)),
+                system(2.0, 1.5, Some(1.0)),
+                rtol = 1e-6,
+                "step {}",
+                3
+            );
+        }
+    }
+}
@@ -1,83 +1,101 @@
 #[allow(unused_imports)]
 use crate::compare::Compare;
 
-/// Counts the number of elements in two array-like structs.
-///
-/// # Arguments
-///
-/// * `arr1` - First array-like struct to count elements of.
-/// * `arr2` - Second array-like struct to count elements of.
+/// Get a string that can be used to print the mismatched elements between two array-like structs.
 ///
-/// # Returns
+/// The table has an index column, columns for the elements of both array-like structs, columns
+/// for their absolute and relative differences, and a column marking each pair of elements as
+/// passed or failed.
 ///
-/// Tuple:
+/// # Arguments
 ///
-/// 1. Number of elements in `arr1`.
-/// 2. Number of elements in `arr2`.
+/// * `arr1` - The first array-like struct.
+/// * `arr2` - The second array-like struct.
+/// * `idx_mismatched` - Vector of indices where mismatches occur.
+/// * `output` - A mutable string to which the formatted output will be appended.
 #[macro_export]
-macro_rules! count_elements {
-    ($arr1:expr, $arr2:expr) => {
-        ($arr1.iter().count(), $arr2.iter().count())
+macro_rules! get_mismatched_elements_str {
+    ($arr1:expr, $arr2:expr, $idx_mismatched:expr, $output:expr) => {
+        let pairs: Vec<_> = $arr1
+            .iter()
+            .zip($arr2.iter())
+            .map(|(a, b)| (*a, *b))
+            .collect();
+        let idx_mismatched: std::collections::HashSet<usize> =
+            $idx_mismatched.iter().copied().collect();
+        let is_mismatched: Vec<bool> = (0..pairs.len())
+            .map(|idx| idx_mismatched.contains(&idx))
+            .collect();
+        $output.push_str(&$crate::mismatched_elements_str(&pairs, &is_mismatched));
     };
 }
 
-/// Validate that two array-like structs have the same number of elements.
+/// Checks element-wise exact equality of two array-like structs.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_equal`](crate::assert_arrays_equal).
 ///
 /// # Arguments
 ///
-/// * `count1` - Number of elements in the first array-like struct.
-/// * `count2` - Number of elements in the second array-like struct.
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+///
+/// # Returns
 ///
-/// # Panics
+/// A [`ComparisonReport`](crate::ComparisonReport) if all element-wise comparisons pass.
 ///
-/// If the two array-like structs do not have the same number of elements. In this case, the number
-/// of elements in each array-like struct is also printed.
-#[macro_export]
-macro_rules! validate_counts {
-    ($count1:expr, $count2:expr) => {
-        if $count1 != $count2 {
-            panic!(
-                "\nThe two arrays must have the same number of elements.\n --> arr1 num elements\
-                : {}\n --> arr2 num elements: {}\n",
-                $count1, $count2
-            );
-        }
-    };
-}
-
-/// Get a string that can be used to print the mismatched elements between two array-like structs.
+/// # Errors
 ///
-/// # Arguments
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, or if any of the element-wise comparisons fail. The error carries
+/// the indices and values of the mismatched elements, along with the per-element differences.
 ///
-/// * `arr1` - The first array-like struct.
-/// * `arr2` - The second array-like struct.
-/// * `idx_mismatched` - Vector of indices where mismatches occur.
-/// * `output` - A mutable string to which the formatted output will be appended.
+/// # Note
+///
+/// See [`Compare::is_equal`] for details on how exact equality is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [1.1, 2.2, 3.3];
+/// let arr2 = [1.1, 2.2, 3.33];
+/// let error = check_arrays_equal!(&arr1, &arr2).unwrap_err();
+/// assert_eq!(error.mismatched_indices(), vec![2]);
+/// assert_eq!(error.mismatches[0].b, 3.33);
+/// ```
 #[macro_export]
-macro_rules! get_mismatched_elements_str {
-    ($arr1:expr, $arr2:expr, $idx_mismatched:expr, $output:expr) => {
-        use std::fmt::Write;
-        // Add the header.
-        let _ = write!($output, "{:>25} {:>25}\n", "arr1", "arr2");
-        let _ = write!($output, "{:>25} {:>25}\n", "----", "----");
-
-        // Iterate over all elements.
-        for (idx, (a, b)) in $arr1.iter().zip($arr2.iter()).enumerate() {
-            // Determine if the current index is in the mismatched indices list.
-            let is_mismatched = $idx_mismatched.contains(&idx);
-
-            // Define ANSI escape codes for red and bold text.
-            let red_bold_start = if is_mismatched { "\x1b[31;1m" } else { "" };
-            let reset = if is_mismatched { "\x1b[0m" } else { "" };
-
-            // Append formatted output to the string.
-            let _ = write!(
-                $output,
-                "{}{:>25e}{} {}{:>25e}{}\n",
-                red_bold_start, a, reset, red_bold_start, b, reset
-            );
-        }
-    };
+macro_rules! check_arrays_equal {
+    ($arr1:expr, $arr2:expr) => {{
+        // Variable to track the maximum absolute difference.
+        let mut max_abs_diff: f64 = 0.0;
+
+        // Compare the two arrays element-wise.
+        $crate::compare_elements(
+            $crate::shaped_elements!($arr1),
+            $crate::shaped_elements!($arr2),
+            |a, b| {
+                let equal = a.is_equal(b);
+                let (_, abs_diff) = a.is_equal_to_atol(b, $crate::__num_traits::Zero::zero());
+                let abs_diff: f64 = $crate::real_to_f64(abs_diff);
+                max_abs_diff = max_abs_diff.max(abs_diff);
+                (equal, abs_diff)
+            },
+        )
+        .and_then(|comparison| {
+            comparison.finish(max_abs_diff, |num_mismatched, count| {
+                format!(
+                    "\nThe two array-like structs are not exactly equal.\n --> Mismatched \
+                    Elements: {}/{}",
+                    num_mismatched, count
+                )
+            })
+        })
+    }};
 }
 
 /// Asserts element-wise exact equality of two array-like structs.
@@ -92,9 +110,13 @@ macro_rules! get_mismatched_elements_str {
 ///   have an `iter` method.
 /// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
 ///   have an `iter` method.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
 ///
 /// # Panics
 ///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
 /// * If the two array-like structs do not have the same number of elements. In this case, the
 ///   number of elements in each array-like struct is also printed.
 /// * If any of the element-wise comparisons fail. In this case, the two array-like structs will be
@@ -106,27 +128,29 @@ macro_rules! get_mismatched_elements_str {
 ///
 /// # Warning
 ///
-/// We **_cannot_** directly perform comparisons between 2D `ndarray` arrays and `nalgebra`
-/// matrices. This is because `ndarray` uses a row-major layout, while `nalgebra` uses a
-/// column-major layout. This is demonstrated in the last example.
+/// 2D `ndarray` arrays and `nalgebra` matrices iterate over their elements in different orders
+/// (`ndarray` uses a row-major layout, while `nalgebra` uses a column-major layout). They can only
+/// be compared directly when the `ndarray` and `nalgebra` features are enabled, in which case
+/// their elements are matched up by their `(row, col)` indices. This is demonstrated in the last example.
 ///
 /// # Warning
 ///
-/// Since this macro simply iterates over all elements, you theoretically _are_ able to compare
-/// 1D arrays with 2D arrays. For example,
+/// Shapes are only checked when _both_ array-like structs implement
+/// [`ArrayShape`](crate::ArrayShape) (e.g. nested `std` arrays, or `ndarray` arrays and `nalgebra`
+/// matrices when the `ndarray` and `nalgebra` features are enabled). Otherwise, this macro simply
+/// iterates over all elements, so you theoretically _are_ able to compare 1D arrays with 2D
+/// arrays. For example,
 ///
 /// ```
-/// use ndarray::{Array1, Array2};
+/// use ndarray::Array2;
 /// use numtest::*;
 ///
-/// let arr_1d = Array1::from_vec(vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6]);
+/// let arr_1d = vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6];
 /// let arr_2d = Array2::from_shape_vec((2, 3), vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6]).unwrap();
 /// assert_arrays_equal!(&arr_1d, &arr_2d);
 /// ```
 ///
-/// However, in general, this practice should be avoided. Comparisons between structs of different
-/// shapes will not be detected by the `numtest` crate since we do not specify dependencies to
-/// external numerical computing crates such as `ndarray` and `nalgebra`.
+/// However, in general, this practice should be avoided.
 ///
 /// # Examples
 ///
@@ -218,7 +242,8 @@ macro_rules! get_mismatched_elements_str {
 /// [`nalgebra::Matrix3`](https://docs.rs/nalgebra/0.25.0/nalgebra/base/type.Matrix3.html) and
 /// [`ndarray::Array2`](https://docs.rs/ndarray/0.15.6/ndarray/type.Array2.html)
 ///
-/// ```should_panic
+/// ```
+/// # #[cfg(all(feature = "nalgebra", feature = "ndarray"))] {
 /// use nalgebra::Matrix3;
 /// use ndarray::Array2;
 /// use numtest::*;
@@ -228,36 +253,92 @@ macro_rules! get_mismatched_elements_str {
 ///     (3, 3), vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6, 7.7, 8.8, 9.9],
 /// ).unwrap();
 /// assert_arrays_equal!(&mat, &arr);
+/// # }
 /// ```
 #[macro_export]
 macro_rules! assert_arrays_equal {
-    ($arr1:expr, $arr2:expr) => {
-        // Assert that the two arrays have the same number of elements.
-        let (count1, count2) = count_elements!($arr1, $arr2);
-        validate_counts!(count1, count2);
-
-        // Track the indices of mismatched elements.
-        let mut idx_mismatched: Vec<usize> = Vec::new();
-        for (idx, (a, b)) in $arr1.iter().zip($arr2.iter()).enumerate() {
-            let equal = a.is_equal(*b);
-            if !equal {
-                idx_mismatched.push(idx);
-            }
+    ($arr1:expr, $arr2:expr) => {{
+        if let Err(error) = $crate::check_arrays_equal!($arr1, $arr2) {
+            panic!("{}", error);
         }
+    }};
+    ($arr1:expr, $arr2:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_equal!($arr1, $arr2) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks element-wise equality of two array-like structs to within a specified decimal precision.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_equal_to_decimal`](crate::assert_arrays_equal_to_decimal).
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `decimal` - Decimal precision to use for comparison.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if all element-wise comparisons pass.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, or if any of the element-wise comparisons fail. The error carries
+/// the indices and values of the mismatched elements, along with the per-element differences.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_decimal`] for details on how equality to within a specified decimal
+/// precision is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [1.1, 2.2, 3.3];
+/// let arr2 = [1.1, 2.22, 3.33];
+/// let report = check_arrays_equal_to_decimal!(&arr1, &arr2, 1).unwrap();
+/// assert_eq!(report.worst_difference, 1.0);
+/// ```
+#[macro_export]
+macro_rules! check_arrays_equal_to_decimal {
+    ($arr1:expr, $arr2:expr, $decimal:expr) => {{
+        let decimal = $decimal;
+
+        // Variable to track the minimum satisfied precision.
+        let mut min_precision = i32::MAX;
 
-        // Panic if equality not satisfied.
-        if idx_mismatched.len() > 0 {
-            let mut mismatched_str = String::new();
-            get_mismatched_elements_str!($arr1, $arr2, &idx_mismatched, &mut mismatched_str);
-            panic!(
-                "\nThe two array-like structs are not exactly equal.\n --> Mismatched \
-                Elements: {}/{}\n\n{}",
-                idx_mismatched.len(),
-                count1,
-                mismatched_str
+        // Compare the two arrays element-wise.
+        $crate::compare_elements(
+            $crate::shaped_elements!($arr1),
+            $crate::shaped_elements!($arr2),
+            |a, b| {
+                let (equal, precision) = a.is_equal_to_decimal(b, decimal);
+                min_precision = min_precision.min(precision);
+                (equal, f64::from(precision))
+            },
+        )
+        .and_then(|comparison| {
+            comparison.with_metric_column("decimal").finish(
+                f64::from(min_precision),
+                |num_mismatched, count| {
+                    format!(
+                        "\nThe two array-like structs are not equal to {} decimal places.\n --> \
+                    Mismatched Elements: {}/{}\n --> Maximum Decimal Places of Precision: {}",
+                        decimal, num_mismatched, count, min_precision
+                    )
+                },
             )
-        }
-    };
+        })
+    }};
 }
 
 /// Asserts element-wise equality of two array-like structs to within a specified decimal precision.
@@ -273,9 +354,13 @@ macro_rules! assert_arrays_equal {
 /// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
 ///   have an `iter` method.
 /// * `decimal` - Decimal precision to use for comparison.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
 ///
 /// # Panics
 ///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
 /// * If the two array-like structs do not have the same number of elements. In this case, the
 ///   number of elements in each array-like struct is also printed.
 /// * If any of the element-wise comparisons fail. In this case, the two array-like structs will be
@@ -288,27 +373,29 @@ macro_rules! assert_arrays_equal {
 ///
 /// # Warning
 ///
-/// We **_cannot_** directly perform comparisons between 2D `ndarray` arrays and `nalgebra`
-/// matrices. This is because `ndarray` uses a row-major layout, while `nalgebra` uses a
-/// column-major layout. This is demonstrated in the last example.
+/// 2D `ndarray` arrays and `nalgebra` matrices iterate over their elements in different orders
+/// (`ndarray` uses a row-major layout, while `nalgebra` uses a column-major layout). They can only
+/// be compared directly when the `ndarray` and `nalgebra` features are enabled, in which case
+/// their elements are matched up by their `(row, col)` indices. This is demonstrated in the last example.
 ///
 /// # Warning
 ///
-/// Since this macro simply iterates over all elements, you theoretically _are_ able to compare
-/// 1D arrays with 2D arrays. For example,
+/// Shapes are only checked when _both_ array-like structs implement
+/// [`ArrayShape`](crate::ArrayShape) (e.g. nested `std` arrays, or `ndarray` arrays and `nalgebra`
+/// matrices when the `ndarray` and `nalgebra` features are enabled). Otherwise, this macro simply
+/// iterates over all elements, so you theoretically _are_ able to compare 1D arrays with 2D
+/// arrays. For example,
 ///
 /// ```
-/// use ndarray::{Array1, Array2};
+/// use ndarray::Array2;
 /// use numtest::*;
 ///
-/// let arr_1d = Array1::from_vec(vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6]);
+/// let arr_1d = vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6];
 /// let arr_2d = Array2::from_shape_vec((2, 3), vec![1.1, 2.22, 3.33, 4.4, 5.55, 6.66]).unwrap();
 /// assert_arrays_equal_to_decimal!(&arr_1d, &arr_2d, 1);
 /// ```
 ///
-/// However, in general, this practice should be avoided. Comparisons between structs of different
-/// shapes will not be detected by the `numtest` crate since we do not specify dependencies to
-/// external numerical computing crates such as `ndarray` and `nalgebra`.
+/// However, in general, this practice should be avoided.
 ///
 /// # Examples
 ///
@@ -400,7 +487,8 @@ macro_rules! assert_arrays_equal {
 /// [`nalgebra::Matrix3`](https://docs.rs/nalgebra/0.25.0/nalgebra/base/type.Matrix3.html) and
 /// [`ndarray::Array2`](https://docs.rs/ndarray/0.15.6/ndarray/type.Array2.html)
 ///
-/// ```should_panic
+/// ```
+/// # #[cfg(all(feature = "nalgebra", feature = "ndarray"))] {
 /// use nalgebra::Matrix3;
 /// use ndarray::Array2;
 /// use numtest::*;
@@ -410,42 +498,239 @@ macro_rules! assert_arrays_equal {
 ///     (3, 3), vec![1.1, 2.22, 3.33, 4.4, 5.55, 6.66, 7.7, 8.88, 9.99],
 /// ).unwrap();
 /// assert_arrays_equal_to_decimal!(&mat, &arr, 1);
+/// # }
 /// ```
 #[macro_export]
 macro_rules! assert_arrays_equal_to_decimal {
-    ($arr1:expr, $arr2:expr, $decimal:expr) => {
-        // Variable to track the minimum satisfied precision.
-        let mut min_precision = i32::MAX;
+    ($arr1:expr, $arr2:expr, $decimal:expr) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_decimal!($arr1, $arr2, $decimal) {
+            panic!("{}", error);
+        }
+    }};
+    ($arr1:expr, $arr2:expr, $decimal:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_decimal!($arr1, $arr2, $decimal) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
 
-        // Assert that the two arrays have the same number of elements.
-        let (count1, count2) = count_elements!($arr1, $arr2);
-        validate_counts!(count1, count2);
+/// Checks element-wise equality of two array-like structs to a specified number of significant
+/// figures.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_equal_to_sigfigs`](crate::assert_arrays_equal_to_sigfigs).
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `sigfigs` - Number of significant figures to use for comparison.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) whose `worst_difference` is the smallest number
+/// of matching significant figures, if all element-wise comparisons pass.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, or if any of the element-wise comparisons fail. The error carries
+/// the indices and values of the mismatched elements, along with the per-element numbers of
+/// matching significant figures.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_sigfigs`] for details on how equality to a specified number of
+/// significant figures is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [6.02214076e23, 1.602176634e-19];
+/// let arr2 = [6.022e23, 1.602e-19];
+/// let report = check_arrays_equal_to_sigfigs!(&arr1, &arr2, 3).unwrap();
+/// assert_eq!(report.worst_difference, 4.0);
+/// ```
+#[macro_export]
+macro_rules! check_arrays_equal_to_sigfigs {
+    ($arr1:expr, $arr2:expr, $sigfigs:expr) => {{
+        let sigfigs = $sigfigs;
+
+        // Variable to track the minimum number of matching significant figures.
+        let mut min_sigfigs = u32::MAX;
+
+        // Compare the two arrays element-wise.
+        $crate::compare_elements(
+            $crate::shaped_elements!($arr1),
+            $crate::shaped_elements!($arr2),
+            |a, b| {
+                let (equal, sigfigs) = a.is_equal_to_sigfigs(b, sigfigs);
+                min_sigfigs = min_sigfigs.min(sigfigs);
+                (equal, f64::from(sigfigs))
+            },
+        )
+        .and_then(|comparison| {
+            comparison.with_metric_column("sigfigs").finish(f64::from(min_sigfigs), |num_mismatched, count| {
+                format!(
+                    "\nThe two array-like structs are not equal to {} significant figures.\n --> \
+                    Mismatched Elements: {}/{}\n --> Minimum Significant Figures: {}",
+                    sigfigs, num_mismatched, count, min_sigfigs
+                )
+            })
+        })
+    }};
+}
 
-        // Track the indices of mismatched elements and the smallest precision that is satisfied.
-        let mut idx_mismatched: Vec<usize> = Vec::new();
-        for (idx, (a, b)) in $arr1.iter().zip($arr2.iter()).enumerate() {
-            let (equal, precision) = a.is_equal_to_decimal(*b, $decimal);
-            if !equal {
-                idx_mismatched.push(idx);
-            }
-            min_precision = min_precision.min(precision);
+/// Asserts element-wise equality of two array-like structs to a specified number of significant
+/// figures.
+///
+/// This macro iterates over the elements of the two structs and checks if each pair of elements is
+/// equal to the specified number of significant figures using [`Compare::is_equal_to_sigfigs`].
+/// Additionally, this macro also checks whether the two structs have the same number of elements.
+/// Since significant figures are counted relative to the magnitude of each pair of elements, this
+/// macro is well suited to array-like structs whose elements span many orders of magnitude.
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `sigfigs` - Number of significant figures to use for comparison.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
+/// * If the two array-like structs do not have the same number of elements. In this case, the
+///   number of elements in each array-like struct is also printed.
+/// * If any of the element-wise comparisons fail. In this case, the smallest number of matching
+///   significant figures is printed along with the two array-like structs, with the mismatched
+///   elements shown in bolded red.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_sigfigs`] for details on how equality to a specified number of
+/// significant figures is defined.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [6.02214076e23, 1.602176634e-19, 299792458.0];
+/// let arr2 = [6.022e23, 1.602e-19, 2.998e8];
+/// assert_arrays_equal_to_sigfigs!(&arr1, &arr2, 4);
+/// ```
+///
+/// ```
+/// use ndarray::array;
+/// use numtest::*;
+///
+/// let arr1 = array![[1.0e-10, 2.0e-5], [3.0, 4.0e5]];
+/// let arr2 = array![[1.001e-10, 2.001e-5], [3.001, 4.001e5]];
+/// assert_arrays_equal_to_sigfigs!(&arr1, &arr2, 3);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let arr1 = [6.02214076e23, 1.602176634e-19];
+/// let arr2 = [6.022e23, 1.6e-19];
+/// assert_arrays_equal_to_sigfigs!(&arr1, &arr2, 4);
+/// ```
+#[macro_export]
+macro_rules! assert_arrays_equal_to_sigfigs {
+    ($arr1:expr, $arr2:expr, $sigfigs:expr) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_sigfigs!($arr1, $arr2, $sigfigs) {
+            panic!("{}", error);
         }
-
-        // Panic if equality not satisfied.
-        if idx_mismatched.len() > 0 {
-            let mut mismatched_str = String::new();
-            get_mismatched_elements_str!($arr1, $arr2, &idx_mismatched, &mut mismatched_str);
-            panic!(
-                "\nThe two array-like structs are not equal to {} decimal places.\n --> Mismatched \
-                Elements: {}/{}\n --> Maximum Decimal Places of Precision: {}\n\n{}",
-                $decimal,
-                idx_mismatched.len(),
-                count1,
-                min_precision,
-                mismatched_str
-            );
+    }};
+    ($arr1:expr, $arr2:expr, $sigfigs:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_sigfigs!($arr1, $arr2, $sigfigs) {
+            panic!("{}{}", format_args!($($arg)+), error);
         }
-    };
+    }};
+}
+
+/// Checks element-wise equality of two array-like structs to within a specified absolute tolerance.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_equal_to_atol`](crate::assert_arrays_equal_to_atol).
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if all element-wise comparisons pass.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, or if any of the element-wise comparisons fail. The error carries
+/// the indices and values of the mismatched elements, along with the per-element differences.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_atol`] for details on how equality to within a specified absolute
+/// tolerance is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [1.1, 2.2, 3.3];
+/// let arr2 = [1.1, 2.22, 3.33];
+///
+/// // Retry with a looser tolerance.
+/// let result = check_arrays_equal_to_atol!(&arr1, &arr2, 0.01)
+///     .or_else(|_| check_arrays_equal_to_atol!(&arr1, &arr2, 0.1));
+/// assert!(result.is_ok());
+/// ```
+#[macro_export]
+macro_rules! check_arrays_equal_to_atol {
+    ($arr1:expr, $arr2:expr, $atol:expr) => {{
+        let atol = $atol;
+
+        // Variable to track the maximum absolute difference.
+        let mut max_abs_diff: f64 = 0.0;
+
+        // Compare the two arrays element-wise.
+        $crate::compare_elements(
+            $crate::shaped_elements!($arr1),
+            $crate::shaped_elements!($arr2),
+            |a, b| {
+                let (equal, abs_diff) = a.is_equal_to_atol(b, atol);
+                let abs_diff: f64 = $crate::real_to_f64(abs_diff);
+                max_abs_diff = max_abs_diff.max(abs_diff);
+                (equal, abs_diff)
+            },
+        )
+        .and_then(|comparison| {
+            comparison.finish(max_abs_diff, |num_mismatched, count| {
+                format!(
+                    "\nThe two array-like structs are not equal to an absolute tolerance of {}.\n \
+                    --> Mismatched Elements: {}/{}\n --> Largest Absolute Difference: {}",
+                    atol, num_mismatched, count, max_abs_diff
+                )
+            })
+        })
+    }};
 }
 
 /// Asserts element-wise equality of two array-like structs to within a specified absolute
@@ -462,9 +747,13 @@ macro_rules! assert_arrays_equal_to_decimal {
 /// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
 ///   have an `iter` method.
 /// * `atol` - Absolute tolerance.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
 ///
 /// # Panics
 ///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
 /// * If the two array-like structs do not have the same number of elements. In this case, the
 ///   number of elements in each array-like struct is also printed.
 /// * If any of the element-wise comparisons fail. In this case, the two array-like structs will be
@@ -477,27 +766,29 @@ macro_rules! assert_arrays_equal_to_decimal {
 ///
 /// # Warning
 ///
-/// We **_cannot_** directly perform comparisons between 2D `ndarray` arrays and `nalgebra`
-/// matrices. This is because `ndarray` uses a row-major layout, while `nalgebra` uses a
-/// column-major layout. This is demonstrated in the last example.
+/// 2D `ndarray` arrays and `nalgebra` matrices iterate over their elements in different orders
+/// (`ndarray` uses a row-major layout, while `nalgebra` uses a column-major layout). They can only
+/// be compared directly when the `ndarray` and `nalgebra` features are enabled, in which case
+/// their elements are matched up by their `(row, col)` indices. This is demonstrated in the last example.
 ///
 /// # Warning
 ///
-/// Since this macro simply iterates over all elements, you theoretically _are_ able to compare
-/// 1D arrays with 2D arrays. For example,
+/// Shapes are only checked when _both_ array-like structs implement
+/// [`ArrayShape`](crate::ArrayShape) (e.g. nested `std` arrays, or `ndarray` arrays and `nalgebra`
+/// matrices when the `ndarray` and `nalgebra` features are enabled). Otherwise, this macro simply
+/// iterates over all elements, so you theoretically _are_ able to compare 1D arrays with 2D
+/// arrays. For example,
 ///
 /// ```
-/// use ndarray::{Array1, Array2};
+/// use ndarray::Array2;
 /// use numtest::*;
 ///
-/// let arr_1d = Array1::from_vec(vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6]);
+/// let arr_1d = vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6];
 /// let arr_2d = Array2::from_shape_vec((2, 3), vec![1.1, 2.22, 3.33, 4.4, 5.55, 6.66]).unwrap();
 /// assert_arrays_equal_to_atol!(&arr_1d, &arr_2d, 0.07);
 /// ```
 ///
-/// However, in general, this practice should be avoided. Comparisons between structs of different
-/// shapes will not be detected by the `numtest` crate since we do not specify dependencies to
-/// external numerical computing crates such as `ndarray` and `nalgebra`.
+/// However, in general, this practice should be avoided.
 ///
 /// # Examples
 ///
@@ -589,7 +880,8 @@ macro_rules! assert_arrays_equal_to_decimal {
 /// [`nalgebra::Matrix3`](https://docs.rs/nalgebra/0.25.0/nalgebra/base/type.Matrix3.html) and
 /// [`ndarray::Array2`](https://docs.rs/ndarray/0.15.6/ndarray/type.Array2.html)
 ///
-/// ```should_panic
+/// ```
+/// # #[cfg(all(feature = "nalgebra", feature = "ndarray"))] {
 /// use nalgebra::Matrix3;
 /// use ndarray::Array2;
 /// use numtest::*;
@@ -599,50 +891,112 @@ macro_rules! assert_arrays_equal_to_decimal {
 ///     (3, 3), vec![1.1, 2.22, 3.33, 4.4, 5.55, 6.66, 7.7, 8.88, 9.99],
 /// ).unwrap();
 /// assert_arrays_equal_to_atol!(&mat, &arr, 0.1);
+/// # }
+/// ```
+///
+/// Custom message
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let arr1 = [1.1, 2.2, 3.3];
+/// let arr2 = [1.1, 2.22, 3.33];
+/// assert_arrays_equal_to_atol!(&arr1, &arr2, 0.01, "case {} failed", 3);
 /// ```
 #[macro_export]
 macro_rules! assert_arrays_equal_to_atol {
-    ($arr1:expr, $arr2:expr, $atol:expr) => {
+    ($arr1:expr, $arr2:expr, $atol:expr) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_atol!($arr1, $arr2, $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($arr1:expr, $arr2:expr, $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_atol!($arr1, $arr2, $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks element-wise equality of two array-like structs to within a separate absolute
+/// tolerance for each pair of elements.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_equal_to_atols`](crate::assert_arrays_equal_to_atols).
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `atols` - Absolute tolerance for each pair of elements. Either an array-like struct with the same
+///   number of elements as `arr1` and `arr2` (and the same shape, if all shapes are known), or a
+///   closure mapping the flat position of each pair of elements to its tolerance (see
+///   [`Tolerances`](crate::Tolerances)).
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if all element-wise comparisons pass.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, if the tolerances do not have the same shape or number of
+/// elements as the array-like structs, or if any of the element-wise comparisons fail.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_atol`] for details on how equality to within a specified absolute
+/// tolerance is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [1.1, 2.2, 3.3];
+/// let arr2 = [1.1, 2.22, 3.3003];
+/// assert!(check_arrays_equal_to_atols!(&arr1, &arr2, [1e-3, 0.05, 1e-3]).is_ok());
+/// assert!(check_arrays_equal_to_atols!(&arr1, &arr2, |_| 1e-3).is_err());
+/// ```
+#[macro_export]
+macro_rules! check_arrays_equal_to_atols {
+    ($arr1:expr, $arr2:expr, $atols:expr) => {{
         // Variable to track the maximum absolute difference.
         let mut max_abs_diff: f64 = 0.0;
 
-        // Assert that the two arrays have the same number of elements.
-        let (count1, count2) = count_elements!($arr1, $arr2);
-        validate_counts!(count1, count2);
-
-        // Track the indices of mismatched elements and the larget absolute difference.
-        let mut idx_mismatched: Vec<usize> = Vec::new();
-        for (idx, (a, b)) in $arr1.iter().zip($arr2.iter()).enumerate() {
-            let (equal, abs_diff) = a.is_equal_to_atol(*b, $atol);
-            if !equal {
-                idx_mismatched.push(idx);
-            }
-            max_abs_diff = max_abs_diff.max(abs_diff.into());
-        }
-
-        // Panic if equality not satisfied.
-        if idx_mismatched.len() > 0 {
-            let mut mismatched_str = String::new();
-            get_mismatched_elements_str!($arr1, $arr2, &idx_mismatched, &mut mismatched_str);
-            panic!(
-                "\nThe two array-like structs are not equal to an absolute tolerance of {}.\n --> \
-                Mismatched Elements: {}/{}\n --> Largest Absolute Difference: {}\n\n{}",
-                $atol,
-                idx_mismatched.len(),
-                count1,
-                max_abs_diff,
-                mismatched_str
-            )
-        }
-    };
+        // Collect the tolerance for each pair of elements.
+        let arr1 = $crate::shaped_elements!($arr1);
+        let arr2 = $crate::shaped_elements!($arr2);
+        let atols = $crate::shaped_tolerances!($atols, arr1.elements.len());
+
+        // Compare the two arrays element-wise.
+        $crate::compare_elements_with_tolerances(arr1, arr2, atols, |a, b, tol| {
+            let (equal, abs_diff) = a.is_equal_to_atol(b, tol);
+            let abs_diff: f64 = $crate::real_to_f64(abs_diff);
+            max_abs_diff = max_abs_diff.max(abs_diff);
+            (equal, abs_diff)
+        })
+        .and_then(|comparison| {
+            comparison.finish(max_abs_diff, |num_mismatched, count| {
+                format!(
+                    "\nThe two array-like structs are not equal to the specified absolute \
+                    tolerances.\n --> Mismatched Elements: {}/{}\n --> Largest Absolute \
+                    Difference: {}",
+                    num_mismatched, count, max_abs_diff
+                )
+            })
+        })
+    }};
 }
 
-/// Asserts element-wise equality of two array-like structs to within a specified relative
-/// tolerance.
+/// Asserts element-wise equality of two array-like structs to within a separate absolute
+/// tolerance for each pair of elements.
 ///
-/// This macro iterates over the elements of the two structs and checks if each pair of elements is
-/// equal to within the specified relative tolerance using the [`Compare::is_equal_to_rtol`].
-/// Additionally, this macro also checks whether the two structs have the same number of elements.
+/// This macro works like [`assert_arrays_equal_to_atol`](crate::assert_arrays_equal_to_atol),
+/// but takes one tolerance per pair of elements instead of a single tolerance (useful when the
+/// elements have different scales, e.g. the components of a state vector).
 ///
 /// # Arguments
 ///
@@ -650,43 +1004,193 @@ macro_rules! assert_arrays_equal_to_atol {
 ///   have an `iter` method.
 /// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
 ///   have an `iter` method.
-/// * `rtol` - Relative tolerance.
+/// * `atols` - Absolute tolerance for each pair of elements. Either an array-like struct with the same
+///   number of elements as `arr1` and `arr2` (and the same shape, if all shapes are known), or a
+///   closure mapping the flat position of each pair of elements to its tolerance (see
+///   [`Tolerances`](crate::Tolerances)).
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
 ///
 /// # Panics
 ///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
 /// * If the two array-like structs do not have the same number of elements. In this case, the
 ///   number of elements in each array-like struct is also printed.
+/// * If the tolerances implement [`ArrayShape`](crate::ArrayShape) and do not have the same shape
+///   as the array-like structs (when their shapes are known). In this case, the shape of the
+///   tolerances is also printed.
+/// * If the number of tolerances does not match the number of elements. In this case, both counts
+///   are also printed.
 /// * If any of the element-wise comparisons fail. In this case, the two array-like structs will be
-///   printed, with the mismatched elements shown in bolded red.
+///   printed alongside the tolerances, with the mismatched elements shown in bolded red.
 ///
 /// # Note
 ///
-/// See [`Compare::is_equal_to_rtol`] for details on how equality to within a specified relative
+/// See [`Compare::is_equal_to_atol`] for details on how equality to within a specified absolute
 /// tolerance is defined.
 ///
-/// # Warning
-///
-/// We **_cannot_** directly perform comparisons between 2D `ndarray` arrays and `nalgebra`
-/// matrices. This is because `ndarray` uses a row-major layout, while `nalgebra` uses a
-/// column-major layout. This is demonstrated in the last example.
-///
-/// # Warning
+/// # Examples
 ///
-/// Since this macro simply iterates over all elements, you theoretically _are_ able to compare
-/// 1D arrays with 2D arrays. For example,
+/// Tolerance array
 ///
 /// ```
-/// use ndarray::{Array1, Array2};
 /// use numtest::*;
 ///
-/// let arr_1d = Array1::from_vec(vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6]);
-/// let arr_2d = Array2::from_shape_vec((2, 3), vec![1.1, 2.22, 3.33, 4.4, 5.55, 6.66]).unwrap();
-/// assert_arrays_equal_to_rtol!(&arr_1d, &arr_2d, 0.01);
+/// let arr1 = [1.1, 2.2, 3.3];
+/// let arr2 = [1.1, 2.22, 3.3003];
+/// assert_arrays_equal_to_atols!(&arr1, &arr2, [1e-3, 0.05, 1e-3]);
 /// ```
 ///
-/// However, in general, this practice should be avoided. Comparisons between structs of different
-/// shapes will not be detected by the `numtest` crate since we do not specify dependencies to
-/// external numerical computing crates such as `ndarray` and `nalgebra`.
+/// Tolerance closure
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [1.1, 2.2, 3.3];
+/// let arr2 = [1.1, 2.22, 3.3003];
+/// assert_arrays_equal_to_atols!(&arr1, &arr2, |idx| if idx == 1 { 0.1 } else { 1e-3 });
+/// ```
+#[macro_export]
+macro_rules! assert_arrays_equal_to_atols {
+    ($arr1:expr, $arr2:expr, $atols:expr) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_atols!($arr1, $arr2, $atols) {
+            panic!("{}", error);
+        }
+    }};
+    ($arr1:expr, $arr2:expr, $atols:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_atols!($arr1, $arr2, $atols) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks element-wise equality of two array-like structs to within a specified relative tolerance.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_equal_to_rtol`](crate::assert_arrays_equal_to_rtol).
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `rtol` - Relative tolerance.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if all element-wise comparisons pass.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, or if any of the element-wise comparisons fail. The error carries
+/// the indices and values of the mismatched elements, along with the per-element differences.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_rtol`] for details on how equality to within a specified relative
+/// tolerance is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let vec1 = vec![1.1, 2.2, 3.3];
+/// let vec2 = vec![1.1, 2.2, 3.4];
+/// let error = check_arrays_equal_to_rtol!(&vec1, &vec2, 0.01).unwrap_err();
+/// assert_eq!(error.mismatched_indices(), vec![2]);
+/// ```
+#[macro_export]
+macro_rules! check_arrays_equal_to_rtol {
+    ($arr1:expr, $arr2:expr, $rtol:expr) => {{
+        let rtol = $rtol;
+
+        // Variable to track the maximum relative difference.
+        let mut max_rel_diff: f64 = 0.0;
+
+        // Compare the two arrays element-wise.
+        $crate::compare_elements(
+            $crate::shaped_elements!($arr1),
+            $crate::shaped_elements!($arr2),
+            |a, b| {
+                let (equal, rel_diff) = a.is_equal_to_rtol(b, rtol);
+                let rel_diff: f64 = $crate::real_to_f64(rel_diff);
+                max_rel_diff = max_rel_diff.max(rel_diff);
+                (equal, rel_diff)
+            },
+        )
+        .and_then(|comparison| {
+            comparison.finish(max_rel_diff, |num_mismatched, count| {
+                format!(
+                    "\nThe two array-like structs are not equal to a relative tolerance of {}.\n \
+                    --> Mismatched Elements: {}/{}\n --> Largest Relative Difference: {}",
+                    rtol, num_mismatched, count, max_rel_diff
+                )
+            })
+        })
+    }};
+}
+
+/// Asserts element-wise equality of two array-like structs to within a specified relative
+/// tolerance.
+///
+/// This macro iterates over the elements of the two structs and checks if each pair of elements is
+/// equal to within the specified relative tolerance using the [`Compare::is_equal_to_rtol`].
+/// Additionally, this macro also checks whether the two structs have the same number of elements.
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `rtol` - Relative tolerance.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
+/// * If the two array-like structs do not have the same number of elements. In this case, the
+///   number of elements in each array-like struct is also printed.
+/// * If any of the element-wise comparisons fail. In this case, the two array-like structs will be
+///   printed, with the mismatched elements shown in bolded red.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_rtol`] for details on how equality to within a specified relative
+/// tolerance is defined.
+///
+/// # Warning
+///
+/// 2D `ndarray` arrays and `nalgebra` matrices iterate over their elements in different orders
+/// (`ndarray` uses a row-major layout, while `nalgebra` uses a column-major layout). They can only
+/// be compared directly when the `ndarray` and `nalgebra` features are enabled, in which case
+/// their elements are matched up by their `(row, col)` indices. This is demonstrated in the last example.
+///
+/// # Warning
+///
+/// Shapes are only checked when _both_ array-like structs implement
+/// [`ArrayShape`](crate::ArrayShape) (e.g. nested `std` arrays, or `ndarray` arrays and `nalgebra`
+/// matrices when the `ndarray` and `nalgebra` features are enabled). Otherwise, this macro simply
+/// iterates over all elements, so you theoretically _are_ able to compare 1D arrays with 2D
+/// arrays. For example,
+///
+/// ```
+/// use ndarray::Array2;
+/// use numtest::*;
+///
+/// let arr_1d = vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6];
+/// let arr_2d = Array2::from_shape_vec((2, 3), vec![1.1, 2.22, 3.33, 4.4, 5.55, 6.66]).unwrap();
+/// assert_arrays_equal_to_rtol!(&arr_1d, &arr_2d, 0.01);
+/// ```
+///
+/// However, in general, this practice should be avoided.
 ///
 /// # Examples
 ///
@@ -778,7 +1282,8 @@ macro_rules! assert_arrays_equal_to_atol {
 /// [`nalgebra::Matrix3`](https://docs.rs/nalgebra/0.25.0/nalgebra/base/type.Matrix3.html) and
 /// [`ndarray::Array2`](https://docs.rs/ndarray/0.15.6/ndarray/type.Array2.html)
 ///
-/// ```should_panic
+/// ```
+/// # #[cfg(all(feature = "nalgebra", feature = "ndarray"))] {
 /// use nalgebra::Matrix3;
 /// use ndarray::Array2;
 /// use numtest::*;
@@ -788,47 +1293,509 @@ macro_rules! assert_arrays_equal_to_atol {
 ///     (3, 3), vec![1.1, 2.22, 3.33, 4.4, 5.55, 6.66, 7.7, 8.88, 9.99],
 /// ).unwrap();
 /// assert_arrays_equal_to_rtol!(&mat, &arr, 0.01);
+/// # }
 /// ```
 #[macro_export]
 macro_rules! assert_arrays_equal_to_rtol {
-    ($arr1:expr, $arr2:expr, $rtol:expr) => {
+    ($arr1:expr, $arr2:expr, $rtol:expr) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_rtol!($arr1, $arr2, $rtol) {
+            panic!("{}", error);
+        }
+    }};
+    ($arr1:expr, $arr2:expr, $rtol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_rtol!($arr1, $arr2, $rtol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks element-wise equality of two array-like structs to within a separate relative
+/// tolerance for each pair of elements.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_equal_to_rtols`](crate::assert_arrays_equal_to_rtols).
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `rtols` - Relative tolerance for each pair of elements. Either an array-like struct with the same
+///   number of elements as `arr1` and `arr2` (and the same shape, if all shapes are known), or a
+///   closure mapping the flat position of each pair of elements to its tolerance (see
+///   [`Tolerances`](crate::Tolerances)).
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if all element-wise comparisons pass.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, if the tolerances do not have the same shape or number of
+/// elements as the array-like structs, or if any of the element-wise comparisons fail.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_rtol`] for details on how equality to within a specified relative
+/// tolerance is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [1.1, 2.2, 3.3];
+/// let arr2 = [1.1, 2.22, 3.3003];
+/// assert!(check_arrays_equal_to_rtols!(&arr1, &arr2, [1e-3, 0.01, 1e-3]).is_ok());
+/// assert!(check_arrays_equal_to_rtols!(&arr1, &arr2, |_| 1e-3).is_err());
+/// ```
+#[macro_export]
+macro_rules! check_arrays_equal_to_rtols {
+    ($arr1:expr, $arr2:expr, $rtols:expr) => {{
         // Variable to track the maximum relative difference.
         let mut max_rel_diff: f64 = 0.0;
 
-        // Assert that the two arrays have the same number of elements.
-        let (count1, count2) = count_elements!($arr1, $arr2);
-        validate_counts!(count1, count2);
+        // Collect the tolerance for each pair of elements.
+        let arr1 = $crate::shaped_elements!($arr1);
+        let arr2 = $crate::shaped_elements!($arr2);
+        let rtols = $crate::shaped_tolerances!($rtols, arr1.elements.len());
+
+        // Compare the two arrays element-wise.
+        $crate::compare_elements_with_tolerances(arr1, arr2, rtols, |a, b, tol| {
+            let (equal, rel_diff) = a.is_equal_to_rtol(b, tol);
+            let rel_diff: f64 = $crate::real_to_f64(rel_diff);
+            max_rel_diff = max_rel_diff.max(rel_diff);
+            (equal, rel_diff)
+        })
+        .and_then(|comparison| {
+            comparison.finish(max_rel_diff, |num_mismatched, count| {
+                format!(
+                    "\nThe two array-like structs are not equal to the specified relative \
+                    tolerances.\n --> Mismatched Elements: {}/{}\n --> Largest Relative \
+                    Difference: {}",
+                    num_mismatched, count, max_rel_diff
+                )
+            })
+        })
+    }};
+}
 
-        // Track the indices of mismatched elements and the larget relative difference.
-        let mut idx_mismatched: Vec<usize> = Vec::new();
-        for (idx, (a, b)) in $arr1.iter().zip($arr2.iter()).enumerate() {
-            let (equal, rel_diff) = a.is_equal_to_rtol(*b, $rtol);
-            if !equal {
-                idx_mismatched.push(idx);
-            }
-            max_rel_diff = max_rel_diff.max(rel_diff.into());
+/// Asserts element-wise equality of two array-like structs to within a separate relative
+/// tolerance for each pair of elements.
+///
+/// This macro works like [`assert_arrays_equal_to_rtol`](crate::assert_arrays_equal_to_rtol),
+/// but takes one tolerance per pair of elements instead of a single tolerance (useful when the
+/// elements have different scales, e.g. the components of a state vector).
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `rtols` - Relative tolerance for each pair of elements. Either an array-like struct with the same
+///   number of elements as `arr1` and `arr2` (and the same shape, if all shapes are known), or a
+///   closure mapping the flat position of each pair of elements to its tolerance (see
+///   [`Tolerances`](crate::Tolerances)).
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
+/// * If the two array-like structs do not have the same number of elements. In this case, the
+///   number of elements in each array-like struct is also printed.
+/// * If the tolerances implement [`ArrayShape`](crate::ArrayShape) and do not have the same shape
+///   as the array-like structs (when their shapes are known). In this case, the shape of the
+///   tolerances is also printed.
+/// * If the number of tolerances does not match the number of elements. In this case, both counts
+///   are also printed.
+/// * If any of the element-wise comparisons fail. In this case, the two array-like structs will be
+///   printed alongside the tolerances, with the mismatched elements shown in bolded red.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_rtol`] for details on how equality to within a specified relative
+/// tolerance is defined.
+///
+/// # Examples
+///
+/// Tolerance array
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [1.1, 2.2, 3.3];
+/// let arr2 = [1.1, 2.22, 3.3003];
+/// assert_arrays_equal_to_rtols!(&arr1, &arr2, [1e-3, 0.01, 1e-3]);
+/// ```
+///
+/// Tolerance closure
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [1.1, 2.2, 3.3];
+/// let arr2 = [1.1, 2.22, 3.3003];
+/// assert_arrays_equal_to_rtols!(&arr1, &arr2, |idx| if idx == 1 { 0.01 } else { 1e-3 });
+/// ```
+#[macro_export]
+macro_rules! assert_arrays_equal_to_rtols {
+    ($arr1:expr, $arr2:expr, $rtols:expr) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_rtols!($arr1, $arr2, $rtols) {
+            panic!("{}", error);
         }
+    }};
+    ($arr1:expr, $arr2:expr, $rtols:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_rtols!($arr1, $arr2, $rtols) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
 
-        // Panic if equality not satisfied.
-        if idx_mismatched.len() > 0 {
-            let mut mismatched_str = String::new();
-            get_mismatched_elements_str!($arr1, $arr2, &idx_mismatched, &mut mismatched_str);
-            panic!(
-                "\nThe two array-like structs are not equal to a relative tolerance of {}.\n --> \
-                Mismatched Elements: {}/{}\n --> Largest Relative Difference: {}\n\n{}",
-                $rtol,
-                idx_mismatched.len(),
-                count1,
-                max_rel_diff,
-                mismatched_str
-            )
+/// Checks element-wise equality of two array-like structs to within a specified number of units in
+/// the last place (ULPs).
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_equal_to_ulps`](crate::assert_arrays_equal_to_ulps).
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `max_ulps` - Maximum allowable distance between each pair of elements, in ULPs.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if all element-wise comparisons pass.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, or if any of the element-wise comparisons fail. The error carries
+/// the indices and values of the mismatched elements, along with the per-element differences.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_ulps`] for details on how equality to within a specified number of
+/// ULPs is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [1.0, 2.0, 3.0];
+/// let arr2 = [1.0 + f64::EPSILON, 2.0, 3.0 - 2.0 * f64::EPSILON];
+/// let report = check_arrays_equal_to_ulps!(&arr1, &arr2, 1).unwrap();
+/// assert_eq!(report.worst_difference, 1.0);
+/// ```
+#[macro_export]
+macro_rules! check_arrays_equal_to_ulps {
+    ($arr1:expr, $arr2:expr, $max_ulps:expr) => {{
+        let max_ulps = $max_ulps;
+
+        // Variable to track the maximum ULP distance.
+        let mut largest_ulps: u64 = 0;
+
+        // Compare the two arrays element-wise.
+        $crate::compare_elements(
+            $crate::shaped_elements!($arr1),
+            $crate::shaped_elements!($arr2),
+            |a, b| {
+            let (equal, ulps) = a.is_equal_to_ulps(b, max_ulps);
+            largest_ulps = largest_ulps.max(ulps);
+            #[allow(clippy::cast_precision_loss)]
+            let ulps = ulps as f64;
+            (equal, ulps)
+        })
+        .and_then(|comparison| {
+            #[allow(clippy::cast_precision_loss)]
+            let worst_ulps = largest_ulps as f64;
+            comparison.with_metric_column("ulps").finish(worst_ulps, |num_mismatched, count| {
+                format!(
+                    "\nThe two array-like structs are not equal to within {} ULPs.\n --> Mismatched \
+                    Elements: {}/{}\n --> Largest ULP Distance: {}",
+                    max_ulps, num_mismatched, count, largest_ulps
+                )
+            })
+        })
+    }};
+}
+
+/// Asserts element-wise equality of two array-like structs to within a specified number of units in
+/// the last place (ULPs).
+///
+/// This macro iterates over the elements of the two structs and checks if each pair of elements is
+/// equal to within the specified number of ULPs using [`Compare::is_equal_to_ulps`]. Additionally,
+/// this macro also checks whether the two structs have the same number of elements.
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `max_ulps` - Maximum allowable distance between each pair of elements, in ULPs.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
+/// * If the two array-like structs do not have the same number of elements. In this case, the
+///   number of elements in each array-like struct is also printed.
+/// * If any of the element-wise comparisons fail. In this case, the two array-like structs will be
+///   printed, with the mismatched elements shown in bolded red.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_ulps`] for details on how equality to within a specified number of
+/// ULPs is defined.
+///
+/// # Warning
+///
+/// 2D `ndarray` arrays and `nalgebra` matrices iterate over their elements in different orders
+/// (`ndarray` uses a row-major layout, while `nalgebra` uses a column-major layout). They can only
+/// be compared directly when the `ndarray` and `nalgebra` features are enabled, in which case
+/// their elements are matched up by their `(row, col)` indices.
+///
+/// # Examples
+///
+/// [`std::array`]
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [1.0, 2.0, 3.0];
+/// let arr2 = [1.0 + f64::EPSILON, 2.0, 3.0 - 2.0 * f64::EPSILON];
+/// assert_arrays_equal_to_ulps!(&arr1, &arr2, 1);
+/// ```
+///
+/// [`Vec`]
+///
+/// ```
+/// use numtest::*;
+///
+/// let vec1 = vec![0.1_f32 + 0.2_f32, 1.0e-40_f32];
+/// let vec2 = vec![0.3_f32, 1.0e-40_f32];
+/// assert_arrays_equal_to_ulps!(&vec1, &vec2, 2);
+/// ```
+///
+/// Mix of 1D array-like structs
+///
+/// ```
+/// use nalgebra::Vector3;
+/// use ndarray::Array1;
+/// use numtest::*;
+///
+/// let std_arr = [1.0, 2.0, 3.0];
+/// let ndarray_arr = Array1::from_vec(vec![1.0, 2.0 + 2.0 * f64::EPSILON, 3.0]);
+/// let nalgebra_vec = Vector3::new(1.0, 2.0, 3.0 + 2.0 * f64::EPSILON);
+///
+/// assert_arrays_equal_to_ulps!(&std_arr, &ndarray_arr, 1);
+/// assert_arrays_equal_to_ulps!(&std_arr, &nalgebra_vec, 1);
+/// ```
+#[macro_export]
+macro_rules! assert_arrays_equal_to_ulps {
+    ($arr1:expr, $arr2:expr, $max_ulps:expr) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_ulps!($arr1, $arr2, $max_ulps) {
+            panic!("{}", error);
         }
-    };
+    }};
+    ($arr1:expr, $arr2:expr, $max_ulps:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_equal_to_ulps!($arr1, $arr2, $max_ulps) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks element-wise closeness of two array-like structs to within a specified combination of
+/// relative and absolute tolerances.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_close`](crate::assert_arrays_close).
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `rtol` - Relative tolerance.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if all element-wise comparisons pass.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, or if any of the element-wise comparisons fail. The error carries
+/// the indices and values of the mismatched elements, along with the per-element differences.
+///
+/// # Note
+///
+/// See [`Compare::is_close`] for details on how closeness is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [1e-10, 1000.001, 3.3];
+/// let arr2 = [0.0, 1000.0, 3.4];
+/// let error = check_arrays_close!(&arr1, &arr2, 1e-5, 1e-8).unwrap_err();
+/// assert_eq!(error.mismatched_indices(), vec![2]);
+/// ```
+#[macro_export]
+macro_rules! check_arrays_close {
+    ($arr1:expr, $arr2:expr, $rtol:expr, $atol:expr) => {{
+        let (rtol, atol) = ($rtol, $atol);
+
+        // Variables to track the maximum absolute and relative differences (and where they occur).
+        let mut max_abs_diff: f64 = 0.0;
+        let mut max_rel_diff: f64 = 0.0;
+        let mut idx_max_abs_diff: usize = 0;
+        let mut idx_max_rel_diff: usize = 0;
+        let mut idx: usize = 0;
+
+        // Compare the two arrays element-wise.
+        $crate::compare_elements(
+            $crate::shaped_elements!($arr1),
+            $crate::shaped_elements!($arr2),
+            |a, b| {
+                let (close, abs_diff, rel_diff) = a.is_close(b, rtol, atol);
+                let (abs_diff, rel_diff): (f64, f64) =
+                    ($crate::real_to_f64(abs_diff), $crate::real_to_f64(rel_diff));
+                if abs_diff > max_abs_diff {
+                    max_abs_diff = abs_diff;
+                    idx_max_abs_diff = idx;
+                }
+                if rel_diff > max_rel_diff {
+                    max_rel_diff = rel_diff;
+                    idx_max_rel_diff = idx;
+                }
+                idx += 1;
+                (close, abs_diff)
+            },
+        )
+        .and_then(|comparison| {
+            let idx_max_abs_diff = comparison.format_index(idx_max_abs_diff);
+            let idx_max_rel_diff = comparison.format_index(idx_max_rel_diff);
+            comparison.finish(max_abs_diff, |num_mismatched, count| {
+                format!(
+                    "\nThe two array-like structs are not close to within a relative tolerance of \
+                    {} and an absolute tolerance of {}.\n --> Mismatched Elements: {}/{}\n --> \
+                    Largest Absolute Difference: {} (index {})\n --> Largest Relative Difference: \
+                    {} (index {})",
+                    rtol,
+                    atol,
+                    num_mismatched,
+                    count,
+                    max_abs_diff,
+                    idx_max_abs_diff,
+                    max_rel_diff,
+                    idx_max_rel_diff
+                )
+            })
+        })
+    }};
+}
+
+/// Asserts element-wise closeness of two array-like structs to within a specified combination of
+/// relative and absolute tolerances.
+///
+/// This macro iterates over the elements of the two structs and checks if each pair of elements is
+/// close to within the specified tolerances using [`Compare::is_close`]. Additionally, this macro
+/// also checks whether the two structs have the same number of elements.
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare against (i.e. the reference values). Must either
+///   implement the [`Iterator`] trait or have an `iter` method.
+/// * `rtol` - Relative tolerance.
+/// * `atol` - Absolute tolerance.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
+/// * If the two array-like structs do not have the same number of elements. In this case, the
+///   number of elements in each array-like struct is also printed.
+/// * If any of the element-wise comparisons fail. In this case, the largest absolute and relative
+///   differences (along with the indices at which they occur) are printed, followed by the two
+///   array-like structs, with the mismatched elements shown in bolded red.
+///
+/// # Note
+///
+/// See [`Compare::is_close`] for details on how closeness is defined.
+///
+/// # Warning
+///
+/// 2D `ndarray` arrays and `nalgebra` matrices iterate over their elements in different orders
+/// (`ndarray` uses a row-major layout, while `nalgebra` uses a column-major layout). They can only
+/// be compared directly when the `ndarray` and `nalgebra` features are enabled, in which case
+/// their elements are matched up by their `(row, col)` indices.
+///
+/// # Examples
+///
+/// [`std::array`]
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr1 = [1e-10, 1000.001, 3.3];
+/// let arr2 = [0.0, 1000.0, 3.3];
+/// assert_arrays_close!(&arr1, &arr2, 1e-5, 1e-8);
+/// ```
+///
+/// Mix of 1D array-like structs
+///
+/// ```
+/// use nalgebra::Vector3;
+/// use ndarray::Array1;
+/// use numtest::*;
+///
+/// let std_vec = vec![0.0, 2.2, 3.3];
+/// let ndarray_arr = Array1::from_vec(vec![1e-9, 2.2, 3.3]);
+/// let nalgebra_vec = Vector3::new(0.0, 2.2001, 3.3001);
+///
+/// assert_arrays_close!(&ndarray_arr, &std_vec, 1e-5, 1e-8);
+/// assert_arrays_close!(&nalgebra_vec, &std_vec, 1e-4, 1e-8);
+/// ```
+#[macro_export]
+macro_rules! assert_arrays_close {
+    ($arr1:expr, $arr2:expr, $rtol:expr, $atol:expr) => {{
+        if let Err(error) = $crate::check_arrays_close!($arr1, $arr2, $rtol, $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($arr1:expr, $arr2:expr, $rtol:expr, $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_close!($arr1, $arr2, $rtol, $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{ColorChoice, with_color_choice};
     use nalgebra::{Matrix3, Vector3};
     use ndarray::{Array1, Array2};
 
@@ -841,12 +1808,12 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 1/3\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n                    2.2e0                     2.2e0\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 1/3\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n    1  2.2e0   2.2e0         0         0    pass\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n"
     )]
     fn test_std_array_exact_fail() {
         let arr1: [f64; 3] = [1.1, 2.2, 3.3];
         let arr2: [f64; 3] = [1.1, 2.2, 3.33];
-        assert_arrays_equal!(&arr1, &arr2);
+        with_color_choice(ColorChoice::Always, || assert_arrays_equal!(&arr1, &arr2));
     }
 
     #[test]
@@ -858,12 +1825,12 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 1/3\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n                    2.2e0                     2.2e0\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 1/3\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n    1  2.2e0   2.2e0         0         0    pass\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n"
     )]
     fn test_std_vec_exact_fail() {
         let vec1 = Vec::from([1.1, 2.2, 3.3]);
         let vec2 = Vec::from([1.1, 2.2, 3.33]);
-        assert_arrays_equal!(&vec1, &vec2);
+        with_color_choice(ColorChoice::Always, || assert_arrays_equal!(&vec1, &vec2));
     }
 
     #[test]
@@ -875,12 +1842,12 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 1/3\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n                    2.2e0                     2.2e0\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 1/3\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n    1  2.2e0   2.2e0         0         0    pass\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n"
     )]
     fn test_ndarray_array1_exact_fail() {
         let arr1 = Array1::from_vec(vec![1.1, 2.2, 3.3]);
         let arr2 = Array1::from_vec(vec![1.1, 2.2, 3.33]);
-        assert_arrays_equal!(&arr1, &arr2);
+        with_color_choice(ColorChoice::Always, || assert_arrays_equal!(&arr1, &arr2));
     }
 
     #[test]
@@ -891,13 +1858,22 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 2/6\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n                    2.2e0                     2.2e0\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n                    4.4e0                     4.4e0\n                    5.5e0                     5.5e0\n\u{1b}[31;1m                    6.6e0\u{1b}[0m \u{1b}[31;1m                   6.66e0\u{1b}[0m\n"
+    #[cfg_attr(
+        not(feature = "ndarray"),
+        should_panic(
+            expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 2/6\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n    1  2.2e0   2.2e0         0         0    pass\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n    3  4.4e0   4.4e0         0         0    pass\n    4  5.5e0   5.5e0         0         0    pass\n\u{1b}[31;1m    5  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n"
+        )
+    )]
+    #[cfg_attr(
+        feature = "ndarray",
+        should_panic(
+            expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 2/6\n\n index   arr1    arr2  abs diff  rel diff  result\n------  -----  ------  --------  --------  ------\n(0, 0)  1.1e0   1.1e0         0         0    pass\n(0, 1)  2.2e0   2.2e0         0         0    pass\n\u{1b}[31;1m(0, 2)  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n(1, 0)  4.4e0   4.4e0         0         0    pass\n(1, 1)  5.5e0   5.5e0         0         0    pass\n\u{1b}[31;1m(1, 2)  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n"
+        )
     )]
     fn test_ndarray_array2_exact_fail() {
         let arr1 = Array2::from_shape_vec((2, 3), vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6]).unwrap();
         let arr2 = Array2::from_shape_vec((2, 3), vec![1.1, 2.2, 3.33, 4.4, 5.5, 6.66]).unwrap();
-        assert_arrays_equal!(&arr1, &arr2);
+        with_color_choice(ColorChoice::Always, || assert_arrays_equal!(&arr1, &arr2));
     }
 
     #[test]
@@ -909,12 +1885,12 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 1/3\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n                    2.2e0                     2.2e0\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 1/3\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n    1  2.2e0   2.2e0         0         0    pass\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n"
     )]
     fn test_nalgebra_vector3_exact_fail() {
         let vec1 = Vector3::new(1.1, 2.2, 3.3);
         let vec2 = Vector3::new(1.1, 2.2, 3.33);
-        assert_arrays_equal!(&vec1, &vec2);
+        with_color_choice(ColorChoice::Always, || assert_arrays_equal!(&vec1, &vec2));
     }
 
     #[test]
@@ -925,13 +1901,22 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 3/9\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n                    4.4e0                     4.4e0\n                    7.7e0                     7.7e0\n                    2.2e0                     2.2e0\n                    5.5e0                     5.5e0\n                    8.8e0                     8.8e0\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n\u{1b}[31;1m                    6.6e0\u{1b}[0m \u{1b}[31;1m                   6.66e0\u{1b}[0m\n\u{1b}[31;1m                    9.9e0\u{1b}[0m \u{1b}[31;1m                   9.99e0\u{1b}[0m\n"
+    #[cfg_attr(
+        not(feature = "nalgebra"),
+        should_panic(
+            expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 3/9\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n    1  4.4e0   4.4e0         0         0    pass\n    2  7.7e0   7.7e0         0         0    pass\n    3  2.2e0   2.2e0         0         0    pass\n    4  5.5e0   5.5e0         0         0    pass\n    5  8.8e0   8.8e0         0         0    pass\n\u{1b}[31;1m    6  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    7  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    8  9.9e0  9.99e0      9e-2  9.009e-3    FAIL\u{1b}[0m\n"
+        )
+    )]
+    #[cfg_attr(
+        feature = "nalgebra",
+        should_panic(
+            expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 3/9\n\n index   arr1    arr2  abs diff  rel diff  result\n------  -----  ------  --------  --------  ------\n(0, 0)  1.1e0   1.1e0         0         0    pass\n(1, 0)  4.4e0   4.4e0         0         0    pass\n(2, 0)  7.7e0   7.7e0         0         0    pass\n(0, 1)  2.2e0   2.2e0         0         0    pass\n(1, 1)  5.5e0   5.5e0         0         0    pass\n(2, 1)  8.8e0   8.8e0         0         0    pass\n\u{1b}[31;1m(0, 2)  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(1, 2)  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(2, 2)  9.9e0  9.99e0      9e-2  9.009e-3    FAIL\u{1b}[0m\n"
+        )
     )]
     fn test_nalgebra_matrix3_exact_fail() {
         let mat1 = Matrix3::new(1.1, 2.2, 3.3, 4.4, 5.5, 6.6, 7.7, 8.8, 9.9);
         let mat2 = Matrix3::new(1.1, 2.2, 3.33, 4.4, 5.5, 6.66, 7.7, 8.8, 9.99);
-        assert_arrays_equal!(&mat1, &mat2);
+        with_color_choice(ColorChoice::Always, || assert_arrays_equal!(&mat1, &mat2));
     }
 
     #[test]
@@ -943,12 +1928,14 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 2/3\n --> Maximum Decimal Places of Precision: 1\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 2/3\n --> Maximum Decimal Places of Precision: 1\n\nindex   arr1    arr2  abs diff  decimal  result\n-----  -----  ------  --------  -------  ------\n    0  1.1e0   1.1e0         0      307    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2        1    FAIL\u{1b}[0m\n"
     )]
     fn test_std_array_decimal_fail() {
         let arr1: [f64; 3] = [1.1, 2.2, 3.3];
         let arr2: [f64; 3] = [1.1, 2.22, 3.33];
-        assert_arrays_equal_to_decimal!(&arr1, &arr2, 2);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_decimal!(&arr1, &arr2, 2);
+        });
     }
 
     #[test]
@@ -960,12 +1947,14 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 2/3\n --> Maximum Decimal Places of Precision: 1\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 2/3\n --> Maximum Decimal Places of Precision: 1\n\nindex   arr1    arr2  abs diff  decimal  result\n-----  -----  ------  --------  -------  ------\n    0  1.1e0   1.1e0         0      307    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2        1    FAIL\u{1b}[0m\n"
     )]
     fn test_std_vec_decimal_fail() {
         let vec1 = Vec::from([1.1, 2.2, 3.3]);
         let vec2 = Vec::from([1.1, 2.22, 3.33]);
-        assert_arrays_equal_to_decimal!(&vec1, &vec2, 2);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_decimal!(&vec1, &vec2, 2);
+        });
     }
 
     #[test]
@@ -977,12 +1966,14 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 2/3\n --> Maximum Decimal Places of Precision: 1\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 2/3\n --> Maximum Decimal Places of Precision: 1\n\nindex   arr1    arr2  abs diff  decimal  result\n-----  -----  ------  --------  -------  ------\n    0  1.1e0   1.1e0         0      307    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2        1    FAIL\u{1b}[0m\n"
     )]
     fn test_ndarray_array1_decimal_fail() {
         let arr1 = Array1::from_vec(vec![1.1, 2.2, 3.3]);
         let arr2 = Array1::from_vec(vec![1.1, 2.22, 3.33]);
-        assert_arrays_equal_to_decimal!(&arr1, &arr2, 2);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_decimal!(&arr1, &arr2, 2);
+        });
     }
 
     #[test]
@@ -993,13 +1984,24 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 4/6\n --> Maximum Decimal Places of Precision: 1\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n                    4.4e0                     4.4e0\n\u{1b}[31;1m                    5.5e0\u{1b}[0m \u{1b}[31;1m                   5.55e0\u{1b}[0m\n\u{1b}[31;1m                    6.6e0\u{1b}[0m \u{1b}[31;1m                   6.66e0\u{1b}[0m\n"
+    #[cfg_attr(
+        not(feature = "ndarray"),
+        should_panic(
+            expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 4/6\n --> Maximum Decimal Places of Precision: 1\n\nindex   arr1    arr2  abs diff  decimal  result\n-----  -----  ------  --------  -------  ------\n    0  1.1e0   1.1e0         0      307    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2        1    FAIL\u{1b}[0m\n    3  4.4e0   4.4e0         0      307    pass\n\u{1b}[31;1m    4  5.5e0  5.55e0      5e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m    5  6.6e0  6.66e0      6e-2        1    FAIL\u{1b}[0m\n"
+        )
+    )]
+    #[cfg_attr(
+        feature = "ndarray",
+        should_panic(
+            expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 4/6\n --> Maximum Decimal Places of Precision: 1\n\n index   arr1    arr2  abs diff  decimal  result\n------  -----  ------  --------  -------  ------\n(0, 0)  1.1e0   1.1e0         0      307    pass\n\u{1b}[31;1m(0, 1)  2.2e0  2.22e0      2e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m(0, 2)  3.3e0  3.33e0      3e-2        1    FAIL\u{1b}[0m\n(1, 0)  4.4e0   4.4e0         0      307    pass\n\u{1b}[31;1m(1, 1)  5.5e0  5.55e0      5e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m(1, 2)  6.6e0  6.66e0      6e-2        1    FAIL\u{1b}[0m\n"
+        )
     )]
     fn test_ndarray_array2_decimal_fail() {
         let arr1 = Array2::from_shape_vec((2, 3), vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6]).unwrap();
         let arr2 = Array2::from_shape_vec((2, 3), vec![1.1, 2.22, 3.33, 4.4, 5.55, 6.66]).unwrap();
-        assert_arrays_equal_to_decimal!(&arr1, &arr2, 2);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_decimal!(&arr1, &arr2, 2);
+        });
     }
 
     #[test]
@@ -1011,12 +2013,14 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 2/3\n --> Maximum Decimal Places of Precision: 1\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 2/3\n --> Maximum Decimal Places of Precision: 1\n\nindex   arr1    arr2  abs diff  decimal  result\n-----  -----  ------  --------  -------  ------\n    0  1.1e0   1.1e0         0      307    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2        1    FAIL\u{1b}[0m\n"
     )]
     fn test_nalgebra_vector3_decimal_fail() {
         let vec1 = Vector3::new(1.1, 2.2, 3.3);
         let vec2 = Vector3::new(1.1, 2.22, 3.33);
-        assert_arrays_equal_to_decimal!(&vec1, &vec2, 2);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_decimal!(&vec1, &vec2, 2);
+        });
     }
 
     #[test]
@@ -1027,13 +2031,57 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 6/9\n --> Maximum Decimal Places of Precision: 1\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n                    4.4e0                     4.4e0\n                    7.7e0                     7.7e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    5.5e0\u{1b}[0m \u{1b}[31;1m                   5.55e0\u{1b}[0m\n\u{1b}[31;1m                    8.8e0\u{1b}[0m \u{1b}[31;1m                   8.88e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n\u{1b}[31;1m                    6.6e0\u{1b}[0m \u{1b}[31;1m                   6.66e0\u{1b}[0m\n\u{1b}[31;1m                    9.9e0\u{1b}[0m \u{1b}[31;1m                   9.99e0\u{1b}[0m\n"
+    #[cfg_attr(
+        not(feature = "nalgebra"),
+        should_panic(
+            expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 6/9\n --> Maximum Decimal Places of Precision: 1\n\nindex   arr1    arr2  abs diff  decimal  result\n-----  -----  ------  --------  -------  ------\n    0  1.1e0   1.1e0         0      307    pass\n    1  4.4e0   4.4e0         0      307    pass\n    2  7.7e0   7.7e0         0      307    pass\n\u{1b}[31;1m    3  2.2e0  2.22e0      2e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m    4  5.5e0  5.55e0      5e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m    5  8.8e0  8.88e0      8e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m    6  3.3e0  3.33e0      3e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m    7  6.6e0  6.66e0      6e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m    8  9.9e0  9.99e0      9e-2        1    FAIL\u{1b}[0m\n"
+        )
+    )]
+    #[cfg_attr(
+        feature = "nalgebra",
+        should_panic(
+            expected = "\nThe two array-like structs are not equal to 2 decimal places.\n --> Mismatched Elements: 6/9\n --> Maximum Decimal Places of Precision: 1\n\n index   arr1    arr2  abs diff  decimal  result\n------  -----  ------  --------  -------  ------\n(0, 0)  1.1e0   1.1e0         0      307    pass\n(1, 0)  4.4e0   4.4e0         0      307    pass\n(2, 0)  7.7e0   7.7e0         0      307    pass\n\u{1b}[31;1m(0, 1)  2.2e0  2.22e0      2e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m(1, 1)  5.5e0  5.55e0      5e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m(2, 1)  8.8e0  8.88e0      8e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m(0, 2)  3.3e0  3.33e0      3e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m(1, 2)  6.6e0  6.66e0      6e-2        1    FAIL\u{1b}[0m\n\u{1b}[31;1m(2, 2)  9.9e0  9.99e0      9e-2        1    FAIL\u{1b}[0m\n"
+        )
     )]
     fn test_nalgebra_matrix3_decimal_fail() {
         let mat1 = Matrix3::new(1.1, 2.2, 3.3, 4.4, 5.5, 6.6, 7.7, 8.8, 9.9);
         let mat2 = Matrix3::new(1.1, 2.22, 3.33, 4.4, 5.55, 6.66, 7.7, 8.88, 9.99);
-        assert_arrays_equal_to_decimal!(&mat1, &mat2, 2);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_decimal!(&mat1, &mat2, 2);
+        });
+    }
+
+    #[test]
+    fn test_std_array_sigfigs_pass() {
+        let arr1 = [6.02214076e23, 1.602176634e-19, 299792458.0];
+        let arr2 = [6.022e23, 1.602e-19, 2.998e8];
+        assert_arrays_equal_to_sigfigs!(&arr1, &arr2, 4);
+        let report = check_arrays_equal_to_sigfigs!(&arr1, &arr1, 4).unwrap();
+        assert_eq!(report.worst_difference, 17.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not equal to 4 significant figures.\n --> Mismatched Elements: 1/3\n --> Minimum Significant Figures: 3\n\nindex   arr1      arr2  abs diff  sigfigs  result\n-----  -----  --------  --------  -------  ------\n\u{1b}[31;1m    0  1e-10  1.01e-10     1e-12        3    FAIL\u{1b}[0m\n    1    2e0       2e0         0        9    pass\n    2   3e10      3e10         0        9    pass\n"
+    )]
+    fn test_std_array_sigfigs_fail() {
+        let arr1: [f32; 3] = [1.0e-10, 2.0, 3.0e10];
+        let arr2: [f32; 3] = [1.01e-10, 2.0, 3.0e10];
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_sigfigs!(&arr1, &arr2, 4);
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not equal to 2 significant figures.\n --> Mismatched Elements: 1/4\n --> Minimum Significant Figures: 1\n\n index  arr1   arr2  abs diff  sigfigs  result\n------  ----  -----  --------  -------  ------\n(0, 0)   1e0    1e0         0       17    pass\n(0, 1)   2e0    2e0         0       17    pass\n\u{1b}[31;1m(1, 0)   3e0  3.3e0      3e-1        1    FAIL\u{1b}[0m\n(1, 1)   4e0    4e0         0       17    pass\n"
+    )]
+    fn test_nested_array_sigfigs_fail() {
+        let arr1 = [[1.0, 2.0], [3.0, 4.0]];
+        let arr2 = [[1.0, 2.0], [3.3, 4.0]];
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_sigfigs!(&arr1, &arr2, 2);
+        });
     }
 
     #[test]
@@ -1045,12 +2093,14 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 2/3\n --> Largest Absolute Difference: 0.029999971389770508\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 2/3\n --> Largest Absolute Difference: 0.029999971389770508\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n"
     )]
     fn test_std_array_atol_fail() {
         let arr1: [f32; 3] = [1.1, 2.2, 3.3];
         let arr2: [f32; 3] = [1.1, 2.22, 3.33];
-        assert_arrays_equal_to_atol!(&arr1, &arr2, 0.01);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_atol!(&arr1, &arr2, 0.01);
+        });
     }
 
     #[test]
@@ -1062,12 +2112,14 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 2/3\n --> Largest Absolute Difference: 0.03000000000000025\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 2/3\n --> Largest Absolute Difference: 0.03000000000000025\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n"
     )]
     fn test_std_vec_atol_fail() {
         let vec1 = Vec::from([1.1, 2.2, 3.3]);
         let vec2 = Vec::from([1.1, 2.22, 3.33]);
-        assert_arrays_equal_to_atol!(&vec1, &vec2, 0.01);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_atol!(&vec1, &vec2, 0.01);
+        });
     }
 
     #[test]
@@ -1079,12 +2131,14 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 2/3\n --> Largest Absolute Difference: 0.03000000000000025\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 2/3\n --> Largest Absolute Difference: 0.03000000000000025\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n"
     )]
     fn test_ndarray_array1_atol_fail() {
         let arr1 = Array1::from_vec(vec![1.1, 2.2, 3.3]);
         let arr2 = Array1::from_vec(vec![1.1, 2.22, 3.33]);
-        assert_arrays_equal_to_atol!(&arr1, &arr2, 0.01);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_atol!(&arr1, &arr2, 0.01);
+        });
     }
 
     #[test]
@@ -1095,13 +2149,24 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 4/6\n --> Largest Absolute Difference: 0.0600000000000005\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n                    4.4e0                     4.4e0\n\u{1b}[31;1m                    5.5e0\u{1b}[0m \u{1b}[31;1m                   5.55e0\u{1b}[0m\n\u{1b}[31;1m                    6.6e0\u{1b}[0m \u{1b}[31;1m                   6.66e0\u{1b}[0m\n"
+    #[cfg_attr(
+        not(feature = "ndarray"),
+        should_panic(
+            expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 4/6\n --> Largest Absolute Difference: 0.0600000000000005\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n    3  4.4e0   4.4e0         0         0    pass\n\u{1b}[31;1m    4  5.5e0  5.55e0      5e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    5  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n"
+        )
+    )]
+    #[cfg_attr(
+        feature = "ndarray",
+        should_panic(
+            expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 4/6\n --> Largest Absolute Difference: 0.0600000000000005\n\n index   arr1    arr2  abs diff  rel diff  result\n------  -----  ------  --------  --------  ------\n(0, 0)  1.1e0   1.1e0         0         0    pass\n\u{1b}[31;1m(0, 1)  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(0, 2)  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n(1, 0)  4.4e0   4.4e0         0         0    pass\n\u{1b}[31;1m(1, 1)  5.5e0  5.55e0      5e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(1, 2)  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n"
+        )
     )]
     fn test_ndarray_array2_atol_fail() {
         let arr1 = Array2::from_shape_vec((2, 3), vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6]).unwrap();
         let arr2 = Array2::from_shape_vec((2, 3), vec![1.1, 2.22, 3.33, 4.4, 5.55, 6.66]).unwrap();
-        assert_arrays_equal_to_atol!(&arr1, &arr2, 0.01);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_atol!(&arr1, &arr2, 0.01);
+        });
     }
 
     #[test]
@@ -1113,12 +2178,14 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 2/3\n --> Largest Absolute Difference: 0.03000000000000025\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 2/3\n --> Largest Absolute Difference: 0.03000000000000025\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n"
     )]
     fn test_nalgebra_vector3_atol_fail() {
         let vec1 = Vector3::new(1.1, 2.2, 3.3);
         let vec2 = Vector3::new(1.1, 2.22, 3.33);
-        assert_arrays_equal_to_atol!(&vec1, &vec2, 0.01);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_atol!(&vec1, &vec2, 0.01);
+        });
     }
 
     #[test]
@@ -1129,13 +2196,24 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 6/9\n --> Largest Absolute Difference: 0.08999999999999986\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n                    4.4e0                     4.4e0\n                    7.7e0                     7.7e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    5.5e0\u{1b}[0m \u{1b}[31;1m                   5.55e0\u{1b}[0m\n\u{1b}[31;1m                    8.8e0\u{1b}[0m \u{1b}[31;1m                   8.88e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n\u{1b}[31;1m                    6.6e0\u{1b}[0m \u{1b}[31;1m                   6.66e0\u{1b}[0m\n\u{1b}[31;1m                    9.9e0\u{1b}[0m \u{1b}[31;1m                   9.99e0\u{1b}[0m\n"
+    #[cfg_attr(
+        not(feature = "nalgebra"),
+        should_panic(
+            expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 6/9\n --> Largest Absolute Difference: 0.08999999999999986\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n    1  4.4e0   4.4e0         0         0    pass\n    2  7.7e0   7.7e0         0         0    pass\n\u{1b}[31;1m    3  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    4  5.5e0  5.55e0      5e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    5  8.8e0  8.88e0      8e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    6  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    7  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    8  9.9e0  9.99e0      9e-2  9.009e-3    FAIL\u{1b}[0m\n"
+        )
+    )]
+    #[cfg_attr(
+        feature = "nalgebra",
+        should_panic(
+            expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 6/9\n --> Largest Absolute Difference: 0.08999999999999986\n\n index   arr1    arr2  abs diff  rel diff  result\n------  -----  ------  --------  --------  ------\n(0, 0)  1.1e0   1.1e0         0         0    pass\n(1, 0)  4.4e0   4.4e0         0         0    pass\n(2, 0)  7.7e0   7.7e0         0         0    pass\n\u{1b}[31;1m(0, 1)  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(1, 1)  5.5e0  5.55e0      5e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(2, 1)  8.8e0  8.88e0      8e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(0, 2)  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(1, 2)  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(2, 2)  9.9e0  9.99e0      9e-2  9.009e-3    FAIL\u{1b}[0m\n"
+        )
     )]
     fn test_nalgebra_matrix3_atol_fail() {
         let mat1 = Matrix3::new(1.1, 2.2, 3.3, 4.4, 5.5, 6.6, 7.7, 8.8, 9.9);
         let mat2 = Matrix3::new(1.1, 2.22, 3.33, 4.4, 5.55, 6.66, 7.7, 8.88, 9.99);
-        assert_arrays_equal_to_atol!(&mat1, &mat2, 0.01);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_atol!(&mat1, &mat2, 0.01);
+        });
     }
 
     #[test]
@@ -1147,12 +2225,14 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 2/3\n --> Largest Relative Difference: 0.009009000845253468\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 2/3\n --> Largest Relative Difference: 0.009009000845253468\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n"
     )]
     fn test_std_array_rtol_fail() {
         let arr1: [f32; 3] = [1.1, 2.2, 3.3];
         let arr2: [f32; 3] = [1.1, 2.22, 3.33];
-        assert_arrays_equal_to_rtol!(&arr1, &arr2, 0.001);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_rtol!(&arr1, &arr2, 0.001);
+        });
     }
 
     #[test]
@@ -1164,12 +2244,14 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 2/3\n --> Largest Relative Difference: 0.009009009009009084\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 2/3\n --> Largest Relative Difference: 0.009009009009009084\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n"
     )]
     fn test_std_vec_rtol_fail() {
         let vec1 = Vec::from([1.1, 2.2, 3.3]);
         let vec2 = Vec::from([1.1, 2.22, 3.33]);
-        assert_arrays_equal_to_rtol!(&vec1, &vec2, 0.001);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_rtol!(&vec1, &vec2, 0.001);
+        });
     }
 
     #[test]
@@ -1181,12 +2263,14 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 2/3\n --> Largest Relative Difference: 0.009009009009009084\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 2/3\n --> Largest Relative Difference: 0.009009009009009084\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n"
     )]
     fn test_ndarray_array1_rtol_fail() {
         let arr1 = Array1::from_vec(vec![1.1, 2.2, 3.3]);
         let arr2 = Array1::from_vec(vec![1.1, 2.22, 3.33]);
-        assert_arrays_equal_to_rtol!(&arr1, &arr2, 0.001);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_rtol!(&arr1, &arr2, 0.001);
+        });
     }
 
     #[test]
@@ -1197,13 +2281,24 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 4/6\n --> Largest Relative Difference: 0.009009009009009084\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n                    4.4e0                     4.4e0\n\u{1b}[31;1m                    5.5e0\u{1b}[0m \u{1b}[31;1m                   5.55e0\u{1b}[0m\n\u{1b}[31;1m                    6.6e0\u{1b}[0m \u{1b}[31;1m                   6.66e0\u{1b}[0m\n"
+    #[cfg_attr(
+        not(feature = "ndarray"),
+        should_panic(
+            expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 4/6\n --> Largest Relative Difference: 0.009009009009009084\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n    3  4.4e0   4.4e0         0         0    pass\n\u{1b}[31;1m    4  5.5e0  5.55e0      5e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    5  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n"
+        )
+    )]
+    #[cfg_attr(
+        feature = "ndarray",
+        should_panic(
+            expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 4/6\n --> Largest Relative Difference: 0.009009009009009084\n\n index   arr1    arr2  abs diff  rel diff  result\n------  -----  ------  --------  --------  ------\n(0, 0)  1.1e0   1.1e0         0         0    pass\n\u{1b}[31;1m(0, 1)  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(0, 2)  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n(1, 0)  4.4e0   4.4e0         0         0    pass\n\u{1b}[31;1m(1, 1)  5.5e0  5.55e0      5e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(1, 2)  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n"
+        )
     )]
     fn test_ndarray_array2_rtol_fail() {
         let arr1 = Array2::from_shape_vec((2, 3), vec![1.1, 2.2, 3.3, 4.4, 5.5, 6.6]).unwrap();
         let arr2 = Array2::from_shape_vec((2, 3), vec![1.1, 2.22, 3.33, 4.4, 5.55, 6.66]).unwrap();
-        assert_arrays_equal_to_rtol!(&arr1, &arr2, 0.001);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_rtol!(&arr1, &arr2, 0.001);
+        });
     }
 
     #[test]
@@ -1215,12 +2310,14 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 2/3\n --> Largest Relative Difference: 0.009009009009009084\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n"
+        expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 2/3\n --> Largest Relative Difference: 0.009009009009009084\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n\u{1b}[31;1m    1  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    2  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n"
     )]
     fn test_nalgebra_vector3_rtol_fail() {
         let vec1 = Vector3::new(1.1, 2.2, 3.3);
         let vec2 = Vector3::new(1.1, 2.22, 3.33);
-        assert_arrays_equal_to_rtol!(&vec1, &vec2, 0.001);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_rtol!(&vec1, &vec2, 0.001);
+        });
     }
 
     #[test]
@@ -1231,12 +2328,519 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 6/9\n --> Largest Relative Difference: 0.009009009009009084\n\n                     arr1                      arr2\n                     ----                      ----\n                    1.1e0                     1.1e0\n                    4.4e0                     4.4e0\n                    7.7e0                     7.7e0\n\u{1b}[31;1m                    2.2e0\u{1b}[0m \u{1b}[31;1m                   2.22e0\u{1b}[0m\n\u{1b}[31;1m                    5.5e0\u{1b}[0m \u{1b}[31;1m                   5.55e0\u{1b}[0m\n\u{1b}[31;1m                    8.8e0\u{1b}[0m \u{1b}[31;1m                   8.88e0\u{1b}[0m\n\u{1b}[31;1m                    3.3e0\u{1b}[0m \u{1b}[31;1m                   3.33e0\u{1b}[0m\n\u{1b}[31;1m                    6.6e0\u{1b}[0m \u{1b}[31;1m                   6.66e0\u{1b}[0m\n\u{1b}[31;1m                    9.9e0\u{1b}[0m \u{1b}[31;1m                   9.99e0\u{1b}[0m\n"
+    #[cfg_attr(
+        not(feature = "nalgebra"),
+        should_panic(
+            expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 6/9\n --> Largest Relative Difference: 0.009009009009009084\n\nindex   arr1    arr2  abs diff  rel diff  result\n-----  -----  ------  --------  --------  ------\n    0  1.1e0   1.1e0         0         0    pass\n    1  4.4e0   4.4e0         0         0    pass\n    2  7.7e0   7.7e0         0         0    pass\n\u{1b}[31;1m    3  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    4  5.5e0  5.55e0      5e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    5  8.8e0  8.88e0      8e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    6  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    7  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m    8  9.9e0  9.99e0      9e-2  9.009e-3    FAIL\u{1b}[0m\n"
+        )
+    )]
+    #[cfg_attr(
+        feature = "nalgebra",
+        should_panic(
+            expected = "\nThe two array-like structs are not equal to a relative tolerance of 0.001.\n --> Mismatched Elements: 6/9\n --> Largest Relative Difference: 0.009009009009009084\n\n index   arr1    arr2  abs diff  rel diff  result\n------  -----  ------  --------  --------  ------\n(0, 0)  1.1e0   1.1e0         0         0    pass\n(1, 0)  4.4e0   4.4e0         0         0    pass\n(2, 0)  7.7e0   7.7e0         0         0    pass\n\u{1b}[31;1m(0, 1)  2.2e0  2.22e0      2e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(1, 1)  5.5e0  5.55e0      5e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(2, 1)  8.8e0  8.88e0      8e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(0, 2)  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(1, 2)  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(2, 2)  9.9e0  9.99e0      9e-2  9.009e-3    FAIL\u{1b}[0m\n"
+        )
     )]
     fn test_nalgebra_matrix3_rtol_fail() {
         let mat1 = Matrix3::new(1.1, 2.2, 3.3, 4.4, 5.5, 6.6, 7.7, 8.8, 9.9);
         let mat2 = Matrix3::new(1.1, 2.22, 3.33, 4.4, 5.55, 6.66, 7.7, 8.88, 9.99);
-        assert_arrays_equal_to_rtol!(&mat1, &mat2, 0.001);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_rtol!(&mat1, &mat2, 0.001);
+        });
+    }
+
+    #[test]
+    fn test_std_array_ulps_pass() {
+        let arr1: [f64; 3] = [1.0, 2.0, 3.0];
+        let arr2: [f64; 3] = [1.0 + f64::EPSILON, 2.0, 3.0 - 2.0 * f64::EPSILON];
+        assert_arrays_equal_to_ulps!(&arr1, &arr2, 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not equal to within 1 ULPs.\n --> Mismatched Elements: 1/3\n --> Largest ULP Distance: 2\n\nindex  arr1                 arr2   abs diff  ulps  result\n-----  ----  -------------------  ---------  ----  ------\n    0   1e0                  1e0          0     0    pass\n    1   2e0                  2e0          0     0    pass\n\u{1b}[31;1m    2   3e0  3.000000000000001e0  8.882e-16     2    FAIL\u{1b}[0m\n"
+    )]
+    fn test_std_array_ulps_fail() {
+        let arr1: [f64; 3] = [1.0, 2.0, 3.0];
+        let arr2: [f64; 3] = [1.0, 2.0, 3.0 + 4.0 * f64::EPSILON];
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_ulps!(&arr1, &arr2, 1);
+        });
+    }
+
+    #[test]
+    fn test_std_vec_ulps_pass() {
+        let vec1: Vec<f32> = Vec::from([0.0, 1.0e-45, 1.0]);
+        let vec2: Vec<f32> = Vec::from([-0.0, -1.0e-45, 0.99999994]);
+        assert_arrays_equal_to_ulps!(&vec1, &vec2, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not equal to within 2 ULPs.\n --> Mismatched Elements: 1/3\n --> Largest ULP Distance: 18446744073709551615\n\nindex  arr1  arr2  abs diff      ulps  result\n-----  ----  ----  --------  --------  ------\n    0   0e0   0e0         0         0    pass\n\u{1b}[31;1m    1   NaN   1e0       NaN  1.845e19    FAIL\u{1b}[0m\n    2   1e0   1e0         0         0    pass\n"
+    )]
+    fn test_std_vec_ulps_fail() {
+        let vec1: Vec<f32> = Vec::from([0.0, f32::NAN, 1.0]);
+        let vec2: Vec<f32> = Vec::from([0.0, 1.0, 1.0]);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_ulps!(&vec1, &vec2, 2);
+        });
+    }
+
+    #[test]
+    fn test_nalgebra_matrix3_ulps_pass() {
+        let mat1 = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let mut mat2 = mat1;
+        mat2[(2, 2)] += 8.0 * f64::EPSILON;
+        assert_arrays_equal_to_ulps!(&mat1, &mat2, 1);
+    }
+
+    #[test]
+    fn test_std_array_close_pass() {
+        let arr1: [f64; 3] = [1e-10, 1000.001, 3.3];
+        let arr2: [f64; 3] = [0.0, 1000.0, 3.3];
+        assert_arrays_close!(&arr1, &arr2, 1e-5, 1e-8);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not close to within a relative tolerance of 0.00001 and an absolute tolerance of 0.00000001.\n --> Mismatched Elements: 2/3\n --> Largest Absolute Difference: 0.10000000000002274 (index 1)\n --> Largest Relative Difference: inf (index 0)\n\nindex      arr1   arr2  abs diff  rel diff  result\n-----  --------  -----  --------  --------  ------\n\u{1b}[31;1m    0      1e-7    0e0      1e-7         1    FAIL\u{1b}[0m\n\u{1b}[31;1m    1  1.0001e3    1e3      1e-1  9.999e-5    FAIL\u{1b}[0m\n    2     3.3e0  3.3e0         0         0    pass\n"
+    )]
+    fn test_std_array_close_fail() {
+        let arr1: [f64; 3] = [1e-7, 1000.1, 3.3];
+        let arr2: [f64; 3] = [0.0, 1000.0, 3.3];
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_close!(&arr1, &arr2, 1e-5, 1e-8);
+        });
+    }
+
+    #[test]
+    fn test_ndarray_array2_close_pass() {
+        let arr1 = Array2::from_shape_vec((2, 2), vec![1e-9, 2.2, 3.3, 4.4001]).unwrap();
+        let arr2 = Array2::from_shape_vec((2, 2), vec![0.0, 2.2, 3.3, 4.4]).unwrap();
+        assert_arrays_close!(&arr1, &arr2, 1e-4, 1e-8);
+    }
+
+    #[test]
+    fn test_std_array_check_atol_ok() {
+        let arr1: [f64; 3] = [1.1, 2.2, 3.3];
+        let arr2: [f64; 3] = [1.1, 2.25, 3.3];
+        let report = check_arrays_equal_to_atol!(&arr1, &arr2, 0.1).unwrap();
+        assert_eq!(report.num_elements, 3);
+        assert!((report.worst_difference - 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_std_vec_check_exact_err() {
+        let vec1 = vec![1.0, 2.0, 3.0, 4.0];
+        let vec2 = vec![1.0, 2.5, 3.0, 2.0];
+        let error = check_arrays_equal!(&vec1, &vec2).unwrap_err();
+        assert_eq!(*error.kind, crate::ComparisonErrorKind::ValueMismatch);
+        assert_eq!(error.num_elements, 4);
+        assert_eq!(error.mismatched_indices(), vec![1, 3]);
+        assert_eq!(
+            error.mismatches[1],
+            crate::Mismatch {
+                index: 3,
+                multi_index: None,
+                a: 4.0,
+                b: 2.0,
+                difference: 2.0
+            }
+        );
+        assert_eq!(error.worst_difference, 2.0);
+        assert!(error.to_string().ends_with(&*error.table));
+    }
+
+    #[test]
+    fn test_std_array_check_count_mismatch() {
+        let arr1: [f64; 3] = [1.1, 2.2, 3.3];
+        let arr2: [f64; 2] = [1.1, 2.2];
+        let error = check_arrays_close!(&arr1, &arr2, 1e-5, 1e-8).unwrap_err();
+        assert_eq!(
+            *error.kind,
+            crate::ComparisonErrorKind::CountMismatch {
+                count1: 3,
+                count2: 2
+            }
+        );
+        assert!(error.mismatches.is_empty());
+    }
+
+    #[test]
+    fn test_ndarray_array2_check_decimal_err() {
+        let arr1 = Array2::from_shape_vec((2, 2), vec![1.1, 2.2, 3.3, 4.4]).unwrap();
+        let arr2 = Array2::from_shape_vec((2, 2), vec![1.1, 2.2, 3.33, 4.4]).unwrap();
+        let error = check_arrays_equal_to_decimal!(&arr1, &arr2, 2).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![2]);
+        assert_eq!(error.worst_difference, 1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "case 2 failed\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 1/3\n\n"
+    )]
+    fn test_std_array_exact_custom_message() {
+        let arr1: [f64; 3] = [1.1, 2.2, 3.3];
+        let arr2: [f64; 3] = [1.1, 2.2, 3.33];
+        let case = 2;
+        assert_arrays_equal!(&arr1, &arr2, "case {case} failed");
+    }
+
+    #[test]
+    fn test_std_array_custom_message_pass() {
+        let arr1: [f64; 3] = [1.1, 2.2, 3.3];
+        let arr2: [f64; 3] = [1.1, 2.22, 3.33];
+        assert_arrays_equal_to_decimal!(&arr1, &arr2, 1, "case {}", 1);
+        assert_arrays_equal_to_atol!(&arr1, &arr2, 0.1, "case {}", 2);
+        assert_arrays_equal_to_rtol!(&arr1, &arr2, 0.01, "case {}", 3);
+        assert_arrays_equal_to_ulps!(&arr1, &arr1, 0, "case {}", 4);
+        assert_arrays_close!(&arr1, &arr2, 0.01, 0.0, "case {}", 5);
+    }
+
+    #[test]
+    #[cfg(feature = "nalgebra")]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 3/9\n --> Largest Absolute Difference: 0.08999999999999986\n\n index   arr1    arr2  abs diff  rel diff  result\n------  -----  ------  --------  --------  ------\n(0, 0)  1.1e0   1.1e0         0         0    pass\n(1, 0)  4.4e0   4.4e0         0         0    pass\n(2, 0)  7.7e0   7.7e0         0         0    pass\n(0, 1)  2.2e0   2.2e0         0         0    pass\n(1, 1)  5.5e0   5.5e0         0         0    pass\n(2, 1)  8.8e0   8.8e0         0         0    pass\n\u{1b}[31;1m(0, 2)  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(1, 2)  6.6e0  6.66e0      6e-2  9.009e-3    FAIL\u{1b}[0m\n\u{1b}[31;1m(2, 2)  9.9e0  9.99e0      9e-2  9.009e-3    FAIL\u{1b}[0m\n"
+    )]
+    fn test_nalgebra_matrix3_atol_fail_shaped() {
+        let mat1 = Matrix3::new(1.1, 2.2, 3.3, 4.4, 5.5, 6.6, 7.7, 8.8, 9.9);
+        let mat2 = Matrix3::new(1.1, 2.2, 3.33, 4.4, 5.5, 6.66, 7.7, 8.8, 9.99);
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_atol!(&mat1, &mat2, 0.01);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "ndarray")]
+    #[should_panic(
+        expected = "\nThe two arrays must have the same shape.\n --> arr1 shape: [3, 4]\n --> arr2 shape: [4, 3]\n"
+    )]
+    fn test_ndarray_array2_shape_mismatch() {
+        let arr1 = Array2::<f64>::zeros((3, 4));
+        let arr2 = Array2::<f64>::zeros((4, 3));
+        assert_arrays_equal!(&arr1, &arr2);
+    }
+
+    #[test]
+    #[cfg(feature = "ndarray")]
+    fn test_ndarray_array3_multi_index() {
+        let arr1 = ndarray::Array3::<f64>::zeros((2, 2, 2));
+        let mut arr2 = ndarray::Array3::<f64>::zeros((2, 2, 2));
+        arr2[[1, 0, 1]] = 1.0;
+        let error = check_arrays_equal!(&arr1, &arr2).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![5]);
+        assert_eq!(error.mismatches[0].multi_index, Some(vec![1, 0, 1]));
+        assert!(error.table.contains("[1, 0, 1]"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ndarray", feature = "nalgebra"))]
+    fn test_ndarray_array1_nalgebra_vector3_shape_pass() {
+        let arr = Array1::from_vec(vec![1.1, 2.2, 3.3]);
+        let vec = Vector3::new(1.1, 2.2, 3.3);
+        assert_arrays_equal!(&arr, &vec);
+    }
+
+    #[test]
+    #[cfg(all(feature = "ndarray", feature = "nalgebra"))]
+    fn test_nalgebra_matrix_ndarray_array2_mixed_layouts() {
+        let mat = nalgebra::Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        let arr = ndarray::arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_arrays_equal!(&mat, &arr);
+        assert_arrays_equal!(&arr, &mat);
+
+        let arr = ndarray::arr2(&[[1.0, 2.0, 3.0], [4.0, 5.5, 6.0]]);
+        let error = check_arrays_equal!(&mat, &arr).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![4]);
+        assert_eq!(error.mismatches[0].multi_index, Some(vec![1, 1]));
+        let error = check_arrays_equal!(&arr, &mat).unwrap_err();
+        assert_eq!(error.mismatches[0].multi_index, Some(vec![1, 1]));
+    }
+
+    #[test]
+    fn test_std_nested_array_exact_pass() {
+        let arr1 = [[1.1, 2.2], [3.3, 4.4]];
+        let arr2 = [[1.1, 2.2], [3.3, 4.4]];
+        assert_arrays_equal!(&arr1, &arr2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not exactly equal.\n --> Mismatched Elements: 1/4\n\n index   arr1    arr2  abs diff  rel diff  result\n------  -----  ------  --------  --------  ------\n(0, 0)  1.1e0   1.1e0         0         0    pass\n(0, 1)  2.2e0   2.2e0         0         0    pass\n\u{1b}[31;1m(1, 0)  3.3e0  3.33e0      3e-2  9.009e-3    FAIL\u{1b}[0m\n(1, 1)  4.4e0   4.4e0         0         0    pass\n"
+    )]
+    fn test_std_nested_vec_exact_fail() {
+        let vec1 = vec![vec![1.1, 2.2], vec![3.3, 4.4]];
+        let vec2 = vec![vec![1.1, 2.2], vec![3.33, 4.4]];
+        with_color_choice(ColorChoice::Always, || assert_arrays_equal!(&vec1, &vec2));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe two arrays must have the same shape.\n --> arr1 shape: [2, 3]\n --> arr2 shape: [3, 2]\n"
+    )]
+    fn test_std_nested_array_shape_mismatch() {
+        let arr1 = [[1.1, 2.2, 3.3], [4.4, 5.5, 6.6]];
+        let arr2 = [[1.1, 2.2], [3.3, 4.4], [5.5, 6.6]];
+        assert_arrays_equal_to_decimal!(&arr1, &arr2, 3);
+    }
+
+    #[test]
+    fn test_std_nested_vec_mixed_flat_pass() {
+        let nested = vec![vec![1.1, 2.2], vec![3.3, 4.4]];
+        let flat = [1.1, 2.2, 3.3, 4.4];
+        assert_arrays_equal!(&nested, &flat);
+    }
+
+    #[test]
+    fn test_std_nested_array_close_multi_index() {
+        let arr1 = [[1e-10, 1000.001], [3.3, 4.4]];
+        let arr2 = [[0.0, 1000.0], [3.4, 4.4]];
+        let error = check_arrays_close!(&arr1, &arr2, 1e-5, 1e-8).unwrap_err();
+        assert_eq!(error.mismatches[0].multi_index, Some(vec![1, 0]));
+        assert!(
+            error
+                .message
+                .contains("Largest Absolute Difference: 0.10000000000000009 (index (1, 0))")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn test_std_vec_complex_atol_pass() {
+        use num_complex::Complex64;
+        let vec1 = vec![Complex64::new(1.0, 1.0), Complex64::new(2.0, -2.0)];
+        let vec2 = vec![Complex64::new(1.0, 1.0), Complex64::new(2.0, -2.0001)];
+        assert_arrays_equal_to_atol!(&vec1, &vec2, 1e-3);
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.001.\n --> Mismatched Elements: 1/2\n --> Largest Absolute Difference: 0.02345678901234649\n\nindex                                        arr1                          arr2  abs diff  rel diff  result\n-----  ------------------------------------------  ----------------------------  --------  --------  ------\n    0                                    1e0+1e0i                      1e0+1e0i         0         0    pass\n\u{1b}[31;1m    1  -1.2345678901234567e0+9.876543210987654e0i  -1.2345678901234567e0+9.9e0i  2.346e-2  2.351e-3    FAIL\u{1b}[0m\n"
+    )]
+    fn test_std_vec_complex_atol_fail() {
+        use num_complex::Complex64;
+        let vec1 = vec![
+            Complex64::new(1.0, 1.0),
+            Complex64::new(-1.2345678901234567, 9.876543210987654),
+        ];
+        let vec2 = vec![
+            Complex64::new(1.0, 1.0),
+            Complex64::new(-1.2345678901234567, 9.9),
+        ];
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_atol!(&vec1, &vec2, 1e-3);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn test_std_arr_half_pass() {
+        use half::{bf16, f16};
+        let arr1 = [f16::from_f32(1.1), f16::from_f32(2.2), f16::from_f32(3.3)];
+        let arr2 = [f16::from_f32(1.1), f16::from_f32(2.21), f16::from_f32(3.3)];
+        assert_arrays_equal_to_decimal!(&arr1, &arr2, 1);
+        assert_arrays_equal_to_atol!(&arr1, &arr2, f16::from_f32(0.02));
+        assert_arrays_equal_to_ulps!(&arr1, &arr1, 0);
+        let arr3 = [bf16::from_f32(1.1), bf16::from_f32(2.2)];
+        let arr4 = [bf16::from_f32(1.1), bf16::from_f32(2.21)];
+        assert_arrays_equal_to_rtol!(&arr3, &arr4, bf16::from_f32(0.01));
+        assert_arrays_close!(&arr3, &arr4, bf16::from_f32(0.01), bf16::ZERO);
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not equal to an absolute tolerance of 0.010002136.\n --> Mismatched Elements: 1/3\n --> Largest Absolute Difference: 0.1015625\n\nindex         arr1         arr2  abs diff  rel diff  result\n-----  -----------  -----------  --------  --------  ------\n    0  1.0996094e0  1.0996094e0         0         0    pass\n\u{1b}[31;1m    1  2.1992188e0  2.3007813e0  1.016e-1  4.413e-2    FAIL\u{1b}[0m\n    2  3.3007813e0  3.3007813e0         0         0    pass\n"
+    )]
+    fn test_std_arr_f16_fail() {
+        use half::f16;
+        let arr1 = [f16::from_f32(1.1), f16::from_f32(2.2), f16::from_f32(3.3)];
+        let arr2 = [f16::from_f32(1.1), f16::from_f32(2.3), f16::from_f32(3.3)];
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_atol!(&arr1, &arr2, f16::from_f32(0.01));
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not equal to 4 decimal places.\n --> Mismatched Elements: 1/2\n --> Maximum Decimal Places of Precision: 3\n\nindex           arr1           arr2  abs diff  decimal  result\n-----  -------------  -------------  --------  -------  ------\n\u{1b}[31;1m    0    9.994507e-4   1.9989014e-3  9.995e-4        3    FAIL\u{1b}[0m\n    1  1.00025555e30  1.00025555e30         0       37    pass\n"
+    )]
+    fn test_std_vec_bf16_fail() {
+        use half::bf16;
+        let vec1 = vec![bf16::from_f32(1e-3), bf16::from_f32(1e30)];
+        let vec2 = vec![bf16::from_f32(2e-3), bf16::from_f32(1e30)];
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_decimal!(&vec1, &vec2, 4);
+        });
+    }
+
+    #[test]
+    fn test_std_arr_atols_pass() {
+        let arr1 = [1.0, 100.0, 0.001];
+        let arr2 = [1.01, 100.5, 0.001001];
+        assert_arrays_equal_to_atols!(&arr1, &arr2, [0.02, 1.0, 1e-5]);
+        assert_arrays_equal_to_atols!(&arr1, &arr2, |idx| if idx == 1 { 1.0 } else { 0.02 });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not equal to the specified absolute tolerances.\n --> Mismatched Elements: 1/3\n --> Largest Absolute Difference: 0.5\n\nindex  arr1      arr2   tol  abs diff  rel diff  result\n-----  ----  --------  ----  --------  --------  ------\n    0   1e0    1.01e0  2e-2      1e-2  9.901e-3    pass\n\u{1b}[31;1m    1   1e2   1.005e2  1e-1      5e-1  4.975e-3    FAIL\u{1b}[0m\n    2  1e-3  1.001e-3  1e-5      1e-6   9.99e-4    pass\n"
+    )]
+    fn test_std_arr_atols_fail() {
+        let arr1 = [1.0, 100.0, 0.001];
+        let arr2 = [1.01, 100.5, 0.001001];
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_atols!(&arr1, &arr2, [0.02, 0.1, 1e-5]);
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe tolerances must have the same number of elements as the arrays.\n --> arr num elements: 3\n --> tolerances num elements: 2\n"
+    )]
+    fn test_std_vec_atols_count_mismatch() {
+        let vec1 = vec![1.0, 2.0, 3.0];
+        let vec2 = vec![1.0, 2.0, 3.0];
+        assert_arrays_equal_to_atols!(&vec1, &vec2, [0.1, 0.1]);
+    }
+
+    #[test]
+    fn test_std_vec_rtols_check_err() {
+        let vec1 = vec![1.0, 100.0, 1e6];
+        let vec2 = vec![1.1, 101.0, 1.01e6];
+        assert!(check_arrays_equal_to_rtols!(&vec1, &vec2, [0.1, 0.01, 0.01]).is_ok());
+        let error = check_arrays_equal_to_rtols!(&vec1, &vec2, |_| 0.05).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![0]);
+        assert!(error.table.contains("tol"));
+        let error = check_arrays_equal_to_rtols!(&vec1, &vec2, [0.1; 4]).unwrap_err();
+        assert_eq!(
+            *error.kind,
+            crate::ComparisonErrorKind::ToleranceCountMismatch {
+                num_elements: 3,
+                num_tolerances: 4
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not equal to the specified relative tolerances.\n --> Mismatched Elements: 1/4\n --> Largest Relative Difference: 0.09090909090909086\n\n index  arr1   arr2   tol  abs diff  rel diff  result\n------  ----  -----  ----  --------  --------  ------\n(0, 0)   1e0    1e0  1e-2         0         0    pass\n(0, 1)   2e0    2e0  1e-2         0         0    pass\n\u{1b}[31;1m(1, 0)   3e0  3.3e0  5e-2      3e-1  9.091e-2    FAIL\u{1b}[0m\n(1, 1)   4e0    4e0  1e-2         0         0    pass\n"
+    )]
+    fn test_nested_rtols_fail() {
+        let arr1 = [[1.0, 2.0], [3.0, 4.0]];
+        let arr2 = [[1.0, 2.0], [3.3, 4.0]];
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_to_rtols!(&arr1, &arr2, [[0.01, 0.01], [0.05, 0.01]]);
+        });
+    }
+
+    #[test]
+    fn test_std_vec_vec_ragged() {
+        let ragged = vec![vec![1.0, 2.0], vec![3.0], vec![4.0, 5.0, 6.0]];
+        let arr = [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+        let error = check_arrays_equal!(&ragged, &arr).unwrap_err();
+        assert_eq!(
+            error.message,
+            "\narr1 must be an array-like struct whose rows all have the same length.\n --> arr1 \
+            shape: [6]\n"
+        );
+        let error = check_arrays_equal_to_atols!(&arr, &arr, &ragged).unwrap_err();
+        assert_eq!(
+            *error.kind,
+            crate::ComparisonErrorKind::InvalidShape {
+                name: String::from("tolerances"),
+                shape: vec![6],
+                expected: String::from("an array-like struct whose rows all have the same length"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_nested_atols_shape_mismatch() {
+        let arr = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        let atols = [[0.1, 0.1], [0.1, 0.1], [0.1, 0.1]];
+        let error = check_arrays_equal_to_atols!(&arr, &arr, atols).unwrap_err();
+        assert_eq!(
+            error.message,
+            "\ntolerances must be an array-like struct with shape [2, 3].\n --> tolerances shape: \
+            [3, 2]\n"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "nalgebra")]
+    fn test_nalgebra_matrix_nested_atols() {
+        // The (0, 1) tolerance must be applied to the (0, 1) element, even though nalgebra
+        // matrices iterate over their elements in column-major order.
+        let m1 = nalgebra::Matrix2::new(1.0, 2.0, 3.0, 4.0);
+        let m2 = nalgebra::Matrix2::new(1.0, 2.5, 3.0, 4.0);
+        assert_arrays_equal_to_atols!(&m1, &m2, [[0.0, 1.0], [0.0, 0.0]]);
+        let error = check_arrays_equal_to_atols!(&m1, &m2, [[0.0, 0.0], [1.0, 0.0]]).unwrap_err();
+        assert_eq!(
+            error.mismatches[0].multi_index.as_deref(),
+            Some(&[0_usize, 1][..])
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "nalgebra", feature = "ndarray"))]
+    fn test_ndarray_array2_nalgebra_matrix_rtols() {
+        let arr1 = ndarray::array![[1.0, 2.0], [3.0, 4.0]];
+        let arr2 = ndarray::array![[1.0, 2.2], [3.0, 4.0]];
+        let rtols = nalgebra::Matrix2::new(0.0, 0.1, 0.0, 0.0);
+        assert_arrays_equal_to_rtols!(&arr1, &arr2, rtols);
+        let rtols = nalgebra::Matrix2::new(0.0, 0.0, 0.1, 0.0);
+        assert!(check_arrays_equal_to_rtols!(&arr1, &arr2, rtols).is_err());
+    }
+
+    #[test]
+    fn test_large_array_truncated_report() {
+        let vec1 = vec![1.0; 1000];
+        let mut vec2 = vec1.clone();
+        for i in (0..1000).step_by(5) {
+            vec2[i] = 1.5;
+        }
+        vec2[995] = 4.0;
+        let error = check_arrays_equal_to_atol!(&vec1, &vec2, 0.1).unwrap_err();
+        assert_eq!(error.mismatches.len(), 200);
+
+        // The first 100 mismatched elements and the worst one are printed, with their indices.
+        assert_eq!(error.table.lines().count(), 2 + 101 + 101);
+        assert!(error.table.starts_with("index"));
+        assert!(error.table.contains("  495"));
+        assert!(!error.table.contains("  500"));
+        assert!(error.table.contains("  995"));
+        assert!(error.message.ends_with(concat!(
+            "\nSummary of the 200 mismatched elements (101 shown):\n",
+            " --> Max Abs Diff: 3\n",
+            " --> Mean Abs Diff: 0.5125\n",
+            " --> Median Abs Diff: 0.5\n",
+            " --> Max Rel Diff: 0.75\n",
+            " --> Mean Rel Diff: 0.33541666666666736\n",
+            " --> Median Rel Diff: 0.3333333333333333\n",
+            " --> Worst Element (Largest Abs Diff): 995\n",
+        )));
+    }
+
+    #[test]
+    fn test_get_mismatched_elements_str() {
+        let arr1 = [1.0, 2.0, 3.0];
+        let arr2 = [1.0, 2.5, 3.0];
+        let mut output = String::new();
+        with_color_choice(ColorChoice::Always, || {
+            get_mismatched_elements_str!(arr1, arr2, [1], output);
+        });
+        assert_eq!(
+            output,
+            concat!(
+                "index  arr1   arr2  abs diff  rel diff  result\n",
+                "-----  ----  -----  --------  --------  ------\n",
+                "    0   1e0    1e0         0         0    pass\n",
+                "\x1b[31;1m    1   2e0  2.5e0      5e-1      2e-1    FAIL\x1b[0m\n",
+                "    2   3e0    3e0         0         0    pass\n",
+            )
+        );
     }
 }
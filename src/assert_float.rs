@@ -1,12 +1,68 @@
 #[allow(unused_imports)]
 use crate::compare::Compare;
 
+/// Checks exact equality between two floating-point numbers.
+///
+/// This macro is the non-panicking counterpart of [`assert_equal`](crate::assert_equal).
+///
+/// # Arguments
+///
+/// * `a` - The first floating-point number to compare.
+/// * `b` - The second floating-point number to compare against.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if the two floating-point numbers are exactly
+/// equal, otherwise a [`ComparisonError`](crate::ComparisonError) whose message is the message that
+/// the corresponding assertion macro panics with.
+///
+/// # Note
+///
+/// See [`Compare::is_equal`] for details on how exact equality is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// assert!(check_equal!(2.0, 2.0).is_ok());
+///
+/// let error = check_equal!(2.0, 2.01).unwrap_err();
+/// assert_eq!(error.mismatches[0].b, 2.01);
+/// ```
+#[macro_export]
+macro_rules! check_equal {
+    ($a:expr, $b:expr) => {{
+        let (a, b) = ($a, $b);
+        let are_equal = a.is_equal(b);
+        let (_, abs_diff) = a.is_equal_to_atol(b, $crate::__num_traits::Zero::zero());
+        if are_equal {
+            Ok($crate::ComparisonReport {
+                num_elements: 1,
+                worst_difference: $crate::real_to_f64(abs_diff),
+            })
+        } else {
+            Err($crate::ComparisonError::scalar(
+                a,
+                b,
+                $crate::real_to_f64(abs_diff),
+                format!(
+                    "\nValues are not exactly equal.\n --> a: {:?}\n --> b: {:?}\n",
+                    a, b
+                ),
+            ))
+        }
+    }};
+}
+
 /// Asserts exact equality between two floating-point numbers.
 ///
 /// # Arguments
 ///
 /// * `a` - The first floating-point number to compare.
 /// * `b` - The second floating-point number to compare against.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
 ///
 /// # Panics
 ///
@@ -38,12 +94,72 @@ use crate::compare::Compare;
 #[macro_export]
 macro_rules! assert_equal {
     ($a:expr, $b:expr) => {{
-        let are_equal = $a.is_equal($b);
-        if !are_equal {
-            panic!(
-                "\nValues are not exactly equal.\n --> a: {:?}\n --> b: {:?}\n",
-                $a, $b
-            );
+        if let Err(error) = $crate::check_equal!($a, $b) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $b:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_equal!($a, $b) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks equality of two floating-point numbers to within a specified decimal precision.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_equal_to_decimal`](crate::assert_equal_to_decimal).
+///
+/// # Arguments
+///
+/// * `a` - The first floating-point number to compare.
+/// * `b` - The second floating-point number to compare against.
+/// * `decimal` - Decimal precision to use for comparison.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if the two floating-point numbers are equal to
+/// within the specified decimal precision, otherwise a [`ComparisonError`](crate::ComparisonError)
+/// whose message is the message that the corresponding assertion macro panics with. In both cases,
+/// the actual decimal precision is reported as the worst difference.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_decimal`] for details on how equality to within a specified decimal
+/// precision is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let report = check_equal_to_decimal!(2.0, 2.012, 1).unwrap();
+/// assert_eq!(report.worst_difference, 2.0);
+///
+/// let error = check_equal_to_decimal!(2.0, 2.012, 4).unwrap_err();
+/// assert_eq!(error.worst_difference, 2.0);
+/// ```
+#[macro_export]
+macro_rules! check_equal_to_decimal {
+    ($a:expr, $b:expr, $decimal:expr) => {{
+        let (a, b, decimal) = ($a, $b, $decimal);
+        let (are_equal, actual_decimal) = a.is_equal_to_decimal(b, decimal);
+        if are_equal {
+            Ok($crate::ComparisonReport {
+                num_elements: 1,
+                worst_difference: f64::from(actual_decimal),
+            })
+        } else {
+            Err($crate::ComparisonError::scalar(
+                a,
+                b,
+                f64::from(actual_decimal),
+                format!(
+                    "\nValues are not equal to {} decimal places. They ARE equal to {} decimal \
+                    places.\n --> a: {:?}\n --> b: {:?}\n",
+                    decimal, actual_decimal, a, b
+                ),
+            ))
         }
     }};
 }
@@ -55,6 +171,8 @@ macro_rules! assert_equal {
 /// * `a` - The first floating-point number to compare.
 /// * `b` - The second floating-point number to compare against.
 /// * `decimal` - Decimal precision to use for comparison.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
 ///
 /// # Panics
 ///
@@ -81,13 +199,179 @@ macro_rules! assert_equal {
 #[macro_export]
 macro_rules! assert_equal_to_decimal {
     ($a:expr, $b:expr, $decimal:expr) => {{
-        let (are_equal, actual_decimal) = $a.is_equal_to_decimal($b, $decimal);
-        if !are_equal {
-            panic!(
-                "\nValues are not equal to {} decimal places. They ARE equal to {} decimal places.\
-                \n --> a: {:?}\n --> b: {:?}\n",
-                $decimal, actual_decimal, $a, $b
-            );
+        if let Err(error) = $crate::check_equal_to_decimal!($a, $b, $decimal) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $b:expr, $decimal:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_equal_to_decimal!($a, $b, $decimal) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks equality of two floating-point numbers to a specified number of significant figures.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_equal_to_sigfigs`](crate::assert_equal_to_sigfigs).
+///
+/// # Arguments
+///
+/// * `a` - The first floating-point number to compare.
+/// * `b` - The second floating-point number to compare against.
+/// * `sigfigs` - Number of significant figures to use for comparison.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if the two floating-point numbers are equal to
+/// the specified number of significant figures, otherwise a
+/// [`ComparisonError`](crate::ComparisonError) whose message is the message that the corresponding
+/// assertion macro panics with. In both cases, the actual number of matching significant figures
+/// is reported as the worst difference.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_sigfigs`] for details on how equality to a specified number of
+/// significant figures is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let report = check_equal_to_sigfigs!(6.02214076e23, 6.022e23, 3).unwrap();
+/// assert_eq!(report.worst_difference, 5.0);
+///
+/// let error = check_equal_to_sigfigs!(6.02214076e23, 6.022e23, 6).unwrap_err();
+/// assert_eq!(error.worst_difference, 5.0);
+/// ```
+#[macro_export]
+macro_rules! check_equal_to_sigfigs {
+    ($a:expr, $b:expr, $sigfigs:expr) => {{
+        let (a, b, sigfigs) = ($a, $b, $sigfigs);
+        let (are_equal, actual_sigfigs) = a.is_equal_to_sigfigs(b, sigfigs);
+        if are_equal {
+            Ok($crate::ComparisonReport {
+                num_elements: 1,
+                worst_difference: f64::from(actual_sigfigs),
+            })
+        } else {
+            Err($crate::ComparisonError::scalar(
+                a,
+                b,
+                f64::from(actual_sigfigs),
+                format!(
+                    "\nValues are not equal to {} significant figures. They ARE equal to {} \
+                    significant figures.\n --> a: {:?}\n --> b: {:?}\n",
+                    sigfigs, actual_sigfigs, a, b
+                ),
+            ))
+        }
+    }};
+}
+
+/// Asserts equality of two floating-point numbers to a specified number of significant figures.
+///
+/// # Arguments
+///
+/// * `a` - The first floating-point number to compare.
+/// * `b` - The second floating-point number to compare against.
+/// * `sigfigs` - Number of significant figures to use for comparison.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// If the two floating-point numbers are not equal to the specified number of significant
+/// figures. In this case, the actual number of matching significant figures is also printed.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_sigfigs`] for details on how equality to a specified number of
+/// significant figures is defined.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// assert_equal_to_sigfigs!(1.602176634e-19, 1.602e-19, 4);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// assert_equal_to_sigfigs!(1.602176634e-19, 1.602e-19, 6);
+/// ```
+#[macro_export]
+macro_rules! assert_equal_to_sigfigs {
+    ($a:expr, $b:expr, $sigfigs:expr) => {{
+        if let Err(error) = $crate::check_equal_to_sigfigs!($a, $b, $sigfigs) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $b:expr, $sigfigs:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_equal_to_sigfigs!($a, $b, $sigfigs) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks equality of two floating-point numbers to within a specified absolute tolerance.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_equal_to_atol`](crate::assert_equal_to_atol).
+///
+/// # Arguments
+///
+/// * `a` - The first floating-point number to compare.
+/// * `b` - The second floating-point number to compare against.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if the two floating-point numbers are equal to
+/// within the specified absolute tolerance, otherwise a [`ComparisonError`](crate::ComparisonError)
+/// whose message is the message that the corresponding assertion macro panics with. In both cases,
+/// the absolute difference is reported as the worst difference.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_atol`] for details on how equality to within a specified absolute
+/// tolerance is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// assert!(check_equal_to_atol!(2.0, 2.00001, 1e-3).is_ok());
+///
+/// // Retry with a looser tolerance.
+/// let result = check_equal_to_atol!(2.0, 2.01, 1e-3).or_else(|_| check_equal_to_atol!(2.0, 2.01, 0.1));
+/// assert!(result.is_ok());
+/// ```
+#[macro_export]
+macro_rules! check_equal_to_atol {
+    ($a:expr, $b:expr, $atol:expr) => {{
+        let (a, b, atol) = ($a, $b, $atol);
+        let (are_equal, abs_diff) = a.is_equal_to_atol(b, atol);
+        if are_equal {
+            Ok($crate::ComparisonReport {
+                num_elements: 1,
+                worst_difference: $crate::real_to_f64(abs_diff),
+            })
+        } else {
+            Err($crate::ComparisonError::scalar(
+                a,
+                b,
+                $crate::real_to_f64(abs_diff),
+                format!(
+                    "\nValues are not equal to within an absolute tolerance of {}. They ARE equal \
+                    to within an absolute tolerance of {}.\n --> a: {:?}\n --> b: {:?}\n",
+                    atol, abs_diff, a, b
+                ),
+            ))
         }
     }};
 }
@@ -99,6 +383,8 @@ macro_rules! assert_equal_to_decimal {
 /// * `a` - The first floating-point number to compare.
 /// * `b` - The second floating-point number to compare against.
 /// * `atol` - Absolute tolerance.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
 ///
 /// # Panics
 ///
@@ -122,16 +408,82 @@ macro_rules! assert_equal_to_decimal {
 ///
 /// assert_equal_to_atol!(2.0, 2.00001, 1e-6);
 /// ```
+///
+/// A custom message can be used to identify the failing case (e.g. when looping over test cases).
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// for (i, x) in [1.0, 2.0, 3.0].into_iter().enumerate() {
+///     assert_equal_to_atol!(x * x, 4.0, 1e-6, "case {i} failed (x = {x})");
+/// }
+/// ```
 #[macro_export]
 macro_rules! assert_equal_to_atol {
     ($a:expr, $b:expr, $atol:expr) => {{
-        let (are_equal, abs_diff) = $a.is_equal_to_atol($b, $atol);
-        if !are_equal {
-            panic!(
-                "\nValues are not equal to within an absolute tolerance of {}. They ARE equal to \
-                within an absolute tolerance of {}.\n --> a: {:?}\n --> b: {:?}\n",
-                $atol, abs_diff, $a, $b
-            );
+        if let Err(error) = $crate::check_equal_to_atol!($a, $b, $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $b:expr, $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_equal_to_atol!($a, $b, $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks equality of two floating-point numbers to within a specified relative tolerance.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_equal_to_rtol`](crate::assert_equal_to_rtol).
+///
+/// # Arguments
+///
+/// * `a` - The first floating-point number to compare.
+/// * `b` - The second floating-point number to compare against.
+/// * `rtol` - Relative tolerance.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if the two floating-point numbers are equal to
+/// within the specified relative tolerance, otherwise a [`ComparisonError`](crate::ComparisonError)
+/// whose message is the message that the corresponding assertion macro panics with. In both cases,
+/// the relative difference is reported as the worst difference.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_rtol`] for details on how equality to within a specified relative
+/// tolerance is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// assert!(check_equal_to_rtol!(2.0, 2.01, 0.05).is_ok());
+/// assert!(check_equal_to_rtol!(2.0, 2.01, 1e-5).is_err());
+/// ```
+#[macro_export]
+macro_rules! check_equal_to_rtol {
+    ($a:expr, $b:expr, $rtol:expr) => {{
+        let (a, b, rtol) = ($a, $b, $rtol);
+        let (are_equal, rel_diff) = a.is_equal_to_rtol(b, rtol);
+        if are_equal {
+            Ok($crate::ComparisonReport {
+                num_elements: 1,
+                worst_difference: $crate::real_to_f64(rel_diff),
+            })
+        } else {
+            Err($crate::ComparisonError::scalar(
+                a,
+                b,
+                $crate::real_to_f64(rel_diff),
+                format!(
+                    "\nValues are not equal to within a relative tolerance of {}. They ARE equal \
+                    to within a relative tolerance of {}.\n --> a: {:?}\n --> b: {:?}\n",
+                    rtol, rel_diff, a, b
+                ),
+            ))
         }
     }};
 }
@@ -143,6 +495,8 @@ macro_rules! assert_equal_to_atol {
 /// * `a` - The first floating-point number to compare.
 /// * `b` - The second floating-point number to compare against.
 /// * `rtol` - Relative tolerance.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
 ///
 /// # Panics
 ///
@@ -169,13 +523,224 @@ macro_rules! assert_equal_to_atol {
 #[macro_export]
 macro_rules! assert_equal_to_rtol {
     ($a:expr, $b:expr, $rtol:expr) => {{
-        let (are_equal, rel_diff) = $a.is_equal_to_rtol($b, $rtol);
-        if !are_equal {
-            panic!(
-                "\nValues are not equal to within a relative tolerance of {}. They ARE equal to \
-                within a relative tolerance of {}.\n --> a: {:?}\n --> b: {:?}\n",
-                $rtol, rel_diff, $a, $b
-            );
+        if let Err(error) = $crate::check_equal_to_rtol!($a, $b, $rtol) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $b:expr, $rtol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_equal_to_rtol!($a, $b, $rtol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks equality of two floating-point numbers to within a specified number of units in the last
+/// place (ULPs).
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_equal_to_ulps`](crate::assert_equal_to_ulps).
+///
+/// # Arguments
+///
+/// * `a` - The first floating-point number to compare.
+/// * `b` - The second floating-point number to compare against.
+/// * `max_ulps` - Maximum allowable distance between the two floats, in ULPs.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if the two floating-point numbers are equal to
+/// within the specified number of ULPs, otherwise a [`ComparisonError`](crate::ComparisonError)
+/// whose message is the message that the corresponding assertion macro panics with. In both cases,
+/// the ULP distance is reported as the worst difference.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_ulps`] for details on how equality to within a specified number of
+/// ULPs is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let error = check_equal_to_ulps!(1.0, 1.0 + 4.0 * f64::EPSILON, 2).unwrap_err();
+/// assert_eq!(error.worst_difference, 4.0);
+/// ```
+#[macro_export]
+macro_rules! check_equal_to_ulps {
+    ($a:expr, $b:expr, $max_ulps:expr) => {{
+        let (a, b, max_ulps) = ($a, $b, $max_ulps);
+        let (are_equal, ulps) = a.is_equal_to_ulps(b, max_ulps);
+        #[allow(clippy::cast_precision_loss)]
+        let ulps_f64 = ulps as f64;
+        if are_equal {
+            Ok($crate::ComparisonReport {
+                num_elements: 1,
+                worst_difference: ulps_f64,
+            })
+        } else {
+            Err($crate::ComparisonError::scalar(
+                a,
+                b,
+                ulps_f64,
+                format!(
+                    "\nValues are not equal to within {} ULPs. They ARE equal to within {} ULPs.\
+                    \n --> a: {:?}\n --> b: {:?}\n",
+                    max_ulps, ulps, a, b
+                ),
+            ))
+        }
+    }};
+}
+
+/// Asserts equality of two floating-point numbers to within a specified number of units in the
+/// last place (ULPs).
+///
+/// # Arguments
+///
+/// * `a` - The first floating-point number to compare.
+/// * `b` - The second floating-point number to compare against.
+/// * `max_ulps` - Maximum allowable distance between the two floats, in ULPs.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// If the two floating-point numbers are not equal to within the specified number of ULPs.
+///
+/// # Note
+///
+/// See [`Compare::is_equal_to_ulps`] for details on how equality to within a specified number of
+/// ULPs is defined.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// assert_equal_to_ulps!(1.0, 1.0 + f64::EPSILON, 1);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// assert_equal_to_ulps!(1.0, 1.0 + 4.0 * f64::EPSILON, 2);
+/// ```
+#[macro_export]
+macro_rules! assert_equal_to_ulps {
+    ($a:expr, $b:expr, $max_ulps:expr) => {{
+        if let Err(error) = $crate::check_equal_to_ulps!($a, $b, $max_ulps) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $b:expr, $max_ulps:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_equal_to_ulps!($a, $b, $max_ulps) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks that two floating-point numbers are close to within a specified combination of relative
+/// and absolute tolerances.
+///
+/// This macro is the non-panicking counterpart of [`assert_close`](crate::assert_close).
+///
+/// # Arguments
+///
+/// * `a` - The first floating-point number to compare.
+/// * `b` - The second floating-point number to compare against (i.e. the reference value).
+/// * `rtol` - Relative tolerance.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if the two floating-point numbers are close to
+/// within the specified tolerances, otherwise a [`ComparisonError`](crate::ComparisonError) whose
+/// message is the message that the corresponding assertion macro panics with. In both cases, the
+/// absolute difference is reported as the worst difference.
+///
+/// # Note
+///
+/// See [`Compare::is_close`] for details on how closeness is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// assert!(check_close!(1e-10, 0.0, 1e-5, 1e-8).is_ok());
+/// assert!(check_close!(1e-7, 0.0, 1e-5, 1e-8).is_err());
+/// ```
+#[macro_export]
+macro_rules! check_close {
+    ($a:expr, $b:expr, $rtol:expr, $atol:expr) => {{
+        let (a, b, rtol, atol) = ($a, $b, $rtol, $atol);
+        let (are_close, abs_diff, rel_diff) = a.is_close(b, rtol, atol);
+        if are_close {
+            Ok($crate::ComparisonReport {
+                num_elements: 1,
+                worst_difference: $crate::real_to_f64(abs_diff),
+            })
+        } else {
+            Err($crate::ComparisonError::scalar(
+                a,
+                b,
+                $crate::real_to_f64(abs_diff),
+                format!(
+                    "\nValues are not close to within a relative tolerance of {} and an absolute \
+                    tolerance of {}. Their absolute difference is {} and their relative difference \
+                    is {}.\n --> a: {:?}\n --> b: {:?}\n",
+                    rtol, atol, abs_diff, rel_diff, a, b
+                ),
+            ))
+        }
+    }};
+}
+
+/// Asserts that two floating-point numbers are close to within a specified combination of relative
+/// and absolute tolerances.
+///
+/// # Arguments
+///
+/// * `a` - The first floating-point number to compare.
+/// * `b` - The second floating-point number to compare against (i.e. the reference value).
+/// * `rtol` - Relative tolerance.
+/// * `atol` - Absolute tolerance.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// If the two floating-point numbers are not close to within the specified tolerances.
+///
+/// # Note
+///
+/// See [`Compare::is_close`] for details on how closeness is defined.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// assert_close!(1e-10, 0.0, 1e-5, 1e-8);
+/// assert_close!(1000.001, 1000.0, 1e-5, 1e-8);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// assert_close!(1e-7, 0.0, 1e-5, 1e-8);
+/// ```
+#[macro_export]
+macro_rules! assert_close {
+    ($a:expr, $b:expr, $rtol:expr, $atol:expr) => {{
+        if let Err(error) = $crate::check_close!($a, $b, $rtol, $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $b:expr, $rtol:expr, $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_close!($a, $b, $rtol, $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
         }
     }};
 }
@@ -255,6 +820,29 @@ mod tests {
         assert_equal_to_decimal!(1234.2222_f64, 1234.2223_f64, 10);
     }
 
+    #[test]
+    fn assert_equal_to_sigfigs_should_pass() {
+        assert_equal_to_sigfigs!(6.02214076e23, 6.022e23, 4);
+        assert_equal_to_sigfigs!(1.0_f32, 1.0001, 4);
+        assert_equal_to_sigfigs!(-0.000123, -0.0001234, 2, "custom message");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nValues are not equal to 5 significant figures. They ARE equal to 3 significant figures.\n --> a: 1.602176634e-19\n --> b: 1.6e-19\n"
+    )]
+    fn assert_equal_to_sigfigs_should_fail_1() {
+        assert_equal_to_sigfigs!(1.602176634e-19, 1.6e-19, 5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nValues are not equal to 1 significant figures. They ARE equal to 0 significant figures.\n --> a: NaN\n --> b: 1.0\n"
+    )]
+    fn assert_equal_to_sigfigs_should_fail_2() {
+        assert_equal_to_sigfigs!(f64::NAN, 1.0, 1);
+    }
+
     #[test]
     fn assert_equal_to_atol_should_pass() {
         assert_equal_to_atol!(1.0_f32, 0.9999999, 1e-5);
@@ -316,4 +904,177 @@ mod tests {
     fn assert_equal_to_rtol_should_fail_3() {
         assert_equal_to_rtol!(1234.2222_f64, 1234.2223_f64, 1e-15);
     }
+
+    #[test]
+    fn assert_equal_to_ulps_should_pass() {
+        assert_equal_to_ulps!(1.0_f32, 0.99999994, 1);
+        assert_equal_to_ulps!(1.0_f64, 1.0000000000000004, 2);
+        assert_equal_to_ulps!(0.0_f64, -0.0_f64, 0);
+        assert_equal_to_ulps!(f64::NAN, f64::NAN, 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nValues are not equal to within 1 ULPs. They ARE equal to within 2 ULPs.\n --> a: 1.0\n --> b: 0.9999999\n"
+    )]
+    fn assert_equal_to_ulps_should_fail_1() {
+        assert_equal_to_ulps!(1.0_f32, 0.9999999, 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nValues are not equal to within 1 ULPs. They ARE equal to within 2 ULPs.\n --> a: 1.0\n --> b: 1.0000000000000004\n"
+    )]
+    fn assert_equal_to_ulps_should_fail_2() {
+        assert_equal_to_ulps!(1.0_f64, 1.0000000000000004, 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nValues are not equal to within 1000 ULPs. They ARE equal to within 18446744073709551615 ULPs.\n --> a: NaN\n --> b: 0.0\n"
+    )]
+    fn assert_equal_to_ulps_should_fail_3() {
+        assert_equal_to_ulps!(f64::NAN, 0.0, 1000);
+    }
+
+    #[test]
+    fn assert_close_should_pass() {
+        assert_close!(1e-10_f32, 0.0, 1e-5, 1e-8);
+        assert_close!(1000.0_f64, 1000.01, 1e-5, 1e-8);
+        assert_close!(f64::NAN, f64::NAN, 0.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nValues are not close to within a relative tolerance of 0.00001 and an absolute tolerance of 0.00000001. Their absolute difference is 0.0000001 and their relative difference is inf.\n --> a: 1e-7\n --> b: 0.0\n"
+    )]
+    fn assert_close_should_fail_1() {
+        assert_close!(1e-7_f64, 0.0, 1e-5, 1e-8);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nValues are not close to within a relative tolerance of 0.5 and an absolute tolerance of 0. Their absolute difference is 1 and their relative difference is 1.\n --> a: 2.0\n --> b: 1.0\n"
+    )]
+    fn assert_close_should_fail_2() {
+        assert_close!(2.0_f64, 1.0, 0.5, 0.0);
+    }
+
+    #[test]
+    fn check_equal_to_atol_ok() {
+        let report = check_equal_to_atol!(2.0_f64, 2.5, 1.0).unwrap();
+        assert_eq!(report.num_elements, 1);
+        assert_eq!(report.worst_difference, 0.5);
+    }
+
+    #[test]
+    fn check_macros_evaluate_tolerances_once() {
+        let mut num_calls = 0;
+        let mut tol = |value| {
+            num_calls += 1;
+            value
+        };
+        assert!(check_equal_to_atol!(2.0_f64, 2.5, tol(0.1)).is_err());
+        assert!(check_equal_to_rtol!(2.0_f64, 2.5, tol(0.1)).is_err());
+        assert!(check_close!(2.0_f64, 2.5, tol(0.1), tol(0.1)).is_err());
+        assert!(crate::check_arrays_equal_to_atol!(&[2.0_f64], &[2.5], tol(0.1)).is_err());
+        assert!(crate::check_arrays_close!(&[2.0_f64], &[2.5], tol(0.1), tol(0.1)).is_err());
+        assert_eq!(num_calls, 7);
+    }
+
+    #[test]
+    fn check_equal_to_atol_err() {
+        let error = check_equal_to_atol!(2.0_f64, 2.5, 0.1).unwrap_err();
+        assert_eq!(*error.kind, crate::ComparisonErrorKind::ValueMismatch);
+        assert_eq!(error.mismatched_indices(), vec![0]);
+        assert_eq!(error.mismatches[0].a, 2.0);
+        assert_eq!(error.mismatches[0].b, 2.5);
+        assert_eq!(error.worst_difference, 0.5);
+        assert_eq!(
+            error.to_string(),
+            "\nValues are not equal to within an absolute tolerance of 0.1. They ARE equal to within \
+            an absolute tolerance of 0.5.\n --> a: 2.0\n --> b: 2.5\n"
+        );
+    }
+
+    #[test]
+    fn check_equal_err() {
+        let error = check_equal!(1.0_f32, 1.5).unwrap_err();
+        assert_eq!(error.worst_difference, 0.5);
+        assert!(check_equal!(f64::NAN, f64::NAN).is_ok());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "case 3 failed (x = 1.5)\nValues are not equal to within an absolute tolerance of 0.1. They ARE equal to within an absolute tolerance of 0.5.\n --> a: 1.5\n --> b: 2.0\n"
+    )]
+    fn assert_equal_to_atol_custom_message() {
+        let x = 1.5_f64;
+        assert_equal_to_atol!(x, 2.0, 0.1, "case {} failed (x = {x})", 3);
+    }
+
+    #[test]
+    fn assert_custom_message_pass() {
+        assert_equal!(2.0_f64, 2.0, "case {}", 1);
+        assert_equal_to_decimal!(2.0_f64, 2.012, 1, "case {}", 2);
+        assert_equal_to_rtol!(2.0_f64, 2.01, 0.01, "case {}", 3);
+        assert_equal_to_ulps!(1.0_f64, 1.0 + f64::EPSILON, 1, "case {}", 4);
+        assert_close!(1e-10_f64, 0.0, 1e-5, 1e-8, "case {}", 5);
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn assert_complex_should_pass() {
+        use num_complex::Complex64;
+        assert_equal!(Complex64::new(1.0, f64::NAN), Complex64::new(1.0, f64::NAN));
+        assert_equal_to_decimal!(Complex64::new(1.0, 2.0), Complex64::new(1.001, 2.01), 1);
+        assert_equal_to_atol!(Complex64::new(1.0, 1.0), Complex64::new(4.0, 5.0), 5.0);
+        assert_equal_to_rtol!(Complex64::new(6.0, 8.0), Complex64::new(3.0, 4.0), 0.5);
+        assert_close!(Complex64::new(3.0, 4.0), Complex64::new(6.0, 8.0), 0.5, 1.0);
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    #[should_panic(
+        expected = "\nValues are not equal to within an absolute tolerance of 4.9. They ARE equal to within an absolute tolerance of 5.\n --> a: Complex { re: 1.0, im: 1.0 }\n --> b: Complex { re: 4.0, im: 5.0 }\n"
+    )]
+    fn assert_complex_should_fail() {
+        use num_complex::Complex64;
+        assert_equal_to_atol!(Complex64::new(1.0, 1.0), Complex64::new(4.0, 5.0), 4.9);
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn assert_half_should_pass() {
+        use half::{bf16, f16};
+        assert_equal!(f16::NAN, f16::NAN);
+        assert_equal_to_decimal!(f16::from_f32(2.0), f16::from_f32(2.012), 1);
+        assert_equal_to_atol!(
+            f16::from_f32(2.0),
+            f16::from_f32(2.001),
+            f16::from_f32(1e-2)
+        );
+        assert_equal_to_rtol!(
+            bf16::from_f32(2.0),
+            bf16::from_f32(2.01),
+            bf16::from_f32(0.01)
+        );
+        assert_equal_to_ulps!(bf16::ONE, bf16::ONE + bf16::EPSILON, 1);
+        assert_close!(
+            bf16::ZERO,
+            bf16::from_f32(1e-10),
+            bf16::ZERO,
+            bf16::from_f32(1e-8)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    #[should_panic(
+        expected = "\nValues are not equal to within an absolute tolerance of 0.099975586. They ARE equal to within an absolute tolerance of 0.5.\n --> a: 2.0\n --> b: 2.5\n"
+    )]
+    fn assert_f16_should_fail() {
+        use half::f16;
+        assert_equal_to_atol!(f16::from_f32(2.0), f16::from_f32(2.5), f16::from_f32(0.1));
+    }
 }
@@ -0,0 +1,455 @@
+use crate::compare::{Compare, real_to_f64};
+use crate::norm::Norm;
+use crate::report::{ComparisonError, ComparisonReport, compare_elements};
+use crate::shape::ShapedElements;
+use num_traits::Zero;
+use std::fmt::LowerExp;
+
+/// Checks that the norm of the difference between two array-like structs (optionally relative to
+/// the norm of the reference) is within a specified tolerance (see
+/// [`check_arrays_equal_in_norm`](crate::check_arrays_equal_in_norm) and
+/// [`check_arrays_equal_in_rel_norm`](crate::check_arrays_equal_in_rel_norm)).
+///
+/// # Arguments
+///
+/// * `arr1` - Elements of the first array-like struct (see
+///   [`shaped_elements`](crate::shaped_elements)).
+/// * `arr2` - Elements of the second array-like struct (the reference).
+/// * `tol` - Tolerance on the (relative) norm of the difference.
+/// * `norm` - Norm.
+/// * `relative` - Whether the norm of the difference is taken relative to the norm of the
+///   reference.
+///
+/// # Returns
+///
+/// Report summarizing the comparison, whose `worst_difference` is the (relative) norm of the
+/// difference.
+///
+/// # Errors
+///
+/// If the two array-like structs do not have the same shape or number of elements, or if the
+/// (relative) norm of the difference exceeds the tolerance.
+#[doc(hidden)]
+pub fn check_norm<T>(
+    arr1: ShapedElements<T>,
+    arr2: ShapedElements<T>,
+    tol: f64,
+    norm: Norm,
+    relative: bool,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: Compare + Copy + LowerExp + Zero,
+{
+    // Absolute differences, and absolute values of the reference elements.
+    let mut abs_diffs: Vec<f64> = Vec::new();
+    let mut abs_refs: Vec<f64> = Vec::new();
+
+    // Compare the two arrays element-wise.
+    let comparison = compare_elements(arr1, arr2, |a, b| {
+        let zero = T::Real::zero();
+        let (_, abs_diff) = a.is_equal_to_atol(b, zero);
+        let (_, abs_ref) = b.is_equal_to_atol(T::zero(), zero);
+        let abs_diff = real_to_f64(abs_diff);
+        abs_diffs.push(abs_diff);
+        abs_refs.push(real_to_f64(abs_ref));
+        (true, abs_diff)
+    })?;
+    let norm_diff = norm.compute(abs_diffs);
+    let norm_ref = norm.compute(abs_refs);
+    let difference = match (relative, norm_diff == 0.0) {
+        (false, _) => norm_diff,
+        (true, true) => 0.0,
+        (true, false) => norm_diff / norm_ref,
+    };
+    let comparison = if difference <= tol {
+        comparison
+    } else {
+        comparison.flag_worst(5)
+    };
+    comparison.finish(difference, |num_mismatched, count| {
+        if relative {
+            format!(
+                "\nThe two array-like structs are not equal in the {norm} norm to a relative \
+                tolerance of {tol}.\n --> Relative Norm of Difference: {difference}\n --> Norm of \
+                Difference: {norm_diff}\n --> Norm of Reference (arr2): {norm_ref}\n --> Worst \
+                Offenders: {num_mismatched}/{count}"
+            )
+        } else {
+            format!(
+                "\nThe two array-like structs are not equal in the {norm} norm to an absolute \
+                tolerance of {tol}.\n --> Norm of Difference: {norm_diff}\n --> Norm of Reference \
+                (arr2): {norm_ref}\n --> Worst Offenders: {num_mismatched}/{count}"
+            )
+        }
+    })
+}
+
+/// Checks that the norm of the difference between two array-like structs is within a specified
+/// absolute tolerance.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_equal_in_norm`](crate::assert_arrays_equal_in_norm).
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare (the reference). Must either implement the
+///   [`Iterator`] trait or have an `iter` method.
+/// * `tol` - Absolute tolerance on the norm of the difference (an [`f64`]).
+/// * `norm = ...` - Optional [`Norm`](crate::Norm) variant (`L1`, `L2`, `Linf`, or `Frobenius`).
+///   Defaults to `L2`.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) whose `worst_difference` is the norm of the
+/// difference, if it is within the tolerance.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, or if the norm of the difference exceeds the tolerance. In the
+/// latter case, the error's mismatches are the worst offenders (the pairs of elements with the
+/// largest absolute differences).
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let x = [1.0, 2.0, 3.0];
+/// let x_ref = [1.0, 2.003, 3.004];
+///
+/// let report = check_arrays_equal_in_norm!(&x, &x_ref, 0.01).unwrap();
+/// assert_eq!(report.worst_difference, 0.005000000000000071);
+/// assert!(check_arrays_equal_in_norm!(&x, &x_ref, 0.005, norm = Linf).is_ok());
+/// ```
+#[macro_export]
+macro_rules! check_arrays_equal_in_norm {
+    ($arr1:expr, $arr2:expr, $tol:expr) => {
+        $crate::check_arrays_equal_in_norm!($arr1, $arr2, $tol, norm = L2)
+    };
+    ($arr1:expr, $arr2:expr, $tol:expr, norm = $norm:ident) => {
+        $crate::check_norm(
+            $crate::shaped_elements!($arr1),
+            $crate::shaped_elements!($arr2),
+            $tol,
+            $crate::Norm::$norm,
+            false,
+        )
+    };
+}
+
+/// Asserts that the norm of the difference between two array-like structs is within a specified
+/// absolute tolerance.
+///
+/// This macro checks that $\|\|\mathbf{a}-\mathbf{b}\|\|\leq\mathrm{tol}$, where the norm is one of
+/// the [`Norm`](crate::Norm) variants (computed over the flattened elements). Unlike the
+/// element-wise macros (e.g. [`assert_arrays_equal_to_atol`](crate::assert_arrays_equal_to_atol)),
+/// individual elements may differ by more than the tolerance as long as the norm does not.
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare (the reference). Must either implement the
+///   [`Iterator`] trait or have an `iter` method.
+/// * `tol` - Absolute tolerance on the norm of the difference (an [`f64`]).
+/// * `norm = ...` - Optional [`Norm`](crate::Norm) variant (`L1`, `L2`, `Linf`, or `Frobenius`).
+///   Defaults to `L2`.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
+/// * If the two array-like structs do not have the same number of elements. In this case, the
+///   number of elements in each array-like struct is also printed.
+/// * If the norm of the difference exceeds the tolerance. In this case, the norm of the difference
+///   and the norm of the reference (`arr2`) are printed, along with the two array-like structs,
+///   with the (up to five) pairs of elements with the largest nonzero absolute differences shown
+///   in bolded red.
+///
+/// # Note
+///
+/// Absolute differences are computed using [`Compare::is_equal_to_atol`], so `NaN`s in the same
+/// position are treated as equal, while a `NaN` in only one of the array-like structs makes the
+/// norm `NaN` (and the assertion fail).
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let x = [1.0, 2.0, 3.0];
+/// let x_ref = [1.0, 2.003, 3.004];
+/// assert_arrays_equal_in_norm!(&x, &x_ref, 0.01);
+/// assert_arrays_equal_in_norm!(&x, &x_ref, 0.01, norm = L1);
+/// ```
+///
+/// ```
+/// use nalgebra::Matrix2;
+/// use numtest::*;
+///
+/// let mat1 = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+/// let mat2 = Matrix2::new(1.0, 2.0, 3.0, 4.001);
+/// assert_arrays_equal_in_norm!(&mat1, &mat2, 1e-2, norm = Frobenius);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let x = [1.0, 2.0, 3.0];
+/// let x_ref = [1.0, 2.003, 3.004];
+/// assert_arrays_equal_in_norm!(&x, &x_ref, 0.001, norm = Linf, "iteration {}", 10);
+/// ```
+#[macro_export]
+macro_rules! assert_arrays_equal_in_norm {
+    ($arr1:expr, $arr2:expr, $tol:expr) => {
+        $crate::assert_arrays_equal_in_norm!($arr1, $arr2, $tol, norm = L2)
+    };
+    ($arr1:expr, $arr2:expr, $tol:expr, norm = $norm:ident) => {{
+        if let Err(error) = $crate::check_arrays_equal_in_norm!($arr1, $arr2, $tol, norm = $norm) {
+            panic!("{}", error);
+        }
+    }};
+    ($arr1:expr, $arr2:expr, $tol:expr, norm = $norm:ident, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_equal_in_norm!($arr1, $arr2, $tol, norm = $norm) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+    ($arr1:expr, $arr2:expr, $tol:expr, $($arg:tt)+) => {
+        $crate::assert_arrays_equal_in_norm!($arr1, $arr2, $tol, norm = L2, $($arg)+)
+    };
+}
+
+/// Checks that the norm of the difference between two array-like structs, relative to the norm of
+/// the reference, is within a specified tolerance.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_equal_in_rel_norm`](crate::assert_arrays_equal_in_rel_norm).
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare (the reference). Must either implement the
+///   [`Iterator`] trait or have an `iter` method.
+/// * `tol` - Tolerance on the relative norm of the difference (an [`f64`]).
+/// * `norm = ...` - Optional [`Norm`](crate::Norm) variant (`L1`, `L2`, `Linf`, or `Frobenius`).
+///   Defaults to `L2`.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) whose `worst_difference` is the relative norm
+/// of the difference, if it is within the tolerance.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, or if the relative norm of the difference exceeds the tolerance.
+/// In the latter case, the error's mismatches are the worst offenders (the pairs of elements with
+/// the largest absolute differences).
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let x = [100.0, 200.0];
+/// let x_ref = [100.0, 200.1];
+///
+/// let report = check_arrays_equal_in_rel_norm!(&x, &x_ref, 1e-3).unwrap();
+/// assert!(report.worst_difference < 5e-4);
+/// ```
+#[macro_export]
+macro_rules! check_arrays_equal_in_rel_norm {
+    ($arr1:expr, $arr2:expr, $tol:expr) => {
+        $crate::check_arrays_equal_in_rel_norm!($arr1, $arr2, $tol, norm = L2)
+    };
+    ($arr1:expr, $arr2:expr, $tol:expr, norm = $norm:ident) => {
+        $crate::check_norm(
+            $crate::shaped_elements!($arr1),
+            $crate::shaped_elements!($arr2),
+            $tol,
+            $crate::Norm::$norm,
+            true,
+        )
+    };
+}
+
+/// Asserts that the norm of the difference between two array-like structs, relative to the norm
+/// of the reference, is within a specified tolerance.
+///
+/// This macro checks that
+/// $\|\|\mathbf{a}-\mathbf{b}\|\|/\|\|\mathbf{b}\|\|\leq\mathrm{tol}$, which is the usual
+/// convergence criterion for iterative solvers. The relative norm is defined to be `0` if the
+/// norm of the difference is `0` (even if the norm of the reference is also `0`).
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct to compare. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `arr2` - Second array-like struct to compare (the reference). Must either implement the
+///   [`Iterator`] trait or have an `iter` method.
+/// * `tol` - Tolerance on the relative norm of the difference (an [`f64`]).
+/// * `norm = ...` - Optional [`Norm`](crate::Norm) variant (`L1`, `L2`, `Linf`, or `Frobenius`).
+///   Defaults to `L2`.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
+/// * If the two array-like structs do not have the same number of elements. In this case, the
+///   number of elements in each array-like struct is also printed.
+/// * If the relative norm of the difference exceeds the tolerance. In this case, the relative
+///   norm, the norm of the difference, and the norm of the reference (`arr2`) are printed, along
+///   with the two array-like structs, with the (up to five) pairs of elements with the largest
+///   nonzero absolute differences shown in bolded red.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let x = vec![100.0, 200.0, 300.0];
+/// let x_ref = vec![100.01, 200.0, 299.98];
+/// assert_arrays_equal_in_rel_norm!(&x, &x_ref, 1e-4);
+/// assert_arrays_equal_in_rel_norm!(&x, &x_ref, 1e-4, norm = Linf);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let x = vec![100.0, 200.0, 300.0];
+/// let x_ref = vec![100.01, 200.0, 299.98];
+/// assert_arrays_equal_in_rel_norm!(&x, &x_ref, 1e-5);
+/// ```
+#[macro_export]
+macro_rules! assert_arrays_equal_in_rel_norm {
+    ($arr1:expr, $arr2:expr, $tol:expr) => {
+        $crate::assert_arrays_equal_in_rel_norm!($arr1, $arr2, $tol, norm = L2)
+    };
+    ($arr1:expr, $arr2:expr, $tol:expr, norm = $norm:ident) => {{
+        if let Err(error) =
+            $crate::check_arrays_equal_in_rel_norm!($arr1, $arr2, $tol, norm = $norm)
+        {
+            panic!("{}", error);
+        }
+    }};
+    ($arr1:expr, $arr2:expr, $tol:expr, norm = $norm:ident, $($arg:tt)+) => {{
+        if let Err(error) =
+            $crate::check_arrays_equal_in_rel_norm!($arr1, $arr2, $tol, norm = $norm)
+        {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+    ($arr1:expr, $arr2:expr, $tol:expr, $($arg:tt)+) => {
+        $crate::assert_arrays_equal_in_rel_norm!($arr1, $arr2, $tol, norm = L2, $($arg)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorChoice, with_color_choice};
+
+    #[test]
+    fn check_norm_report() {
+        let x = [3.0, 0.0];
+        let x_ref = [0.0, 4.0];
+        assert_eq!(
+            check_arrays_equal_in_norm!(&x, &x_ref, 5.0)
+                .unwrap()
+                .worst_difference,
+            5.0
+        );
+        for (norm, expected) in [(Norm::L1, 7.0), (Norm::Linf, 4.0), (Norm::Frobenius, 5.0)] {
+            let report = match norm {
+                Norm::L1 => check_arrays_equal_in_norm!(&x, &x_ref, 10.0, norm = L1),
+                Norm::Linf => check_arrays_equal_in_norm!(&x, &x_ref, 10.0, norm = Linf),
+                _ => check_arrays_equal_in_norm!(&x, &x_ref, 10.0, norm = Frobenius),
+            };
+            assert_eq!(report.unwrap().worst_difference, expected);
+        }
+    }
+
+    #[test]
+    fn check_norm_worst_offenders() {
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        let x_ref = [1.1, 2.0, 3.3, 4.0, 5.5, 6.2, 7.4];
+        let error = check_arrays_equal_in_norm!(&x, &x_ref, 0.1, norm = L1).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![0, 2, 4, 5, 6]);
+        let error = check_arrays_equal_in_norm!(&x, &x_ref, 0.1, norm = Linf).unwrap_err();
+        assert_eq!(error.worst_difference, 0.5);
+    }
+
+    #[test]
+    fn check_norm_nan() {
+        let x = [1.0, f64::NAN, 3.0];
+        assert!(check_arrays_equal_in_norm!(&x, &x, 0.0).is_ok());
+        let error = check_arrays_equal_in_norm!(&x, &[1.0, 2.0, 3.0], 1.0).unwrap_err();
+        assert!(error.worst_difference.is_nan());
+        assert_eq!(error.mismatched_indices(), vec![1]);
+    }
+
+    #[test]
+    fn check_rel_norm_zero_reference() {
+        let zeros = [0.0, 0.0];
+        assert_eq!(
+            check_arrays_equal_in_rel_norm!(&zeros, &zeros, 0.0)
+                .unwrap()
+                .worst_difference,
+            0.0
+        );
+        let error = check_arrays_equal_in_rel_norm!(&[1e-12, 0.0], &zeros, 1.0).unwrap_err();
+        assert_eq!(error.worst_difference, f64::INFINITY);
+    }
+
+    #[test]
+    fn check_rel_norm_large_magnitudes() {
+        let x = [1e200, 1e200];
+        let x_ref = [1e200, 1.0000001e200];
+        let report = check_arrays_equal_in_rel_norm!(&x, &x_ref, 1e-3, norm = L2).unwrap();
+        assert!((report.worst_difference - 1e-7 / 2.0_f64.sqrt()).abs() < 1e-12);
+        assert!(check_arrays_equal_in_norm!(&x, &x_ref, 1e193, norm = Frobenius).is_ok());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe two array-like structs are not equal in the L2 norm to an absolute tolerance of 0.01.\n --> Norm of Difference: 0.10000000000000009\n --> Norm of Reference (arr2): 3.7960505792204615\n --> Worst Offenders: 1/3\n\nindex  arr1   arr2  abs diff  rel diff  result\n-----  ----  -----  --------  --------  ------\n    0   1e0    1e0         0         0    pass\n\u{1b}[31;1m    1   2e0  2.1e0      1e-1  4.762e-2    FAIL\u{1b}[0m\n    2   3e0    3e0         0         0    pass\n"
+    )]
+    fn assert_norm_fail() {
+        let x = vec![1.0, 2.0, 3.0];
+        let x_ref = vec![1.0, 2.1, 3.0];
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_in_norm!(&x, &x_ref, 0.01);
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "solver cg\nThe two array-like structs are not equal in the Frobenius norm to a relative tolerance of 0.01.\n --> Relative Norm of Difference: 0.08543576577167611\n --> Norm of Difference: 0.5\n --> Norm of Reference (arr2): 5.852349955359812\n --> Worst Offenders: 1/4\n\n index  arr1   arr2  abs diff  rel diff  result\n------  ----  -----  --------  --------  ------\n(0, 0)   1e0    1e0         0         0    pass\n(0, 1)   2e0    2e0         0         0    pass\n(1, 0)   3e0    3e0         0         0    pass\n\u{1b}[31;1m(1, 1)   4e0  4.5e0      5e-1  1.111e-1    FAIL\u{1b}[0m\n"
+    )]
+    fn assert_rel_norm_fail() {
+        let x = [[1.0, 2.0], [3.0, 4.0]];
+        let x_ref = [[1.0, 2.0], [3.0, 4.5]];
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_equal_in_rel_norm!(&x, &x_ref, 0.01, norm = Frobenius, "solver {}", "cg");
+        });
+    }
+
+    #[test]
+    fn assert_norm_pass() {
+        let x = vec![1.0, 2.0, 3.0];
+        let x_ref = vec![1.0, 2.001, 3.0];
+        assert_arrays_equal_in_norm!(&x, &x_ref, 0.01);
+        assert_arrays_equal_in_norm!(&x, &x_ref, 0.01, "custom {}", "message");
+        assert_arrays_equal_in_rel_norm!(&x, &x_ref, 1e-3, norm = L1);
+    }
+}
@@ -0,0 +1,887 @@
+#[allow(unused_imports)]
+use crate::compare::Compare;
+
+/// Checks that a floating-point number is less than or equal to an upper bound to within a
+/// specified absolute tolerance.
+///
+/// This macro is the non-panicking counterpart of [`assert_less_than`](crate::assert_less_than).
+///
+/// # Arguments
+///
+/// * `a` - The floating-point number to compare.
+/// * `bound` - Upper bound.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if the floating-point number is less than or
+/// equal to the upper bound to within the specified absolute tolerance, otherwise a
+/// [`ComparisonError`](crate::ComparisonError) whose message is the message that the corresponding
+/// assertion macro panics with. In both cases, the excess over the upper bound is reported as the
+/// worst difference.
+///
+/// # Note
+///
+/// See [`Compare::is_less_than`] for details on how the comparison is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// assert!(check_less_than!(1e-9, 1e-8, 0.0).is_ok());
+///
+/// let error = check_less_than!(1.5, 1.0, 0.1).unwrap_err();
+/// assert_eq!(error.worst_difference, 0.5);
+/// ```
+#[macro_export]
+macro_rules! check_less_than {
+    ($a:expr, $bound:expr, $atol:expr) => {{
+        let (a, bound, atol) = ($a, $bound, $atol);
+        let (result, excess) = a.is_less_than(bound, atol);
+        if result {
+            Ok($crate::ComparisonReport {
+                num_elements: 1,
+                worst_difference: $crate::real_to_f64(excess),
+            })
+        } else {
+            Err($crate::ComparisonError::scalar(
+                a,
+                bound,
+                $crate::real_to_f64(excess),
+                format!(
+                    "\nValue is not less than or equal to the upper bound to within an absolute \
+                    tolerance of {}. It exceeds the upper bound by {}.\n --> a: {:?}\n --> \
+                    bound: {:?}\n",
+                    atol, excess, a, bound
+                ),
+            ))
+        }
+    }};
+}
+
+/// Asserts that a floating-point number is less than or equal to an upper bound to within a
+/// specified absolute tolerance.
+///
+/// # Arguments
+///
+/// * `a` - The floating-point number to compare.
+/// * `bound` - Upper bound.
+/// * `atol` - Absolute tolerance.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// If the floating-point number exceeds the upper bound by more than the absolute tolerance. In
+/// this case, the excess over the upper bound is also printed.
+///
+/// # Note
+///
+/// See [`Compare::is_less_than`] for details on how the comparison is defined.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let error = 3e-9;
+/// assert_less_than!(error, 1e-8, 0.0);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let error = 3e-8;
+/// assert_less_than!(error, 1e-8, 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_less_than {
+    ($a:expr, $bound:expr, $atol:expr) => {{
+        if let Err(error) = $crate::check_less_than!($a, $bound, $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $bound:expr, $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_less_than!($a, $bound, $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks that a floating-point number is greater than or equal to a lower bound to within a
+/// specified absolute tolerance.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_greater_than`](crate::assert_greater_than).
+///
+/// # Arguments
+///
+/// * `a` - The floating-point number to compare.
+/// * `bound` - Lower bound.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if the floating-point number is greater than or
+/// equal to the lower bound to within the specified absolute tolerance, otherwise a
+/// [`ComparisonError`](crate::ComparisonError) whose message is the message that the corresponding
+/// assertion macro panics with. In both cases, the excess under the lower bound is reported as the
+/// worst difference.
+///
+/// # Note
+///
+/// See [`Compare::is_greater_than`] for details on how the comparison is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// assert!(check_greater_than!(0.0, -1e-12, 0.0).is_ok());
+///
+/// let error = check_greater_than!(-0.5, 0.0, 0.1).unwrap_err();
+/// assert_eq!(error.worst_difference, 0.5);
+/// ```
+#[macro_export]
+macro_rules! check_greater_than {
+    ($a:expr, $bound:expr, $atol:expr) => {{
+        let (a, bound, atol) = ($a, $bound, $atol);
+        let (result, excess) = a.is_greater_than(bound, atol);
+        if result {
+            Ok($crate::ComparisonReport {
+                num_elements: 1,
+                worst_difference: $crate::real_to_f64(excess),
+            })
+        } else {
+            Err($crate::ComparisonError::scalar(
+                a,
+                bound,
+                $crate::real_to_f64(excess),
+                format!(
+                    "\nValue is not greater than or equal to the lower bound to within an \
+                    absolute tolerance of {}. It falls below the lower bound by {}.\n --> a: \
+                    {:?}\n --> bound: {:?}\n",
+                    atol, excess, a, bound
+                ),
+            ))
+        }
+    }};
+}
+
+/// Asserts that a floating-point number is greater than or equal to a lower bound to within a
+/// specified absolute tolerance.
+///
+/// # Arguments
+///
+/// * `a` - The floating-point number to compare.
+/// * `bound` - Lower bound.
+/// * `atol` - Absolute tolerance.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// If the floating-point number falls below the lower bound by more than the absolute tolerance.
+/// In this case, the excess under the lower bound is also printed.
+///
+/// # Note
+///
+/// See [`Compare::is_greater_than`] for details on how the comparison is defined.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let variance = -1e-17;
+/// assert_greater_than!(variance, 0.0, 1e-15);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let variance = -1e-3;
+/// assert_greater_than!(variance, 0.0, 1e-15);
+/// ```
+#[macro_export]
+macro_rules! assert_greater_than {
+    ($a:expr, $bound:expr, $atol:expr) => {{
+        if let Err(error) = $crate::check_greater_than!($a, $bound, $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $bound:expr, $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_greater_than!($a, $bound, $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks that a floating-point number lies within a closed interval to within a specified
+/// absolute tolerance.
+///
+/// This macro is the non-panicking counterpart of [`assert_in_range`](crate::assert_in_range).
+///
+/// # Arguments
+///
+/// * `a` - The floating-point number to compare.
+/// * `lower` - Lower bound of the interval.
+/// * `upper` - Upper bound of the interval.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) if the floating-point number lies within the
+/// interval to within the specified absolute tolerance, otherwise a
+/// [`ComparisonError`](crate::ComparisonError) whose message is the message that the corresponding
+/// assertion macro panics with (and whose mismatch pairs the floating-point number with the bound
+/// it violates). In both cases, the excess outside the interval is reported as the worst
+/// difference.
+///
+/// # Note
+///
+/// See [`Compare::is_in_range`] for details on how the comparison is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// assert!(check_in_range!(1.0 + 1e-12, 0.0, 1.0, 1e-9).is_ok());
+///
+/// let error = check_in_range!(-0.25, 0.0, 1.0, 1e-9).unwrap_err();
+/// assert_eq!(error.worst_difference, 0.25);
+/// assert_eq!(error.mismatches[0].b, 0.0);
+/// ```
+#[macro_export]
+macro_rules! check_in_range {
+    ($a:expr, $lower:expr, $upper:expr, $atol:expr) => {{
+        let (a, lower, upper, atol) = ($a, $lower, $upper, $atol);
+        let (result, excess) = a.is_in_range(lower, upper, atol);
+        if result {
+            Ok($crate::ComparisonReport {
+                num_elements: 1,
+                worst_difference: $crate::real_to_f64(excess),
+            })
+        } else {
+            let zero = $crate::__num_traits::Zero::zero();
+            let (_, below) = a.is_greater_than(lower, zero);
+            let bound = if below > zero { lower } else { upper };
+            Err($crate::ComparisonError::scalar(
+                a,
+                bound,
+                $crate::real_to_f64(excess),
+                format!(
+                    "\nValue is not in the range [{:?}, {:?}] to within an absolute tolerance of \
+                    {}. It lies outside the range by {}.\n --> a: {:?}\n",
+                    lower, upper, atol, excess, a
+                ),
+            ))
+        }
+    }};
+}
+
+/// Asserts that a floating-point number lies within a closed interval to within a specified
+/// absolute tolerance.
+///
+/// # Arguments
+///
+/// * `a` - The floating-point number to compare.
+/// * `lower` - Lower bound of the interval.
+/// * `upper` - Upper bound of the interval.
+/// * `atol` - Absolute tolerance.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// If the floating-point number lies outside the interval by more than the absolute tolerance. In
+/// this case, the excess outside the interval is also printed.
+///
+/// # Note
+///
+/// See [`Compare::is_in_range`] for details on how the comparison is defined.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let probability = 1.0000000000000002;
+/// assert_in_range!(probability, 0.0, 1.0, 1e-12);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let probability = 1.1;
+/// assert_in_range!(probability, 0.0, 1.0, 1e-12);
+/// ```
+#[macro_export]
+macro_rules! assert_in_range {
+    ($a:expr, $lower:expr, $upper:expr, $atol:expr) => {{
+        if let Err(error) = $crate::check_in_range!($a, $lower, $upper, $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $lower:expr, $upper:expr, $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_in_range!($a, $lower, $upper, $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks that each element of an array-like struct is less than or equal to the corresponding
+/// element of another to within a specified absolute tolerance.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_less_than`](crate::assert_arrays_less_than).
+///
+/// # Arguments
+///
+/// * `arr1` - Array-like struct to compare. Must either implement the [`Iterator`] trait or have an
+///   `iter` method.
+/// * `arr2` - Array-like struct of upper bounds. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) whose `worst_difference` is the largest excess
+/// over the upper bounds, if all element-wise comparisons pass.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, or if any of the element-wise comparisons fail. The error carries
+/// the indices and values of the offending elements, along with the per-element excesses.
+///
+/// # Note
+///
+/// See [`Compare::is_less_than`] for details on how the comparison is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let residuals = [1e-3, 1e-5, 2e-5];
+/// let previous = [1e-2, 1e-3, 1e-5];
+///
+/// let error = check_arrays_less_than!(&residuals, &previous, 0.0).unwrap_err();
+/// assert_eq!(error.mismatched_indices(), vec![2]);
+/// ```
+#[macro_export]
+macro_rules! check_arrays_less_than {
+    ($arr1:expr, $arr2:expr, $atol:expr) => {{
+        let atol = $atol;
+
+        // Variable to track the maximum excess.
+        let mut max_excess: f64 = 0.0;
+
+        // Compare the two arrays element-wise.
+        $crate::compare_elements(
+            $crate::shaped_elements!($arr1),
+            $crate::shaped_elements!($arr2),
+            |a, b| {
+                let (result, excess) = a.is_less_than(b, atol);
+                let excess: f64 = $crate::real_to_f64(excess);
+                max_excess = max_excess.max(excess);
+                (result, excess)
+            },
+        )
+        .and_then(|comparison| {
+            comparison
+                .with_metric_column("excess")
+                .finish(max_excess, |num_mismatched, count| {
+                    format!(
+                        "\nThe first array-like struct is not less than or equal to the second to \
+                        within an absolute tolerance of {}.\n --> Mismatched Elements: {}/{}\n \
+                        --> Largest Excess: {}",
+                        atol, num_mismatched, count, max_excess
+                    )
+                })
+        })
+    }};
+}
+
+/// Asserts that each element of an array-like struct is less than or equal to the corresponding
+/// element of another to within a specified absolute tolerance.
+///
+/// This macro iterates over the elements of the two structs and checks each pair of elements using
+/// [`Compare::is_less_than`]. Additionally, this macro also checks whether the two structs have the
+/// same number of elements.
+///
+/// # Arguments
+///
+/// * `arr1` - Array-like struct to compare. Must either implement the [`Iterator`] trait or have an
+///   `iter` method.
+/// * `arr2` - Array-like struct of upper bounds. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `atol` - Absolute tolerance.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
+/// * If the two array-like structs do not have the same number of elements. In this case, the
+///   number of elements in each array-like struct is also printed.
+/// * If any of the element-wise comparisons fail. In this case, the two array-like structs will be
+///   printed alongside the excess of each element over its upper bound, with the offending
+///   elements shown in bolded red.
+///
+/// # Note
+///
+/// To compare every element against the same upper bound, use
+/// [`assert_arrays_in_range`](crate::assert_arrays_in_range) with a lower bound of `-Inf`.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// // The residual must not increase between iterations.
+/// let residuals = [1e-2, 1e-3, 1e-3, 1e-5];
+/// assert_arrays_less_than!(&residuals[1..], &residuals[..3], 0.0);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let residuals = [1e-2, 1e-3, 2e-3, 1e-5];
+/// assert_arrays_less_than!(&residuals[1..], &residuals[..3], 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_arrays_less_than {
+    ($arr1:expr, $arr2:expr, $atol:expr) => {{
+        if let Err(error) = $crate::check_arrays_less_than!($arr1, $arr2, $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($arr1:expr, $arr2:expr, $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_less_than!($arr1, $arr2, $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks that each element of an array-like struct is greater than or equal to the corresponding
+/// element of another to within a specified absolute tolerance.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_greater_than`](crate::assert_arrays_greater_than).
+///
+/// # Arguments
+///
+/// * `arr1` - Array-like struct to compare. Must either implement the [`Iterator`] trait or have an
+///   `iter` method.
+/// * `arr2` - Array-like struct of lower bounds. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) whose `worst_difference` is the largest excess
+/// under the lower bounds, if all element-wise comparisons pass.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if the two array-like structs do not have the same
+/// shape or number of elements, or if any of the element-wise comparisons fail. The error carries
+/// the indices and values of the offending elements, along with the per-element excesses.
+///
+/// # Note
+///
+/// See [`Compare::is_greater_than`] for details on how the comparison is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let energies = [1.0, 2.0, 3.0];
+/// let minimums = [0.5, 2.1, 2.0];
+///
+/// let error = check_arrays_greater_than!(&energies, &minimums, 0.05).unwrap_err();
+/// assert_eq!(error.mismatched_indices(), vec![1]);
+/// ```
+#[macro_export]
+macro_rules! check_arrays_greater_than {
+    ($arr1:expr, $arr2:expr, $atol:expr) => {{
+        let atol = $atol;
+
+        // Variable to track the maximum excess.
+        let mut max_excess: f64 = 0.0;
+
+        // Compare the two arrays element-wise.
+        $crate::compare_elements(
+            $crate::shaped_elements!($arr1),
+            $crate::shaped_elements!($arr2),
+            |a, b| {
+                let (result, excess) = a.is_greater_than(b, atol);
+                let excess: f64 = $crate::real_to_f64(excess);
+                max_excess = max_excess.max(excess);
+                (result, excess)
+            },
+        )
+        .and_then(|comparison| {
+            comparison
+                .with_metric_column("excess")
+                .finish(max_excess, |num_mismatched, count| {
+                    format!(
+                        "\nThe first array-like struct is not greater than or equal to the second \
+                        to within an absolute tolerance of {}.\n --> Mismatched Elements: {}/{}\n \
+                        --> Largest Excess: {}",
+                        atol, num_mismatched, count, max_excess
+                    )
+                })
+        })
+    }};
+}
+
+/// Asserts that each element of an array-like struct is greater than or equal to the
+/// corresponding element of another to within a specified absolute tolerance.
+///
+/// This macro iterates over the elements of the two structs and checks each pair of elements using
+/// [`Compare::is_greater_than`]. Additionally, this macro also checks whether the two structs have
+/// the same number of elements.
+///
+/// # Arguments
+///
+/// * `arr1` - Array-like struct to compare. Must either implement the [`Iterator`] trait or have an
+///   `iter` method.
+/// * `arr2` - Array-like struct of lower bounds. Must either implement the [`Iterator`] trait or
+///   have an `iter` method.
+/// * `atol` - Absolute tolerance.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If both array-like structs implement [`ArrayShape`](crate::ArrayShape) and they do not have
+///   the same shape. In this case, the shape of each array-like struct is also printed.
+/// * If the two array-like structs do not have the same number of elements. In this case, the
+///   number of elements in each array-like struct is also printed.
+/// * If any of the element-wise comparisons fail. In this case, the two array-like structs will be
+///   printed alongside the excess of each element under its lower bound, with the offending
+///   elements shown in bolded red.
+///
+/// # Note
+///
+/// To compare every element against the same lower bound, use
+/// [`assert_arrays_in_range`](crate::assert_arrays_in_range) with an upper bound of `Inf`.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let upper = [1.0, 2.0, 3.0];
+/// let lower = [0.0, 2.0 + 1e-12, 2.5];
+/// assert_arrays_greater_than!(&upper, &lower, 1e-9);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let upper = [1.0, 2.0, 3.0];
+/// let lower = [0.0, 2.5, 2.5];
+/// assert_arrays_greater_than!(&upper, &lower, 1e-9);
+/// ```
+#[macro_export]
+macro_rules! assert_arrays_greater_than {
+    ($arr1:expr, $arr2:expr, $atol:expr) => {{
+        if let Err(error) = $crate::check_arrays_greater_than!($arr1, $arr2, $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($arr1:expr, $arr2:expr, $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_greater_than!($arr1, $arr2, $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks that every element of an array-like struct lies within a closed interval to within a
+/// specified absolute tolerance.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_arrays_in_range`](crate::assert_arrays_in_range).
+///
+/// # Arguments
+///
+/// * `arr` - Array-like struct to check. Must either implement the [`Iterator`] trait or have an
+///   `iter` method.
+/// * `lower` - Lower bound of the interval (shared by all elements).
+/// * `upper` - Upper bound of the interval (shared by all elements).
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) whose `worst_difference` is the largest excess
+/// outside the interval, if all elements lie within the interval.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if any element lies outside the interval. Each
+/// mismatch pairs the offending element (`a`) with the bound it violates (`b`).
+///
+/// # Note
+///
+/// See [`Compare::is_in_range`] for details on how the comparison is defined.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let probabilities = vec![0.2, 1.05, -0.1];
+///
+/// let error = check_arrays_in_range!(&probabilities, 0.0, 1.0, 1e-9).unwrap_err();
+/// assert_eq!(error.mismatched_indices(), vec![1, 2]);
+/// assert_eq!(error.worst_difference, 0.1);
+/// ```
+#[macro_export]
+macro_rules! check_arrays_in_range {
+    ($arr:expr, $lower:expr, $upper:expr, $atol:expr) => {{
+        let (lower, upper, atol) = ($lower, $upper, $atol);
+        let zero = $crate::__num_traits::Zero::zero();
+
+        // Variable to track the maximum excess.
+        let mut max_excess: f64 = 0.0;
+
+        // Pair each element with the bound it is closest to violating.
+        let arr = $crate::shaped_elements!($arr);
+        let bounds = $crate::ShapedElements {
+            layout: None,
+            elements: arr
+                .elements
+                .iter()
+                .map(|a| {
+                    let (_, below) = a.is_greater_than(lower, zero);
+                    if below > zero { lower } else { upper }
+                })
+                .collect(),
+            ragged: false,
+        };
+
+        // Compare each element against the interval.
+        $crate::compare_elements(arr, bounds, |a, _| {
+            let (result, excess) = a.is_in_range(lower, upper, atol);
+            let excess: f64 = $crate::real_to_f64(excess);
+            max_excess = max_excess.max(excess);
+            (result, excess)
+        })
+        .and_then(|comparison| {
+            comparison
+                .with_labels("arr", "bound")
+                .with_metric_column("excess")
+                .finish(max_excess, |num_mismatched, count| {
+                    format!(
+                        "\nThe array-like struct is not in the range [{:?}, {:?}] to within an \
+                        absolute tolerance of {}.\n --> Mismatched Elements: {}/{}\n --> Largest \
+                        Excess: {}",
+                        lower, upper, atol, num_mismatched, count, max_excess
+                    )
+                })
+        })
+    }};
+}
+
+/// Asserts that every element of an array-like struct lies within a closed interval to within a
+/// specified absolute tolerance.
+///
+/// This macro iterates over the elements of the struct and checks each element using
+/// [`Compare::is_in_range`].
+///
+/// # Arguments
+///
+/// * `arr` - Array-like struct to check. Must either implement the [`Iterator`] trait or have an
+///   `iter` method.
+/// * `lower` - Lower bound of the interval (shared by all elements).
+/// * `upper` - Upper bound of the interval (shared by all elements).
+/// * `atol` - Absolute tolerance.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// If any element lies outside the interval by more than the absolute tolerance. In this case, the
+/// array-like struct will be printed alongside the bound each element is closest to violating and
+/// the excess of each element outside the interval, with the offending elements shown in bolded
+/// red.
+///
+/// # Note
+///
+/// One-sided bounds can be checked by passing `-Inf` as the lower bound or `Inf` as the upper
+/// bound.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let probabilities = [0.0, 0.5, 1.0 + 1e-12];
+/// assert_arrays_in_range!(&probabilities, 0.0, 1.0, 1e-9);
+///
+/// // Every error must be below 1e-8.
+/// let errors = vec![1e-9, 3e-10, 5e-9];
+/// assert_arrays_in_range!(&errors, f64::NEG_INFINITY, 1e-8, 0.0);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let probabilities = [0.0, 0.5, 1.5];
+/// assert_arrays_in_range!(&probabilities, 0.0, 1.0, 1e-9);
+/// ```
+#[macro_export]
+macro_rules! assert_arrays_in_range {
+    ($arr:expr, $lower:expr, $upper:expr, $atol:expr) => {{
+        if let Err(error) = $crate::check_arrays_in_range!($arr, $lower, $upper, $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($arr:expr, $lower:expr, $upper:expr, $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_arrays_in_range!($arr, $lower, $upper, $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorChoice, with_color_choice};
+
+    #[test]
+    fn check_scalar_ordered() {
+        assert_eq!(
+            check_less_than!(2.5, 2.0, 0.5).unwrap().worst_difference,
+            0.5
+        );
+        assert_eq!(
+            check_greater_than!(1.0_f32, 2.0, 0.5)
+                .unwrap_err()
+                .worst_difference,
+            1.0
+        );
+        let error = check_in_range!(3.0, 0.0, 1.0, 0.0).unwrap_err();
+        assert_eq!(error.worst_difference, 2.0);
+        assert_eq!(error.mismatches[0].b, 1.0);
+        assert!(check_in_range!(f64::NAN, 0.0, 1.0, 1.0).is_err());
+    }
+
+    #[test]
+    fn check_ordered_evaluates_tolerance_once() {
+        let mut num_calls = 0;
+        let mut atol = || {
+            num_calls += 1;
+            0.1
+        };
+        assert!(check_less_than!(2.5, 2.0, atol()).is_err());
+        assert!(check_in_range!(3.0, 0.0, 1.0, atol()).is_err());
+        assert!(check_arrays_greater_than!(&[1.0, 2.0], &[1.5, 2.5], atol()).is_err());
+        assert_eq!(num_calls, 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nValue is not less than or equal to the upper bound to within an absolute tolerance of 0.1. It exceeds the upper bound by 0.5.\n --> a: 1.5\n --> bound: 1.0\n"
+    )]
+    fn assert_less_than_fail() {
+        assert_less_than!(1.5, 1.0, 0.1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "case 2\nValue is not greater than or equal to the lower bound to within an absolute tolerance of 0.1. It falls below the lower bound by 0.5.\n --> a: -0.5\n --> bound: 0.0\n"
+    )]
+    fn assert_greater_than_fail() {
+        assert_greater_than!(-0.5, 0.0, 0.1, "case {}", 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nValue is not in the range [0.0, 1.0] to within an absolute tolerance of 0.1. It lies outside the range by 0.25.\n --> a: -0.25\n"
+    )]
+    fn assert_in_range_fail() {
+        assert_in_range!(-0.25, 0.0, 1.0, 0.1);
+    }
+
+    #[test]
+    fn check_arrays_ordered() {
+        let arr = [1.0, 2.0, 3.0];
+        assert!(check_arrays_less_than!(&arr, &[1.0, 2.5, 3.0], 0.0).is_ok());
+        assert!(check_arrays_greater_than!(&arr, &[1.0, 1.5, 3.0], 0.0).is_ok());
+        assert_eq!(
+            check_arrays_in_range!(&arr, 1.5, 2.5, 0.5)
+                .unwrap()
+                .worst_difference,
+            0.5
+        );
+        let error = check_arrays_in_range!(&arr, 1.5, 2.5, 0.25).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![0, 2]);
+        assert_eq!(error.mismatches[0].b, 1.5);
+        assert_eq!(error.mismatches[1].b, 2.5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe first array-like struct is not less than or equal to the second to within an absolute tolerance of 0.1.\n --> Mismatched Elements: 1/3\n --> Largest Excess: 0.5\n\nindex  arr1   arr2  abs diff  excess  result\n-----  ----  -----  --------  ------  ------\n    0   1e0    1e0         0       0    pass\n\u{1b}[31;1m    1   2e0  1.5e0      5e-1    5e-1    FAIL\u{1b}[0m\n    2   3e0  3.5e0      5e-1       0    pass\n"
+    )]
+    fn assert_arrays_less_than_fail() {
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_less_than!(&vec![1.0, 2.0, 3.0], &vec![1.0, 1.5, 3.5], 0.1);
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe first array-like struct is not greater than or equal to the second to within an absolute tolerance of 0.\n --> Mismatched Elements: 1/2\n --> Largest Excess: 0.5\n\nindex  arr1   arr2  abs diff  excess  result\n-----  ----  -----  --------  ------  ------\n\u{1b}[31;1m    0   1e0  1.5e0      5e-1    5e-1    FAIL\u{1b}[0m\n    1   2e0    1e0         1       0    pass\n"
+    )]
+    fn assert_arrays_greater_than_fail() {
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_greater_than!(&[1.0, 2.0], &[1.5, 1.0], 0.0);
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe array-like struct is not in the range [0.0, 1.0] to within an absolute tolerance of 0.000000001.\n --> Mismatched Elements: 2/4\n --> Largest Excess: 1\n\n index    arr  bound  abs diff  excess  result\n------  -----  -----  --------  ------  ------\n(0, 0)   5e-1    1e0      5e-1       0    pass\n\u{1b}[31;1m(0, 1)  -5e-1    0e0      5e-1    5e-1    FAIL\u{1b}[0m\n(1, 0)    1e0    1e0         0       0    pass\n\u{1b}[31;1m(1, 1)    2e0    1e0         1       1    FAIL\u{1b}[0m\n"
+    )]
+    fn assert_arrays_in_range_fail() {
+        with_color_choice(ColorChoice::Always, || {
+            assert_arrays_in_range!(&[[0.5, -0.5], [1.0, 2.0]], 0.0, 1.0, 1e-9);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "The two arrays must have the same number of elements.")]
+    fn assert_arrays_less_than_count_mismatch() {
+        assert_arrays_less_than!(&[1.0, 2.0], &[1.0], 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn check_arrays_ordered_complex() {
+        use num_complex::Complex64;
+
+        let arr = [Complex64::new(0.5, 0.5), Complex64::new(1.3, 0.6)];
+        let error = check_arrays_in_range!(
+            &arr,
+            Complex64::new(0.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            0.1
+        )
+        .unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![1]);
+        assert_eq!(error.worst_difference, 0.30000000000000004);
+    }
+}
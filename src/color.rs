@@ -0,0 +1,228 @@
+use std::cell::Cell;
+use std::ffi::OsString;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Whether the failure tables printed by the array assertion macros use colour.
+///
+/// When colour is enabled, mismatched rows are shown in bolded red using ANSI escape codes. When
+/// colour is disabled, no escape codes are printed, so that the tables remain readable in CI logs,
+/// XML test reports, and IDE test panes (mismatched rows are still marked as `FAIL` in the last
+/// column).
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use numtest::*;
+///
+/// set_color_choice(ColorChoice::Never);
+///
+/// let error = check_arrays_equal!(&[1.0, 2.0], &[1.0, 2.5]).unwrap_err();
+/// assert!(!error.table.contains('\x1b'));
+/// assert!(error.table.contains("FAIL"));
+///
+/// set_color_choice(ColorChoice::Auto);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorChoice {
+    /// Decide based on the environment (the default).
+    ///
+    /// Colour is used if the `CLICOLOR_FORCE` environment variable is set to anything other than
+    /// `0`. Otherwise, colour is disabled if the `NO_COLOR` environment variable is set to a
+    /// non-empty string, and is only used if stderr is a terminal.
+    #[default]
+    Auto,
+
+    /// Always use colour.
+    Always,
+
+    /// Never use colour.
+    Never,
+}
+
+/// Colour choice set by [`set_color_choice`] (stored as a `u8`).
+static COLOR_CHOICE: AtomicU8 = AtomicU8::new(0);
+
+/// Sets whether the failure tables printed by the array assertion macros use colour (see
+/// [`ColorChoice`]).
+///
+/// This setting is global, so it applies to all threads (and therefore to all tests in the same
+/// test binary).
+///
+/// # Arguments
+///
+/// * `choice` - Colour choice.
+pub fn set_color_choice(choice: ColorChoice) {
+    let value = match choice {
+        ColorChoice::Auto => 0,
+        ColorChoice::Always => 1,
+        ColorChoice::Never => 2,
+    };
+    COLOR_CHOICE.store(value, Ordering::Relaxed);
+}
+
+/// Gets the colour choice set by [`set_color_choice`].
+///
+/// # Returns
+///
+/// Colour choice ([`ColorChoice::Auto`] if [`set_color_choice`] was never called).
+#[must_use]
+pub fn color_choice() -> ColorChoice {
+    match COLOR_CHOICE.load(Ordering::Relaxed) {
+        1 => ColorChoice::Always,
+        2 => ColorChoice::Never,
+        _ => ColorChoice::Auto,
+    }
+}
+
+thread_local! {
+    /// Colour choice set by [`with_color_choice`] for the current thread (`None` outside of
+    /// [`with_color_choice`]).
+    static THREAD_COLOR_CHOICE: Cell<Option<ColorChoice>> = const { Cell::new(None) };
+}
+
+/// Runs a closure with the given colour choice (see [`ColorChoice`]), overriding the choice set by
+/// [`set_color_choice`].
+///
+/// Unlike [`set_color_choice`], this setting only applies to the current thread and only for the
+/// duration of the closure (the previous setting is restored even if the closure panics). This
+/// makes it suitable for tests, which run in parallel within the same test binary.
+///
+/// # Arguments
+///
+/// * `choice` - Colour choice.
+/// * `f` - Closure to run.
+///
+/// # Returns
+///
+/// Value returned by the closure.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let error = with_color_choice(ColorChoice::Always, || {
+///     check_arrays_equal!(&[1.0, 2.0], &[1.0, 2.5]).unwrap_err()
+/// });
+/// assert!(error.table.contains("\x1b[31;1m"));
+///
+/// let error = with_color_choice(ColorChoice::Never, || {
+///     check_arrays_equal!(&[1.0, 2.0], &[1.0, 2.5]).unwrap_err()
+/// });
+/// assert!(!error.table.contains('\x1b'));
+/// ```
+pub fn with_color_choice<R>(choice: ColorChoice, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous colour choice of the current thread when dropped.
+    struct Restore(Option<ColorChoice>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            THREAD_COLOR_CHOICE.set(self.0);
+        }
+    }
+
+    let _restore = Restore(THREAD_COLOR_CHOICE.replace(Some(choice)));
+    f()
+}
+
+/// Determines whether colour should be used.
+///
+/// # Arguments
+///
+/// * `choice` - Colour choice.
+/// * `no_color` - Value of the `NO_COLOR` environment variable (`None` if it is not set).
+/// * `clicolor_force` - Value of the `CLICOLOR_FORCE` environment variable (`None` if it is not
+///   set).
+/// * `is_terminal` - Whether stderr is a terminal.
+///
+/// # Returns
+///
+/// `true` if colour should be used, `false` otherwise.
+fn resolve_color(
+    choice: ColorChoice,
+    no_color: Option<OsString>,
+    clicolor_force: Option<OsString>,
+    is_terminal: bool,
+) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            if clicolor_force.is_some_and(|value| value != "0") {
+                true
+            } else if no_color.is_some_and(|value| !value.is_empty()) {
+                false
+            } else {
+                is_terminal
+            }
+        }
+    }
+}
+
+/// Determines whether the failure tables should use colour, based on the colour choice set by
+/// [`with_color_choice`] (or, outside of [`with_color_choice`], by [`set_color_choice`]) and the
+/// environment.
+///
+/// # Returns
+///
+/// `true` if colour should be used, `false` otherwise.
+pub(crate) fn use_color() -> bool {
+    resolve_color(
+        THREAD_COLOR_CHOICE.get().unwrap_or_else(color_choice),
+        std::env::var_os("NO_COLOR"),
+        std::env::var_os("CLICOLOR_FORCE"),
+        std::io::stderr().is_terminal(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_color_override() {
+        let set = Some(OsString::from("1"));
+        assert!(resolve_color(ColorChoice::Always, set.clone(), None, false));
+        assert!(!resolve_color(ColorChoice::Never, None, set, true));
+    }
+
+    #[test]
+    fn resolve_color_auto() {
+        let auto = ColorChoice::Auto;
+        assert!(resolve_color(auto, None, None, true));
+        assert!(!resolve_color(auto, None, None, false));
+
+        // NO_COLOR disables colour (unless it is empty).
+        assert!(!resolve_color(auto, Some("1".into()), None, true));
+        assert!(resolve_color(auto, Some("".into()), None, true));
+
+        // CLICOLOR_FORCE enables colour (unless it is 0), even if NO_COLOR is set.
+        assert!(resolve_color(auto, None, Some("1".into()), false));
+        assert!(resolve_color(
+            auto,
+            Some("1".into()),
+            Some("1".into()),
+            false
+        ));
+        assert!(!resolve_color(auto, None, Some("0".into()), false));
+    }
+
+    #[test]
+    fn with_color_choice_scoped() {
+        assert!(with_color_choice(ColorChoice::Always, use_color));
+        assert!(!with_color_choice(ColorChoice::Never, use_color));
+
+        // Nested calls override the outer choice, and the previous choice is restored afterwards
+        // (even if the closure panics).
+        with_color_choice(ColorChoice::Never, || {
+            assert!(with_color_choice(ColorChoice::Always, use_color));
+            let result = std::panic::catch_unwind(|| {
+                with_color_choice(ColorChoice::Always, || panic!("closure panicked"))
+            });
+            assert!(result.is_err());
+            assert!(!use_color());
+        });
+        assert_eq!(THREAD_COLOR_CHOICE.get(), None);
+    }
+}
@@ -2,7 +2,42 @@ use crate::precision::Precision;
 use num_traits::Float;
 
 /// Trait for comparing floating-point numbers.
+///
+/// This trait is implemented for every type that implements both
+/// [`num_traits::Float`](https://docs.rs/num-traits/latest/num_traits/float/trait.Float.html) and
+/// [`Precision`] (see [Custom floating-point types](Precision#custom-floating-point-types)).
+///
+/// # Complex numbers
+///
+/// With the `num-complex` feature enabled, this trait is also implemented for
+/// [`num_complex::Complex`](https://docs.rs/num-complex/latest/num_complex/struct.Complex.html)
+/// numbers whose components implement this trait. For complex numbers:
+///
+/// * [`Compare::is_equal`], [`Compare::is_equal_to_decimal`] and [`Compare::is_equal_to_ulps`]
+///   compare the real and imaginary parts separately (the actual decimal precision is the smaller
+///   of the two, and the ULP distance is the larger of the two).
+/// * [`Compare::is_equal_to_sigfigs`] also compares the real and imaginary parts separately, but
+///   counts the significant figures from the leading digit of the larger of the moduli $\|a\|$ and
+///   $\|b\|$ (so a component that is small relative to the modulus does not need to match to as
+///   many significant figures of its own).
+/// * [`Compare::is_equal_to_atol`], [`Compare::is_equal_to_rtol`], [`Compare::is_close`] and
+///   [`Compare::is_close_symmetric`] use the modulus of the difference, $\|a-b\|$, as the absolute
+///   difference, and the moduli $\|a\|$ and $\|b\|$ to scale the relative difference. The
+///   tolerances and the returned differences are real numbers.
+/// * `NaN`s and `Inf`s are handled per component, using the same special cases as for real numbers.
+///   If either number has a `NaN` or `Inf` component, the absolute difference is
+///   $\sqrt{\Delta_{\mathrm{re}}^{2}+\Delta_{\mathrm{im}}^{2}}$ and the relative difference is
+///   $\mathrm{max}(\delta_{\mathrm{re}},\delta_{\mathrm{im}})$, where $\Delta$ and $\delta$ are
+///   the absolute and relative differences of each pair of components.
+/// * [`Compare::is_less_than`], [`Compare::is_greater_than`] and [`Compare::is_in_range`] apply
+///   the bounds to the real and imaginary parts separately (i.e. the bounds define a rectangle in
+///   the complex plane), and the returned excess is the distance from the rectangle,
+///   $\sqrt{e_{\mathrm{re}}^{2}+e_{\mathrm{im}}^{2}}$, where $e$ is the excess of each component.
 pub trait Compare {
+    /// Real floating-point type used for tolerances and differences (`Self` for floating-point
+    /// numbers, and the component type for complex numbers).
+    type Real: Float;
+
     /// Determines if a floating-point number is exactly equal to another.
     ///
     /// # Arguments
@@ -29,9 +64,7 @@ pub trait Compare {
     ///
     /// assert!(123.45678.is_equal(123.45678));
     /// ```
-    fn is_equal(&self, other: Self) -> bool
-    where
-        Self: Float;
+    fn is_equal(&self, other: Self) -> bool;
 
     /// Determines if a floating-point number is equal to another within the specified decimal
     /// precision.
@@ -90,7 +123,9 @@ pub trait Compare {
     ///
     /// Unlike [NumPy's `assert_almost_equal` function](https://numpy.org/doc/stable/reference/generated/numpy.testing.assert_almost_equal.html),
     /// this method allows negative decimal precisions. For example, a decimal precision of
-    /// `decimal = -1` corresponds to equality to the 10's place.
+    /// `decimal = -1` corresponds to equality to the 10's place. For numbers of very large or very
+    /// small magnitude, comparing significant figures with [`Compare::is_equal_to_sigfigs`] is
+    /// usually more convenient.
     ///
     /// ```
     /// use numtest::Compare;
@@ -138,9 +173,67 @@ pub trait Compare {
     ///
     /// Note that [NumPy](https://numpy.org/doc/stable/reference/generated/numpy.testing.assert_almost_equal.html)
     /// makes the same assumptions.
-    fn is_equal_to_decimal(&self, other: Self, decimal: i32) -> (bool, i32)
-    where
-        Self: Float;
+    fn is_equal_to_decimal(&self, other: Self, decimal: i32) -> (bool, i32);
+
+    /// Determines if a floating-point number is equal to another to the specified number of
+    /// significant figures.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first floating-point number to compare.
+    /// * `other` - The second floating-point number to compare against.
+    /// * `sigfigs` - Number of significant figures to use for comparison.
+    ///
+    /// # Returns
+    ///
+    /// A tuple where the first element indicates whether the two floats are equal (`true`) or not
+    /// (`false`) to the specified number of significant figures, and the second element is the
+    /// actual number of matching significant figures.
+    ///
+    /// # Definition
+    ///
+    /// Unlike the decimal precision (see [`Compare::is_equal_to_decimal`]), which counts places
+    /// after the decimal point, the number of significant figures is counted from the leading
+    /// digit of the larger (in magnitude) of the two floats. We say that two floats, $a$ and $b$,
+    /// are equal to $n$ significant figures if
+    ///
+    /// $$\|a-b\|\leq(1.5)\left(10^{e-n+1}\right)$$
+    ///
+    /// where
+    ///
+    /// $$e=\left\lfloor\log_{10}\left(\mathrm{max}(\|a\|,\|b\|)\right)\right\rfloor$$
+    ///
+    /// is the exponent of the leading significant digit. This is equivalent to comparing the two
+    /// floats to $n-1-e$ decimal places (including the 1.5 multiplier, for the reasons discussed
+    /// in [`Compare::is_equal_to_decimal`]), so the comparison is meaningful for numbers of any
+    /// magnitude (e.g. `6.02e23` or `1.6e-19`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numtest::Compare;
+    ///
+    /// let (result, actual_sigfigs) = 6.02214076e23.is_equal_to_sigfigs(6.0221e23, 4);
+    /// assert!(result);
+    /// assert_eq!(actual_sigfigs, 5);
+    ///
+    /// let (result, actual_sigfigs) = 1.602176634e-19.is_equal_to_sigfigs(1.6e-19, 4);
+    /// assert!(!result);
+    /// assert_eq!(actual_sigfigs, 3);
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// * The actual number of significant figures is capped at `MAX_DECIMAL + 2` (see
+    ///   [`Precision::MAX_DECIMAL`]), which is enough significant figures to distinguish any two
+    ///   floats of the same type (e.g. 17 for [`f64`] and 9 for [`f32`]). This is also the actual
+    ///   number of significant figures returned if the two floats are exactly equal.
+    /// * `NaN`s and `Inf`s are handled like in [`Compare::is_equal_to_decimal`]: `NaN`s are equal
+    ///   to each other, `Inf`s of the same sign are equal to each other, and if the two floats are
+    ///   completely unequal (for example, comparing `NaN` with `0.0` or `Inf`), the actual number
+    ///   of significant figures is returned as `0` (so only a comparison to `0` significant figures
+    ///   passes).
+    fn is_equal_to_sigfigs(&self, other: Self, sigfigs: u32) -> (bool, u32);
 
     /// Determines if a floating-point number is equal to another within the specified absolute
     /// tolerance.
@@ -189,9 +282,7 @@ pub trait Compare {
     /// assert!(result);
     /// assert_eq!(abs_diff, 0.002130000000008181);
     /// ```
-    fn is_equal_to_atol(&self, other: Self, atol: Self) -> (bool, Self)
-    where
-        Self: Float;
+    fn is_equal_to_atol(&self, other: Self, atol: Self::Real) -> (bool, Self::Real);
 
     /// Determines if a floating-point number is equal to another within the specified relative
     /// tolerance.
@@ -247,131 +338,929 @@ pub trait Compare {
     /// assert!(result);
     /// assert_eq!(rel_diff, 1.7252703753890107e-5);
     /// ```
-    fn is_equal_to_rtol(&self, other: Self, rtol: Self) -> (bool, Self)
-    where
-        Self: Float;
+    fn is_equal_to_rtol(&self, other: Self, rtol: Self::Real) -> (bool, Self::Real);
+
+    /// Determines if a floating-point number is equal to another to within the specified number of
+    /// units in the last place (ULPs).
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first floating-point number to compare.
+    /// * `other` - The second floating-point number to compare against.
+    /// * `max_ulps` - Maximum allowable distance between the two floats, in ULPs.
+    ///
+    /// # Returns
+    ///
+    /// A tuple where the first element indicates whether the two floats are equal (`true`) or not
+    /// (`false`) to within the specified number of ULPs, and the second element is the actual ULP
+    /// distance between the two floats.
+    ///
+    /// # Definition
+    ///
+    /// The ULP distance between two floats, $a$ and $b$, is the number of representable floats of
+    /// the same type lying between $a$ and $b$, plus one (so adjacent floats are 1 ULP apart). It
+    /// is computed by mapping the bit patterns of the two floats onto a single monotonically
+    /// increasing integer scale and taking the difference. This method performs the comparison
+    ///
+    /// $$\text{ULP distance}(a,b)\leq\text{max ULPs}$$
+    ///
+    /// Since the spacing between adjacent floats is uniform across the subnormal range and across
+    /// the boundary between subnormal and normal numbers, subnormals require no special treatment.
+    ///
+    /// # Special Cases
+    ///
+    /// | Float 1 | Float 2 | ULP Distance |
+    /// | ------- | ------- | ------------ |
+    /// | `0.0` | `-0.0` | `0` |
+    /// | `NaN` | `NaN` | `0` |
+    /// | `NaN` | `-NaN` | `0` |
+    /// | `-NaN` | `-NaN` | `0` |
+    /// | `Inf` | `NaN` | [`u64::MAX`] |
+    /// | `Inf` | `Inf` | `0` |
+    /// | `-Inf` | `-Inf` | `0` |
+    /// | `Inf` | `-Inf` | [`u64::MAX`] |
+    /// | `NaN` | [any other float] | [`u64::MAX`] |
+    /// | `Inf` | [any other float] | [`u64::MAX`] |
+    ///
+    /// As with [`Compare::is_equal_to_rtol`], comparisons involving exactly one `NaN` or `Inf` are
+    /// treated as completely unequal, and will only pass if `max_ulps` is [`u64::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numtest::Compare;
+    ///
+    /// let (result, ulps) = 1.0_f64.is_equal_to_ulps(1.0 + 2.0 * f64::EPSILON, 4);
+    /// assert!(result);
+    /// assert_eq!(ulps, 2);
+    /// ```
+    fn is_equal_to_ulps(&self, other: Self, max_ulps: u64) -> (bool, u64);
+
+    /// Determines if a floating-point number is close to another to within the specified
+    /// combination of relative and absolute tolerances.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first floating-point number to compare.
+    /// * `other` - The second floating-point number to compare against (i.e. the reference value).
+    /// * `rtol` - Relative tolerance.
+    /// * `atol` - Absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A tuple where the first element indicates whether the two floats are close (`true`) or not
+    /// (`false`), the second element is the actual absolute difference between the two floats, and
+    /// the third element is the actual relative difference between the two floats.
+    ///
+    /// # Definition
+    ///
+    /// Like [NumPy's `isclose` function](https://numpy.org/doc/stable/reference/generated/numpy.isclose.html),
+    /// this method performs the comparison
+    ///
+    /// $$\|a-b\|\leq\text{absolute tolerance}+(\text{relative tolerance})\|b\|$$
+    ///
+    /// The absolute tolerance dominates for values near zero, while the relative tolerance
+    /// dominates for values of large magnitude. The relative difference is defined here as
+    ///
+    /// $$\text{relative difference} = \frac{\|a-b\|}{\|b\|}$$
+    ///
+    /// Note that this comparison is _not_ symmetric in $a$ and $b$, since $b$ is treated as the
+    /// reference value. See [`Compare::is_close_symmetric`] for a symmetric variant.
+    ///
+    /// # Special Cases
+    ///
+    /// | Float 1 | Float 2 | Absolute Difference | Relative Difference |
+    /// | ------- | ------- | ------------------- | ------------------- |
+    /// | `0.0` | `0.0` | `0.0` | `0.0` |
+    /// | [any nonzero float] | `0.0` | $\|a\|$ | `Inf` |
+    /// | `NaN` | `NaN` | `0.0` | `0.0` |
+    /// | `NaN` | `-NaN` | `0.0` | `0.0` |
+    /// | `-NaN` | `-NaN` | `0.0` | `0.0` |
+    /// | `Inf` | `NaN` | `NaN` | `1.0` |
+    /// | `Inf` | `Inf` | `0.0` | `0.0` |
+    /// | `-Inf` | `-Inf` | `0.0` | `0.0` |
+    /// | `Inf` | `-Inf` | `Inf` | `1.0` |
+    /// | `NaN` | [any other float] | `NaN` | `1.0` |
+    /// | `Inf` | [any other float] | `Inf` | `1.0` |
+    ///
+    /// Comparisons involving exactly one `NaN` or `Inf` always fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numtest::Compare;
+    ///
+    /// // The absolute tolerance allows values near zero to pass.
+    /// let (result, abs_diff, rel_diff) = 1e-10.is_close(0.0, 1e-5, 1e-8);
+    /// assert!(result);
+    /// assert_eq!(abs_diff, 1e-10);
+    /// assert_eq!(rel_diff, f64::INFINITY);
+    ///
+    /// // The relative tolerance allows values of large magnitude to pass.
+    /// let (result, abs_diff, rel_diff) = 1000.001.is_close(1000.0, 1e-5, 1e-8);
+    /// assert!(result);
+    /// ```
+    fn is_close(
+        &self,
+        other: Self,
+        rtol: Self::Real,
+        atol: Self::Real,
+    ) -> (bool, Self::Real, Self::Real);
+
+    /// Determines if a floating-point number is close to another to within the specified
+    /// combination of relative and absolute tolerances, treating both numbers symmetrically.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first floating-point number to compare.
+    /// * `other` - The second floating-point number to compare against.
+    /// * `rtol` - Relative tolerance.
+    /// * `atol` - Absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A tuple where the first element indicates whether the two floats are close (`true`) or not
+    /// (`false`), the second element is the actual absolute difference between the two floats, and
+    /// the third element is the actual relative difference between the two floats.
+    ///
+    /// # Definition
+    ///
+    /// This method performs the comparison
+    ///
+    /// $$\|a-b\|\leq\text{absolute tolerance}+(\text{relative tolerance})\mathrm{max}(\|a\|,\|b\|)$$
+    ///
+    /// where the relative difference is defined identically to [`Compare::is_equal_to_rtol`] as
+    ///
+    /// $$\text{relative difference} = \frac{\|a-b\|}{\mathrm{max}(\|a\|,\|b\|)}$$
+    ///
+    /// As a result, `a.is_close_symmetric(b, rtol, atol)` and `b.is_close_symmetric(a, rtol, atol)`
+    /// always return identical results. For finite floats, setting `atol` to `0.0` reduces this
+    /// method to [`Compare::is_equal_to_rtol`]. Comparisons involving exactly one `NaN` or `Inf`
+    /// always fail, whereas [`Compare::is_equal_to_rtol`] passes them if `rtol` is at least `1.0`.
+    ///
+    /// # Special Cases
+    ///
+    /// The special cases are identical to those of [`Compare::is_close`], except that comparing a
+    /// nonzero float against `0.0` yields a relative difference of `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numtest::Compare;
+    ///
+    /// let (result, abs_diff, rel_diff) = 0.0.is_close_symmetric(1e-10, 1e-5, 1e-8);
+    /// assert!(result);
+    /// assert_eq!(abs_diff, 1e-10);
+    /// assert_eq!(rel_diff, 1.0);
+    /// ```
+    fn is_close_symmetric(
+        &self,
+        other: Self,
+        rtol: Self::Real,
+        atol: Self::Real,
+    ) -> (bool, Self::Real, Self::Real);
+
+    /// Determines if a floating-point number is less than or equal to an upper bound to within the
+    /// specified absolute tolerance.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The floating-point number to compare.
+    /// * `bound` - Upper bound.
+    /// * `atol` - Absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A tuple where the first element indicates whether the float is less than or equal to the
+    /// upper bound (`true`) or not (`false`) to within the specified absolute tolerance, and the
+    /// second element is the excess (i.e. how far above the upper bound the float is, or `0` if
+    /// it is not above the upper bound).
+    ///
+    /// # Definition
+    ///
+    /// We define the excess of a float, $a$, over an upper bound, $u$, as
+    ///
+    /// $$\text{excess} = \mathrm{max}(a-u,0)$$
+    ///
+    /// This method performs the comparison
+    ///
+    /// $$\text{excess}\leq\text{absolute tolerance}$$
+    ///
+    /// It also returns the excess itself.
+    ///
+    /// # Special Cases
+    ///
+    /// `NaN`s and `Inf`s are handled in the same way as in [`Compare::is_equal_to_atol`] (i.e. a
+    /// `NaN` only satisfies a `NaN` bound, and `Inf`s of the same sign satisfy each other).
+    ///
+    /// | Float | Bound | Excess |
+    /// | ----- | ----- | ------ |
+    /// | `NaN` | `NaN` | `0.0` |
+    /// | `NaN` | [any other float] | `NaN` |
+    /// | [any other float] | `NaN` | `NaN` |
+    /// | `Inf` | `Inf` | `0.0` |
+    /// | `-Inf` | `-Inf` | `0.0` |
+    /// | `Inf` | [any finite float] | `Inf` |
+    /// | [any finite float] | `-Inf` | `Inf` |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numtest::Compare;
+    ///
+    /// let (result, excess) = 1.0.is_less_than(2.0, 0.0);
+    /// assert!(result);
+    /// assert_eq!(excess, 0.0);
+    ///
+    /// let (result, excess) = 2.5.is_less_than(2.0, 0.1);
+    /// assert!(!result);
+    /// assert_eq!(excess, 0.5);
+    /// ```
+    fn is_less_than(&self, bound: Self, atol: Self::Real) -> (bool, Self::Real);
+
+    /// Determines if a floating-point number is greater than or equal to a lower bound to within
+    /// the specified absolute tolerance.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The floating-point number to compare.
+    /// * `bound` - Lower bound.
+    /// * `atol` - Absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A tuple where the first element indicates whether the float is greater than or equal to the
+    /// lower bound (`true`) or not (`false`) to within the specified absolute tolerance, and the
+    /// second element is the excess (i.e. how far below the lower bound the float is, or `0` if
+    /// it is not below the lower bound).
+    ///
+    /// # Definition
+    ///
+    /// We define the excess of a float, $a$, under a lower bound, $l$, as
+    ///
+    /// $$\text{excess} = \mathrm{max}(l-a,0)$$
+    ///
+    /// This method performs the comparison
+    ///
+    /// $$\text{excess}\leq\text{absolute tolerance}$$
+    ///
+    /// It also returns the excess itself.
+    ///
+    /// # Special Cases
+    ///
+    /// The special cases mirror those of [`Compare::is_less_than`] (with the signs of the `Inf`s
+    /// flipped).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numtest::Compare;
+    ///
+    /// let (result, excess) = 0.999999.is_greater_than(1.0, 1e-5);
+    /// assert!(result);
+    /// assert_eq!(excess, 1.0000000000287557e-6);
+    /// ```
+    fn is_greater_than(&self, bound: Self, atol: Self::Real) -> (bool, Self::Real);
+
+    /// Determines if a floating-point number lies within a closed interval to within the specified
+    /// absolute tolerance.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The floating-point number to compare.
+    /// * `lower` - Lower bound of the interval.
+    /// * `upper` - Upper bound of the interval.
+    /// * `atol` - Absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A tuple where the first element indicates whether the float lies within the interval
+    /// (`true`) or not (`false`) to within the specified absolute tolerance, and the second element
+    /// is the excess (i.e. how far outside the interval the float is, or `0` if it is inside the
+    /// interval).
+    ///
+    /// # Definition
+    ///
+    /// We define the excess of a float, $a$, outside the interval $\[l,u\]$ as
+    ///
+    /// $$\text{excess} = \mathrm{max}(l-a,a-u,0)$$
+    ///
+    /// This method performs the comparison
+    ///
+    /// $$\text{excess}\leq\text{absolute tolerance}$$
+    ///
+    /// which is equivalent to checking both [`Compare::is_greater_than`] with the lower bound and
+    /// [`Compare::is_less_than`] with the upper bound.
+    ///
+    /// # Special Cases
+    ///
+    /// The special cases are those of [`Compare::is_less_than`] and [`Compare::is_greater_than`].
+    /// If either bound check yields a `NaN` excess, the excess is `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numtest::Compare;
+    ///
+    /// // Probabilities must lie in [0, 1] up to rounding.
+    /// let (result, _) = 1.0000000001.is_in_range(0.0, 1.0, 1e-9);
+    /// assert!(result);
+    ///
+    /// let (result, excess) = (-0.25).is_in_range(0.0, 1.0, 1e-9);
+    /// assert!(!result);
+    /// assert_eq!(excess, 0.25);
+    /// ```
+    fn is_in_range(&self, lower: Self, upper: Self, atol: Self::Real) -> (bool, Self::Real);
 }
 
-// Implementing Compare trait for f32's and f64's.
-macro_rules! impl_compare {
-    ($t:ty) => {
-        impl Compare for $t {
-            // Implements the is_equal method.
-            fn is_equal(&self, other: Self) -> bool {
-                // Edge case: NaNs.
-                if self.is_nan() || other.is_nan() {
-                    return self.is_nan() && other.is_nan();
-                }
+/// Determines if a floating-point number is close to another to within the specified combination
+/// of relative and absolute tolerances.
+///
+/// # Arguments
+///
+/// * `a` - The first floating-point number to compare.
+/// * `b` - The second floating-point number to compare against.
+/// * `rtol` - Relative tolerance.
+/// * `atol` - Absolute tolerance.
+/// * `symmetric` - `true` if the relative tolerance should be scaled by $\mathrm{max}(\|a\|,\|b\|)$,
+///   `false` if it should be scaled by $\|b\|$.
+///
+/// # Returns
+///
+/// A tuple where the first element indicates whether the two floats are close (`true`) or not
+/// (`false`), the second element is the absolute difference, and the third element is the relative
+/// difference.
+fn is_close<T: Float>(a: T, b: T, rtol: T, atol: T, symmetric: bool) -> (bool, T, T) {
+    // Edge case: both are NaNs.
+    if a.is_nan() && b.is_nan() {
+        (true, T::zero(), T::zero())
+    }
+    // Edge case: only one is NaN.
+    else if a.is_nan() || b.is_nan() {
+        (false, T::nan(), T::one())
+    }
+    // Edge case: both are Infs.
+    else if a.is_infinite() && b.is_infinite() {
+        if a.signum() == b.signum() {
+            (true, T::zero(), T::zero())
+        } else {
+            (false, T::infinity(), T::one())
+        }
+    }
+    // Edge case: only one is Inf.
+    else if a.is_infinite() || b.is_infinite() {
+        (false, T::infinity(), T::one())
+    }
+    // Standard case.
+    else {
+        let abs_diff = (a - b).abs();
+        let scale = if symmetric {
+            a.abs().max(b.abs())
+        } else {
+            b.abs()
+        };
+        let result = abs_diff <= atol + rtol * scale;
+        let rel_diff = if abs_diff == T::zero() {
+            T::zero()
+        } else {
+            abs_diff / scale
+        };
+        (result, abs_diff, rel_diff)
+    }
+}
+
+/// Determines if a floating-point number is less than or equal to an upper bound to within the
+/// specified absolute tolerance.
+///
+/// # Arguments
+///
+/// * `a` - The floating-point number to compare.
+/// * `bound` - Upper bound.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// A tuple where the first element indicates whether the float is less than or equal to the upper
+/// bound (`true`) or not (`false`), and the second element is the excess.
+fn is_less_than<T: Float>(a: T, bound: T, atol: T) -> (bool, T) {
+    // Edge case: both are NaNs.
+    if a.is_nan() && bound.is_nan() {
+        (true, T::zero())
+    }
+    // Edge case: only one is NaN.
+    else if a.is_nan() || bound.is_nan() {
+        (atol.is_nan(), T::nan())
+    }
+    // Edge case: Infs of same sign.
+    else if a.is_infinite() && bound.is_infinite() && a.signum() == bound.signum() {
+        (true, T::zero())
+    }
+    // Standard case.
+    else {
+        let excess = (a - bound).max(T::zero());
+        (excess <= atol, excess)
+    }
+}
+
+/// Determines if a floating-point number lies within a closed interval to within the specified
+/// absolute tolerance.
+///
+/// # Arguments
+///
+/// * `a` - The floating-point number to compare.
+/// * `lower` - Lower bound of the interval.
+/// * `upper` - Upper bound of the interval.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// A tuple where the first element indicates whether the float lies within the interval (`true`)
+/// or not (`false`), and the second element is the excess.
+fn is_in_range<T: Float>(a: T, lower: T, upper: T, atol: T) -> (bool, T) {
+    let (result_lower, excess_lower) = is_less_than(lower, a, atol);
+    let (result_upper, excess_upper) = is_less_than(a, upper, atol);
+    let excess = if excess_lower.is_nan() || excess_upper.is_nan() {
+        T::nan()
+    } else {
+        excess_lower.max(excess_upper)
+    };
+    (result_lower && result_upper, excess)
+}
+
+/// Largest absolute difference allowed between two floats that are equal to a given decimal
+/// precision.
+///
+/// # Arguments
+///
+/// * `decimal` - Decimal precision.
+///
+/// # Returns
+///
+/// Largest allowed absolute difference, $(1.5)\left(10^{-d}\right)$.
+fn decimal_tolerance<T: Float>(decimal: i32) -> T {
+    let multiplier: T = num_traits::cast(1.5).unwrap_or_else(T::one);
+    let ten: T = num_traits::cast(10.0).unwrap_or_else(T::one);
+    multiplier * ten.powi(-decimal)
+}
+
+/// Exponent of the leading significant digit of a positive, finite floating-point number.
+///
+/// # Arguments
+///
+/// * `x` - Positive, finite floating-point number.
+///
+/// # Returns
+///
+/// Exponent of the leading significant digit, $\lfloor\log_{10}(x)\rfloor$.
+fn leading_exponent<T: Float>(x: T) -> i32 {
+    let ten: T = num_traits::cast(10.0).unwrap_or_else(T::one);
+    let mut exponent = x.log10().floor().to_i32().unwrap_or(0);
+
+    // Correct for rounding errors in the logarithm (e.g. for exact powers of 10).
+    if ten.powi(exponent + 1) <= x {
+        exponent += 1;
+    } else if ten.powi(exponent) > x {
+        exponent -= 1;
+    }
+    exponent
+}
+
+/// Determines if two finite floating-point numbers that are not exactly equal are equal to the
+/// specified number of significant figures, by comparing them to the equivalent decimal
+/// precision.
+///
+/// # Arguments
+///
+/// * `a` - The first floating-point number to compare.
+/// * `b` - The second floating-point number to compare against.
+/// * `sigfigs` - Number of significant figures to use for comparison.
+/// * `scale` - Larger of the magnitudes of the two floating-point numbers.
+/// * `max_sigfigs` - Largest number of significant figures that can be reported.
+///
+/// # Returns
+///
+/// A tuple where the first element indicates whether the two floats are equal (`true`) or not
+/// (`false`) to the specified number of significant figures, and the second element is the actual
+/// number of matching significant figures.
+fn is_equal_to_sigfigs<C: Compare>(
+    a: &C,
+    b: C,
+    sigfigs: u32,
+    scale: C::Real,
+    max_sigfigs: u32,
+) -> (bool, u32) {
+    let exponent = leading_exponent(scale);
+    let decimal = i32::try_from(sigfigs)
+        .unwrap_or(i32::MAX)
+        .saturating_sub(exponent + 1);
+    let (result, actual_decimal) = a.is_equal_to_decimal(b, decimal);
+    let actual_sigfigs = actual_decimal.saturating_add(exponent + 1).max(0);
+    (
+        result,
+        u32::try_from(actual_sigfigs).map_or(max_sigfigs, |actual| actual.min(max_sigfigs)),
+    )
+}
+
+/// Converts a tolerance or difference to an [`f64`] for reporting.
+///
+/// # Arguments
+///
+/// * `x` - Real floating-point number.
+///
+/// # Returns
+///
+/// `x` as an [`f64`] (`NaN` if it cannot be represented as an [`f64`]).
+#[doc(hidden)]
+pub fn real_to_f64<T: Float>(x: T) -> f64 {
+    x.to_f64().unwrap_or(f64::NAN)
+}
+
+// Implementing Compare trait for all floating-point types that implement the Precision trait
+// (including f32's, f64's, and, with the half feature, f16's and bf16's). Edge cases are kept in
+// separate branches (even when they return the same result) to mirror the special-case tables.
+#[allow(clippy::if_same_then_else)]
+impl<T> Compare for T
+where
+    T: Float + Precision,
+{
+    type Real = Self;
+
+    // Implements the is_equal method.
+    fn is_equal(&self, other: Self) -> bool {
+        // Edge case: NaNs.
+        if self.is_nan() || other.is_nan() {
+            return self.is_nan() && other.is_nan();
+        }
+
+        // Standard case.
+        *self == other
+    }
+
+    // Implements the is_equal_to_decimal method.
+    fn is_equal_to_decimal(&self, other: Self, decimal: i32) -> (bool, i32) {
+        // Edge case: NaNs.
+        if self.is_nan() || other.is_nan() {
+            if self.is_nan() && other.is_nan() {
+                return (true, self.min_10_exp().abs());
+            }
+            return (decimal == -self.max_10_exp(), -self.max_10_exp());
+        }
+
+        // Edge case: Infs.
+        if self.is_infinite() || other.is_infinite() {
+            if (self.is_infinite() && other.is_infinite()) && (*self == other) {
+                return (true, self.min_10_exp().abs());
+            }
+            return (decimal == -self.max_10_exp(), -self.max_10_exp());
+        }
+
+        // Determines if the two numbers are equal to the specified decimal precision.
+        let result = (*self - other).abs() <= decimal_tolerance(decimal);
+
+        // Determines the actual decimal precision between the two numbers.
+        let mut actual_decimal = decimal;
+        let mut new_result = result;
+        if result {
+            while new_result && actual_decimal < self.min_10_exp().abs() {
+                actual_decimal += 1;
+                new_result = (*self - other).abs() <= decimal_tolerance(actual_decimal);
+            }
+            if actual_decimal < self.min_10_exp().abs() {
+                actual_decimal -= 1;
+            }
+        } else {
+            while !new_result && actual_decimal > -self.max_10_exp() {
+                actual_decimal -= 1;
+                new_result = (*self - other).abs() <= decimal_tolerance(actual_decimal);
+            }
+        }
+        (result, actual_decimal)
+    }
+
+    // Implements the is_equal_to_sigfigs method.
+    fn is_equal_to_sigfigs(&self, other: Self, sigfigs: u32) -> (bool, u32) {
+        let max_sigfigs = self.max_decimal() + 2;
+
+        // Edge case: exactly equal (including NaNs and Infs of the same sign).
+        if self.is_equal(other) {
+            return (true, max_sigfigs);
+        }
+
+        // Edge case: NaNs or Infs.
+        if !self.is_finite() || !other.is_finite() {
+            return (sigfigs == 0, 0);
+        }
+
+        // Standard case.
+        is_equal_to_sigfigs(
+            self,
+            other,
+            sigfigs,
+            self.abs().max(other.abs()),
+            max_sigfigs,
+        )
+    }
+
+    // Implements the is_equal_to_atol method.
+    fn is_equal_to_atol(&self, other: Self, atol: Self) -> (bool, Self) {
+        // Edge case: both are NaNs.
+        if self.is_nan() && other.is_nan() {
+            (true, Self::zero())
+        }
+        // Edge case: only one is NaN.
+        else if self.is_nan() || other.is_nan() {
+            (atol.is_nan(), Self::nan())
+        }
+        // Edge case: Infs of same sign.
+        else if self.is_infinite() && other.is_infinite() && self.signum() == other.signum() {
+            (true, Self::zero())
+        }
+        // Standard case.
+        else {
+            let abs_diff = (*self - other).abs();
+            let result = abs_diff <= atol;
+            (result, abs_diff)
+        }
+    }
 
-                // Standard case.
-                *self == other
+    // Implements the is_equal_to_rtol method.
+    fn is_equal_to_rtol(&self, other: Self, rtol: Self) -> (bool, Self) {
+        // Edge case: both are 0.
+        if (*self == Self::zero()) && (other == Self::zero()) {
+            (true, Self::zero())
+        }
+        // Edge case: both are NaNs.
+        else if self.is_nan() && other.is_nan() {
+            (true, Self::zero())
+        }
+        // Edge case: both are Infs.
+        else if self.is_infinite() && other.is_infinite() {
+            if self.signum() == other.signum() {
+                (true, Self::zero())
+            } else {
+                (rtol == Self::one(), Self::one())
             }
+        }
+        // Edge case: only one is NaN.
+        else if self.is_nan() || other.is_nan() {
+            (rtol == Self::one(), Self::one())
+        }
+        // Edge case: only one is Inf.
+        else if self.is_infinite() || other.is_infinite() {
+            (rtol == Self::one(), Self::one())
+        }
+        // Standard case.
+        else {
+            let abs_diff = (*self - other).abs();
+            let max = self.abs().max(other.abs());
+            let result = abs_diff <= rtol * max;
+            (result, abs_diff / max)
+        }
+    }
 
-            // Implements the is_equal_to_decimal method.
-            fn is_equal_to_decimal(&self, other: Self, decimal: i32) -> (bool, i32) {
-                // Edge case: NaNs.
-                if self.is_nan() || other.is_nan() {
-                    if (self.is_nan() && other.is_nan()) {
-                        return (true, self.min_10_exp().abs());
-                    }
-                    return (decimal == -self.max_10_exp(), -self.max_10_exp());
+    // Implements the is_equal_to_ulps method.
+    fn is_equal_to_ulps(&self, other: Self, max_ulps: u64) -> (bool, u64) {
+        // Edge case: both are NaNs.
+        if self.is_nan() && other.is_nan() {
+            (true, 0)
+        }
+        // Edge case: Infs of same sign.
+        else if self.is_infinite() && other.is_infinite() && self.signum() == other.signum() {
+            (true, 0)
+        }
+        // Edge case: only one is NaN, only one is Inf, or Infs of opposite sign.
+        else if self.is_nan() || other.is_nan() || self.is_infinite() || other.is_infinite() {
+            (max_ulps == u64::MAX, u64::MAX)
+        }
+        // Standard case.
+        else {
+            // Maps the bit pattern of a float onto a monotonically increasing integer
+            // scale, with both 0.0 and -0.0 mapping to 0.
+            let to_ordered = |x: Self| -> i128 {
+                let bits = i128::from(x.to_raw_bits());
+                let sign_mask = 1_i128 << (Self::NUM_BITS - 1);
+                if bits & sign_mask == 0 {
+                    bits
+                } else {
+                    -(bits & !sign_mask)
                 }
+            };
+            let ulps = to_ordered(*self).abs_diff(to_ordered(other));
+            let ulps = u64::try_from(ulps).unwrap_or(u64::MAX);
+            (ulps <= max_ulps, ulps)
+        }
+    }
 
-                // Edge case: Infs.
-                if self.is_infinite() || other.is_infinite() {
-                    if (self.is_infinite() && other.is_infinite()) && (*self == other) {
-                        return (true, self.min_10_exp().abs());
-                    }
-                    return (decimal == -self.max_10_exp(), -self.max_10_exp());
-                }
+    // Implements the is_close method.
+    fn is_close(&self, other: Self, rtol: Self, atol: Self) -> (bool, Self, Self) {
+        is_close(*self, other, rtol, atol, false)
+    }
 
-                // Determines if the two numbers are equal to the specified decimal precision.
-                let result = (self - other).abs() <= 1.5 * 10.0.powi(-decimal);
-
-                // Determines the actual decimal precision between the two numbers.
-                let mut actual_decimal = decimal;
-                let mut new_result = result;
-                if result {
-                    while new_result && actual_decimal < self.min_10_exp().abs() {
-                        actual_decimal += 1;
-                        new_result = (self - other).abs() <= 1.5 * 10.0.powi(-actual_decimal);
-                    }
-                    if actual_decimal < self.min_10_exp().abs() {
-                        actual_decimal -= 1;
-                    }
-                } else {
-                    while !new_result && actual_decimal > -self.max_10_exp() {
-                        actual_decimal -= 1;
-                        new_result = (self - other).abs() <= 1.5 * 10.0.powi(-actual_decimal);
-                    }
-                }
-                (result, actual_decimal)
-            }
+    // Implements the is_close_symmetric method.
+    fn is_close_symmetric(&self, other: Self, rtol: Self, atol: Self) -> (bool, Self, Self) {
+        is_close(*self, other, rtol, atol, true)
+    }
 
-            // Implements the is_equal_to_atol method.
-            fn is_equal_to_atol(&self, other: Self, atol: Self) -> (bool, Self) {
-                // Edge case: both are NaNs.
-                if self.is_nan() && other.is_nan() {
-                    (true, 0.0)
-                }
-                // Edge case: only one is NaN.
-                else if self.is_nan() || other.is_nan() {
-                    (atol.is_nan(), Self::NAN)
-                }
-                // Edge case: Infs of same sign.
-                else if self.is_infinite()
-                    && other.is_infinite()
-                    && self.signum() == other.signum()
-                {
-                    (true, 0.0)
-                }
-                // Standard case.
-                else {
-                    let abs_diff = (self - other).abs();
-                    let result = abs_diff <= atol;
-                    (result, abs_diff)
-                }
-            }
+    // Implements the is_less_than method.
+    fn is_less_than(&self, bound: Self, atol: Self) -> (bool, Self) {
+        is_less_than(*self, bound, atol)
+    }
 
-            // Implements the is_equal_to_rtol method.
-            fn is_equal_to_rtol(&self, other: Self, rtol: Self) -> (bool, Self) {
-                // Edge case: both are 0.
-                if (*self == 0.0) && (other == 0.0) {
-                    (true, 0.0)
-                }
-                // Edge case: both are NaNs.
-                else if self.is_nan() && other.is_nan() {
-                    (true, 0.0)
-                }
-                // Edge case: both are Infs.
-                else if self.is_infinite() && other.is_infinite() {
-                    if self.signum() == other.signum() {
-                        (true, 0.0)
-                    } else {
-                        (rtol == 1.0, 1.0)
-                    }
-                }
-                // Edge case: only one is NaN.
-                else if self.is_nan() || other.is_nan() {
-                    (rtol == 1.0, 1.0)
-                }
-                // Edge case: only one is Inf.
-                else if self.is_infinite() || other.is_infinite() {
-                    (rtol == 1.0, 1.0)
-                }
-                // Standard case.
-                else {
-                    let abs_diff = (self - other).abs();
-                    let max = self.abs().max(other.abs());
-                    let result = abs_diff <= rtol * max;
-                    (result, abs_diff / max)
-                }
-            }
+    // Implements the is_greater_than method.
+    fn is_greater_than(&self, bound: Self, atol: Self) -> (bool, Self) {
+        is_less_than(bound, *self, atol)
+    }
+
+    // Implements the is_in_range method.
+    fn is_in_range(&self, lower: Self, upper: Self, atol: Self) -> (bool, Self) {
+        is_in_range(*self, lower, upper, atol)
+    }
+}
+
+/// Determines if two complex numbers have a `NaN` or `Inf` component.
+///
+/// # Arguments
+///
+/// * `a` - The first complex number.
+/// * `b` - The second complex number.
+///
+/// # Returns
+///
+/// `true` if any component of `a` or `b` is `NaN` or `Inf`, `false` otherwise.
+#[cfg(feature = "num-complex")]
+fn has_non_finite_component<T: Float>(
+    a: num_complex::Complex<T>,
+    b: num_complex::Complex<T>,
+) -> bool {
+    !(a.re.is_finite() && a.im.is_finite() && b.re.is_finite() && b.im.is_finite())
+}
+
+// Implementing Compare trait for complex numbers.
+#[cfg(feature = "num-complex")]
+impl<T> Compare for num_complex::Complex<T>
+where
+    T: Compare<Real = T> + Float + Precision,
+{
+    type Real = T;
+
+    // Implements the is_equal method.
+    fn is_equal(&self, other: Self) -> bool {
+        self.re.is_equal(other.re) && self.im.is_equal(other.im)
+    }
+
+    // Implements the is_equal_to_decimal method.
+    fn is_equal_to_decimal(&self, other: Self, decimal: i32) -> (bool, i32) {
+        let (result_re, decimal_re) = self.re.is_equal_to_decimal(other.re, decimal);
+        let (result_im, decimal_im) = self.im.is_equal_to_decimal(other.im, decimal);
+        (result_re && result_im, decimal_re.min(decimal_im))
+    }
+
+    // Implements the is_equal_to_sigfigs method.
+    fn is_equal_to_sigfigs(&self, other: Self, sigfigs: u32) -> (bool, u32) {
+        // Edge case: NaN or Inf components (handled per component).
+        if has_non_finite_component(*self, other) {
+            let (result_re, sigfigs_re) = self.re.is_equal_to_sigfigs(other.re, sigfigs);
+            let (result_im, sigfigs_im) = self.im.is_equal_to_sigfigs(other.im, sigfigs);
+            (result_re && result_im, sigfigs_re.min(sigfigs_im))
         }
-    };
+        // Edge case: exactly equal.
+        else if self.is_equal(other) {
+            (true, self.re.max_decimal() + 2)
+        }
+        // Standard case.
+        else {
+            let scale = self.norm().max(other.norm());
+            is_equal_to_sigfigs(self, other, sigfigs, scale, self.re.max_decimal() + 2)
+        }
+    }
+
+    // Implements the is_equal_to_atol method.
+    fn is_equal_to_atol(&self, other: Self, atol: T) -> (bool, T) {
+        // Edge case: NaN or Inf components (handled per component).
+        if has_non_finite_component(*self, other) {
+            let (result_re, abs_diff_re) = self.re.is_equal_to_atol(other.re, atol);
+            let (result_im, abs_diff_im) = self.im.is_equal_to_atol(other.im, atol);
+            let abs_diff = abs_diff_re.hypot(abs_diff_im);
+            (result_re && result_im && abs_diff <= atol, abs_diff)
+        }
+        // Standard case.
+        else {
+            let abs_diff = (self - other).norm();
+            (abs_diff <= atol, abs_diff)
+        }
+    }
+
+    // Implements the is_equal_to_rtol method.
+    fn is_equal_to_rtol(&self, other: Self, rtol: T) -> (bool, T) {
+        // Edge case: NaN or Inf components (handled per component).
+        if has_non_finite_component(*self, other) {
+            let (result_re, rel_diff_re) = self.re.is_equal_to_rtol(other.re, rtol);
+            let (result_im, rel_diff_im) = self.im.is_equal_to_rtol(other.im, rtol);
+            (result_re && result_im, rel_diff_re.max(rel_diff_im))
+        }
+        // Edge case: both are 0.
+        else if self.norm() == T::zero() && other.norm() == T::zero() {
+            (true, T::zero())
+        }
+        // Standard case.
+        else {
+            let abs_diff = (self - other).norm();
+            let max = self.norm().max(other.norm());
+            (abs_diff <= rtol * max, abs_diff / max)
+        }
+    }
+
+    // Implements the is_equal_to_ulps method.
+    fn is_equal_to_ulps(&self, other: Self, max_ulps: u64) -> (bool, u64) {
+        let (result_re, ulps_re) = self.re.is_equal_to_ulps(other.re, max_ulps);
+        let (result_im, ulps_im) = self.im.is_equal_to_ulps(other.im, max_ulps);
+        (result_re && result_im, ulps_re.max(ulps_im))
+    }
+
+    // Implements the is_close method.
+    fn is_close(&self, other: Self, rtol: T, atol: T) -> (bool, T, T) {
+        is_close_complex(*self, other, rtol, atol, false)
+    }
+
+    // Implements the is_close_symmetric method.
+    fn is_close_symmetric(&self, other: Self, rtol: T, atol: T) -> (bool, T, T) {
+        is_close_complex(*self, other, rtol, atol, true)
+    }
+
+    // Implements the is_less_than method.
+    fn is_less_than(&self, bound: Self, atol: T) -> (bool, T) {
+        let (result_re, excess_re) = is_less_than(self.re, bound.re, atol);
+        let (result_im, excess_im) = is_less_than(self.im, bound.im, atol);
+        let excess = excess_re.hypot(excess_im);
+        (result_re && result_im && excess <= atol, excess)
+    }
+
+    // Implements the is_greater_than method.
+    fn is_greater_than(&self, bound: Self, atol: T) -> (bool, T) {
+        bound.is_less_than(*self, atol)
+    }
+
+    // Implements the is_in_range method.
+    fn is_in_range(&self, lower: Self, upper: Self, atol: T) -> (bool, T) {
+        let (result_re, excess_re) = is_in_range(self.re, lower.re, upper.re, atol);
+        let (result_im, excess_im) = is_in_range(self.im, lower.im, upper.im, atol);
+        let excess = excess_re.hypot(excess_im);
+        (result_re && result_im && excess <= atol, excess)
+    }
+}
+
+/// Determines if a complex number is close to another to within the specified combination of
+/// relative and absolute tolerances.
+///
+/// # Arguments
+///
+/// * `a` - The first complex number to compare.
+/// * `b` - The second complex number to compare against.
+/// * `rtol` - Relative tolerance.
+/// * `atol` - Absolute tolerance.
+/// * `symmetric` - `true` if the relative tolerance should be scaled by $\mathrm{max}(\|a\|,\|b\|)$,
+///   `false` if it should be scaled by $\|b\|$.
+///
+/// # Returns
+///
+/// A tuple where the first element indicates whether the two complex numbers are close (`true`) or
+/// not (`false`), the second element is the absolute difference, and the third element is the
+/// relative difference.
+#[cfg(feature = "num-complex")]
+fn is_close_complex<T: Float>(
+    a: num_complex::Complex<T>,
+    b: num_complex::Complex<T>,
+    rtol: T,
+    atol: T,
+    symmetric: bool,
+) -> (bool, T, T) {
+    // Edge case: NaN or Inf components (handled per component).
+    if has_non_finite_component(a, b) {
+        let (result_re, abs_diff_re, rel_diff_re) = is_close(a.re, b.re, rtol, atol, symmetric);
+        let (result_im, abs_diff_im, rel_diff_im) = is_close(a.im, b.im, rtol, atol, symmetric);
+        (
+            result_re && result_im,
+            abs_diff_re.hypot(abs_diff_im),
+            rel_diff_re.max(rel_diff_im),
+        )
+    }
+    // Standard case.
+    else {
+        let abs_diff = (a - b).norm();
+        let scale = if symmetric {
+            a.norm().max(b.norm())
+        } else {
+            b.norm()
+        };
+        let result = abs_diff <= atol + rtol * scale;
+        let rel_diff = if abs_diff == T::zero() {
+            T::zero()
+        } else {
+            abs_diff / scale
+        };
+        (result, abs_diff, rel_diff)
+    }
 }
-impl_compare!(f32);
-impl_compare!(f64);
 
 #[cfg(test)]
 mod tests {
@@ -416,7 +1305,7 @@ mod tests {
     /// * `exp_abs_diff` - The expected absolute difference.
     fn test_atol<T>(a: T, b: T, atol: T, exp_result: bool, exp_abs_diff: T)
     where
-        T: Compare + Float + std::fmt::Debug,
+        T: Compare<Real = T> + Float + std::fmt::Debug,
     {
         // Run is_equal_to_atol() method.
         let (result, abs_diff) = a.is_equal_to_atol(b, atol);
@@ -447,7 +1336,7 @@ mod tests {
     /// * `exp_rel_diff` - The expected relative difference.
     fn test_rtol<T>(a: T, b: T, rtol: T, exp_result: bool, exp_rel_diff: T)
     where
-        T: Compare + Float + std::fmt::Debug,
+        T: Compare<Real = T> + Float + std::fmt::Debug,
     {
         // Run is_equal_to_rtol() method.
         let (result, rel_diff) = a.is_equal_to_rtol(b, rtol);
@@ -467,6 +1356,82 @@ mod tests {
         }
     }
 
+    /// Function used for testing the `is_equal_to_ulps` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first floating-point number to compare.
+    /// * `b` - The second floating-point number to compare against.
+    /// * `max_ulps` - Maximum allowable distance between the two floats, in ULPs.
+    /// * `exp_result` - The expected boolean result of the comparison.
+    /// * `exp_ulps` - The expected ULP distance.
+    fn test_ulps<T>(a: T, b: T, max_ulps: u64, exp_result: bool, exp_ulps: u64)
+    where
+        T: Compare + Float,
+    {
+        // Run is_equal_to_ulps() method.
+        let (result, ulps) = a.is_equal_to_ulps(b, max_ulps);
+
+        // Check that the expected result was obtained.
+        if exp_result {
+            assert!(result);
+        } else {
+            assert!(!result);
+        }
+
+        // Check that the ULP distance matches the expected value.
+        assert_eq!(ulps, exp_ulps);
+    }
+
+    /// Function used for testing the `is_close` and `is_close_symmetric` methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first floating-point number to compare.
+    /// * `b` - The second floating-point number to compare against.
+    /// * `rtol` - Relative tolerance.
+    /// * `atol` - Absolute tolerance.
+    /// * `symmetric` - `true` to test `is_close_symmetric`, `false` to test `is_close`.
+    /// * `exp_result` - The expected boolean result of the comparison.
+    /// * `exp_abs_diff` - The expected absolute difference.
+    /// * `exp_rel_diff` - The expected relative difference.
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::fn_params_excessive_bools)]
+    fn test_close<T>(
+        a: T,
+        b: T,
+        rtol: T,
+        atol: T,
+        symmetric: bool,
+        exp_result: bool,
+        exp_abs_diff: T,
+        exp_rel_diff: T,
+    ) where
+        T: Compare<Real = T> + Float + std::fmt::Debug,
+    {
+        // Run is_close() or is_close_symmetric() method.
+        let (result, abs_diff, rel_diff) = if symmetric {
+            a.is_close_symmetric(b, rtol, atol)
+        } else {
+            a.is_close(b, rtol, atol)
+        };
+
+        // Check that the expected result was obtained.
+        if exp_result {
+            assert!(result);
+        } else {
+            assert!(!result);
+        }
+
+        // Check that the absolute and relative differences match the expected values.
+        if exp_abs_diff.is_nan() {
+            assert!(abs_diff.is_nan());
+        } else {
+            assert_eq!(abs_diff, exp_abs_diff);
+        }
+        assert_eq!(rel_diff, exp_rel_diff);
+    }
+
     #[test]
     fn is_equal() {
         // f32 equal.
@@ -851,6 +1816,233 @@ mod tests {
         test_rtol(f64::NAN, f64::INFINITY, 1.0, true, 1.0);
     }
 
+    #[test]
+    fn ulps() {
+        // f32 standard cases.
+        test_ulps(1.0_f32, 1.0_f32, 0, true, 0);
+        test_ulps(1.0_f32, 1.0 + f32::EPSILON, 1, true, 1);
+        test_ulps(1.0_f32, 1.0 + 4.0 * f32::EPSILON, 3, false, 4);
+        test_ulps(1.0_f32, 1.0 - f32::EPSILON / 2.0, 1, true, 1);
+        test_ulps(1.0_f32, -1.0_f32, 0, false, 2_130_706_432);
+
+        // f32 signed zeros and subnormals.
+        test_ulps(0.0_f32, -0.0_f32, 0, true, 0);
+        test_ulps(f32::from_bits(1), -f32::from_bits(1), 2, true, 2);
+        test_ulps(f32::from_bits(1), 0.0_f32, 0, false, 1);
+        test_ulps(
+            f32::MIN_POSITIVE,
+            f32::from_bits(f32::MIN_POSITIVE.to_bits() - 1),
+            1,
+            true,
+            1,
+        );
+
+        // f32 infinities and NaNs.
+        test_ulps(f32::INFINITY, f32::INFINITY, 0, true, 0);
+        test_ulps(f32::NEG_INFINITY, f32::NEG_INFINITY, 0, true, 0);
+        test_ulps(f32::INFINITY, f32::NEG_INFINITY, 1000, false, u64::MAX);
+        test_ulps(f32::MAX, f32::INFINITY, 1000, false, u64::MAX);
+        test_ulps(f32::NAN, f32::NAN, 0, true, 0);
+        test_ulps(f32::NAN, -f32::NAN, 0, true, 0);
+        test_ulps(f32::NAN, 0.0, 1000, false, u64::MAX);
+        test_ulps(0.0, f32::NAN, u64::MAX, true, u64::MAX);
+        test_ulps(f32::INFINITY, f32::NAN, 1000, false, u64::MAX);
+
+        // f64 standard cases.
+        test_ulps(1.0_f64, 1.0_f64, 0, true, 0);
+        test_ulps(1.0_f64, 1.0 + f64::EPSILON, 1, true, 1);
+        test_ulps(1.0_f64, 1.0 + 4.0 * f64::EPSILON, 3, false, 4);
+        test_ulps(1.0_f64, 1.0 - f64::EPSILON / 2.0, 1, true, 1);
+        test_ulps(1.0_f64, 0.9999999999999999, 1, true, 1);
+        test_ulps(1.0_f64, -1.0_f64, 0, false, 9_214_364_837_600_034_816);
+
+        // f64 signed zeros and subnormals.
+        test_ulps(0.0_f64, -0.0_f64, 0, true, 0);
+        test_ulps(f64::from_bits(1), -f64::from_bits(1), 2, true, 2);
+        test_ulps(f64::from_bits(1), 0.0_f64, 0, false, 1);
+        test_ulps(
+            f64::MIN_POSITIVE,
+            f64::from_bits(f64::MIN_POSITIVE.to_bits() - 1),
+            1,
+            true,
+            1,
+        );
+
+        // f64 infinities and NaNs.
+        test_ulps(f64::INFINITY, f64::INFINITY, 0, true, 0);
+        test_ulps(f64::NEG_INFINITY, f64::NEG_INFINITY, 0, true, 0);
+        test_ulps(f64::INFINITY, f64::NEG_INFINITY, 1000, false, u64::MAX);
+        test_ulps(f64::MAX, f64::INFINITY, 1000, false, u64::MAX);
+        test_ulps(f64::NAN, f64::NAN, 0, true, 0);
+        test_ulps(f64::NAN, -f64::NAN, 0, true, 0);
+        test_ulps(f64::NAN, 0.0, 1000, false, u64::MAX);
+        test_ulps(0.0, f64::NAN, u64::MAX, true, u64::MAX);
+        test_ulps(f64::INFINITY, f64::NAN, 1000, false, u64::MAX);
+    }
+
+    #[test]
+    fn close_f32() {
+        // f32 near zero (absolute tolerance dominates).
+        test_close(
+            1e-10_f32,
+            0.0,
+            1e-5,
+            1e-8,
+            false,
+            true,
+            1e-10,
+            f32::INFINITY,
+        );
+        test_close(1e-10_f32, 0.0, 1e-5, 1e-8, true, true, 1e-10, 1.0);
+        test_close(1e-7_f32, 0.0, 1e-5, 1e-8, false, false, 1e-7, f32::INFINITY);
+
+        // f32 large magnitude (relative tolerance dominates).
+        test_close(
+            1000.0_f32,
+            1000.01,
+            2e-5,
+            1e-8,
+            false,
+            true,
+            0.010009766,
+            1.0009666e-5,
+        );
+        test_close(
+            1000.0_f32,
+            1000.01,
+            1e-6,
+            1e-8,
+            true,
+            false,
+            0.010009766,
+            1.0009666e-5,
+        );
+
+        // f32 special cases.
+        test_close(0.0_f32, 0.0, 0.0, 0.0, false, true, 0.0, 0.0);
+        test_close(f32::NAN, -f32::NAN, 0.0, 0.0, false, true, 0.0, 0.0);
+        test_close(f32::NAN, 0.0, 1.0, 1.0, true, false, f32::NAN, 1.0);
+        test_close(f32::INFINITY, f32::INFINITY, 0.0, 0.0, true, true, 0.0, 0.0);
+        test_close(
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            1.0,
+            1.0,
+            false,
+            false,
+            f32::INFINITY,
+            1.0,
+        );
+        test_close(
+            f32::INFINITY,
+            f32::NAN,
+            1.0,
+            1.0,
+            false,
+            false,
+            f32::NAN,
+            1.0,
+        );
+        test_close(
+            1.0_f32,
+            f32::INFINITY,
+            1.0,
+            1.0,
+            true,
+            false,
+            f32::INFINITY,
+            1.0,
+        );
+    }
+
+    #[test]
+    fn close_f64() {
+        // f64 near zero (absolute tolerance dominates).
+        test_close(
+            1e-10_f64,
+            0.0,
+            1e-5,
+            1e-8,
+            false,
+            true,
+            1e-10,
+            f64::INFINITY,
+        );
+        test_close(0.0_f64, 1e-10, 1e-5, 1e-8, false, true, 1e-10, 1.0);
+        test_close(1e-10_f64, 0.0, 1e-5, 1e-8, true, true, 1e-10, 1.0);
+        test_close(1e-7_f64, 0.0, 1e-5, 1e-8, false, false, 1e-7, f64::INFINITY);
+
+        // f64 large magnitude (relative tolerance dominates).
+        test_close(
+            1000.0_f64,
+            1000.01,
+            1e-5,
+            1e-8,
+            false,
+            true,
+            0.009999999999990905,
+            9.999900000990895e-6,
+        );
+        test_close(
+            1000.0_f64,
+            1000.01,
+            1e-6,
+            1e-8,
+            true,
+            false,
+            0.009999999999990905,
+            9.999900000990895e-6,
+        );
+
+        // f64 asymmetry.
+        test_close(1.0_f64, 2.0, 0.5, 0.0, false, true, 1.0, 0.5);
+        test_close(2.0_f64, 1.0, 0.5, 0.0, false, false, 1.0, 1.0);
+        test_close(1.0_f64, 2.0, 0.5, 0.0, true, true, 1.0, 0.5);
+        test_close(2.0_f64, 1.0, 0.5, 0.0, true, true, 1.0, 0.5);
+
+        // f64 special cases.
+        test_close(0.0_f64, 0.0, 0.0, 0.0, false, true, 0.0, 0.0);
+        test_close(f64::NAN, -f64::NAN, 0.0, 0.0, false, true, 0.0, 0.0);
+        test_close(f64::NAN, 0.0, 1.0, 1.0, true, false, f64::NAN, 1.0);
+        test_close(f64::INFINITY, f64::INFINITY, 0.0, 0.0, true, true, 0.0, 0.0);
+        test_close(
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            1.0,
+            1.0,
+            false,
+            false,
+            f64::INFINITY,
+            1.0,
+        );
+        test_close(
+            f64::INFINITY,
+            f64::NAN,
+            1.0,
+            1.0,
+            false,
+            false,
+            f64::NAN,
+            1.0,
+        );
+        test_close(
+            1.0_f64,
+            f64::INFINITY,
+            1.0,
+            1.0,
+            true,
+            false,
+            f64::INFINITY,
+            1.0,
+        );
+
+        // With atol = 0, is_close_symmetric only agrees with is_equal_to_rtol for finite floats.
+        assert_eq!(f64::NAN.is_equal_to_rtol(1.0, 1.0), (true, 1.0));
+        assert!(!f64::NAN.is_close_symmetric(1.0, 1.0, 0.0).0);
+        assert_eq!(f64::INFINITY.is_equal_to_rtol(1.0, 1.0), (true, 1.0));
+        assert!(!f64::INFINITY.is_close_symmetric(1.0, 1.0, 0.0).0);
+    }
+
     // https://github.com/numpy/numpy/blob/main/numpy/testing/tests/test_utils.py
     #[test]
     fn numpy() {
@@ -962,4 +2154,327 @@ mod tests {
         test_decimal(8.148766_f64, 8.156124_f64, 3, false, 2);
         test_decimal(6.9613953_f64, 6.9613953_f64, 7, true, 307);
     }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn complex_component_wise() {
+        use num_complex::Complex64;
+
+        // Exact equality (NaNs handled per component).
+        assert!(Complex64::new(1.0, f64::NAN).is_equal(Complex64::new(1.0, f64::NAN)));
+        assert!(!Complex64::new(1.0, f64::NAN).is_equal(Complex64::new(1.0, 2.0)));
+        assert!(!Complex64::new(1.0, 2.0).is_equal(Complex64::new(1.0, 2.1)));
+
+        // Decimal precision (smallest of the two components).
+        assert_eq!(
+            Complex64::new(1.0, 2.0).is_equal_to_decimal(Complex64::new(1.001, 2.1), 1),
+            (true, 1)
+        );
+        assert_eq!(
+            Complex64::new(1.0, 2.0).is_equal_to_decimal(Complex64::new(1.001, 2.1), 2),
+            (false, 1)
+        );
+
+        // ULPs (largest of the two components).
+        assert_eq!(
+            Complex64::new(1.0, 2.0).is_equal_to_ulps(Complex64::new(1.0 + f64::EPSILON, 2.0), 1),
+            (true, 1)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn complex_modulus_based() {
+        use num_complex::{Complex32, Complex64};
+
+        // Absolute tolerance (modulus of the difference).
+        assert_eq!(
+            Complex64::new(1.0, 1.0).is_equal_to_atol(Complex64::new(4.0, 5.0), 5.0),
+            (true, 5.0)
+        );
+        assert_eq!(
+            Complex32::new(1.0, 1.0).is_equal_to_atol(Complex32::new(4.0, 5.0), 4.9),
+            (false, 5.0)
+        );
+
+        // Relative tolerance (scaled by the larger modulus).
+        assert_eq!(
+            Complex64::new(3.0, 4.0).is_equal_to_rtol(Complex64::new(0.0, 0.0), 1.0),
+            (true, 1.0)
+        );
+        assert_eq!(
+            Complex64::new(0.0, 0.0).is_equal_to_rtol(Complex64::new(0.0, 0.0), 0.0),
+            (true, 0.0)
+        );
+        assert_eq!(
+            Complex64::new(6.0, 8.0).is_equal_to_rtol(Complex64::new(3.0, 4.0), 0.4),
+            (false, 0.5)
+        );
+
+        // Closeness (scaled by the modulus of the reference value).
+        assert_eq!(
+            Complex64::new(3.0, 4.0).is_close(Complex64::new(6.0, 8.0), 0.4, 0.0),
+            (false, 5.0, 0.5)
+        );
+        assert_eq!(
+            Complex64::new(3.0, 4.0).is_close(Complex64::new(6.0, 8.0), 0.5, 1.0),
+            (true, 5.0, 0.5)
+        );
+        assert_eq!(
+            Complex64::new(6.0, 8.0).is_close_symmetric(Complex64::new(3.0, 4.0), 0.5, 0.0),
+            (true, 5.0, 0.5)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn complex_non_finite() {
+        use num_complex::Complex64;
+
+        // NaNs in the same component.
+        assert_eq!(
+            Complex64::new(f64::NAN, 3.0).is_equal_to_atol(Complex64::new(f64::NAN, 7.0), 5.0),
+            (true, 4.0)
+        );
+
+        // NaN in only one of the numbers.
+        let (result, abs_diff) =
+            Complex64::new(f64::NAN, 3.0).is_equal_to_atol(Complex64::new(1.0, 3.0), 5.0);
+        assert!(!result);
+        assert!(abs_diff.is_nan());
+
+        // Infs of the same sign in the same component.
+        assert_eq!(
+            Complex64::new(f64::INFINITY, 3.0)
+                .is_equal_to_rtol(Complex64::new(f64::INFINITY, 3.3), 0.1),
+            (true, 0.09090909090909086)
+        );
+
+        // Inf in only one of the numbers.
+        assert_eq!(
+            Complex64::new(f64::INFINITY, 0.0).is_close(Complex64::new(1.0, 0.0), 1.0, 1.0),
+            (false, f64::INFINITY, 1.0)
+        );
+    }
+
+    #[test]
+    fn sigfigs() {
+        // Standard cases.
+        assert_eq!(123.456.is_equal_to_sigfigs(123.4, 4), (true, 4));
+        assert_eq!(123.456.is_equal_to_sigfigs(123.4, 5), (false, 4));
+        assert_eq!(0.001234.is_equal_to_sigfigs(0.001235, 3), (true, 4));
+        assert_eq!(9.99.is_equal_to_sigfigs(10.0, 2), (true, 4));
+        assert_eq!(1000.0.is_equal_to_sigfigs(1001.0, 4), (true, 4));
+        assert_eq!(1000.0.is_equal_to_sigfigs(1002.0, 4), (false, 3));
+        assert_eq!((-5.0e10).is_equal_to_sigfigs(-5.1e10, 1), (true, 2));
+        assert_eq!(1.0.is_equal_to_sigfigs(-1.0, 1), (false, 0));
+        assert_eq!(1.0_f32.is_equal_to_sigfigs(1.000_001, 6), (true, 7));
+
+        // Exactly equal.
+        assert_eq!(1.5.is_equal_to_sigfigs(1.5, 30), (true, 17));
+        assert_eq!(1.5_f32.is_equal_to_sigfigs(1.5, 3), (true, 9));
+        assert_eq!(0.0.is_equal_to_sigfigs(-0.0, 3), (true, 17));
+
+        // Adjacent floats.
+        let (result, actual_sigfigs) = 1.0.is_equal_to_sigfigs(1.0 + f64::EPSILON, 17);
+        assert!(!result);
+        assert_eq!(actual_sigfigs, 16);
+
+        // NaNs and Infs.
+        assert_eq!(f64::NAN.is_equal_to_sigfigs(f64::NAN, 5), (true, 17));
+        assert_eq!(f64::NAN.is_equal_to_sigfigs(1.0, 5), (false, 0));
+        assert_eq!(f64::NAN.is_equal_to_sigfigs(1.0, 0), (true, 0));
+        assert_eq!(
+            f64::INFINITY.is_equal_to_sigfigs(f64::INFINITY, 5),
+            (true, 17)
+        );
+        assert_eq!(f64::INFINITY.is_equal_to_sigfigs(f64::MAX, 1), (false, 0));
+    }
+
+    #[test]
+    fn ordered() {
+        // Standard cases.
+        assert_eq!(1.0.is_less_than(2.0, 0.0), (true, 0.0));
+        assert_eq!(2.0.is_less_than(2.0, 0.0), (true, 0.0));
+        assert_eq!(2.5.is_less_than(2.0, 0.5), (true, 0.5));
+        assert_eq!(2.5.is_less_than(2.0, 0.4), (false, 0.5));
+        assert_eq!(2.0_f32.is_greater_than(1.0, 0.0), (true, 0.0));
+        assert_eq!(0.5_f32.is_greater_than(1.0, 0.25), (false, 0.5));
+        assert_eq!(0.5.is_in_range(0.0, 1.0, 0.0), (true, 0.0));
+        assert_eq!(1.25.is_in_range(0.0, 1.0, 0.5), (true, 0.25));
+        assert_eq!((-2.0).is_in_range(0.0, 1.0, 1.0), (false, 2.0));
+        assert_eq!(3.0.is_in_range(0.0, 1.0, 1.0), (false, 2.0));
+
+        // Infinities.
+        assert_eq!(f64::INFINITY.is_less_than(f64::INFINITY, 0.0), (true, 0.0));
+        assert_eq!(f64::NEG_INFINITY.is_less_than(1.0, 0.0), (true, 0.0));
+        assert_eq!(
+            f64::INFINITY.is_less_than(1.0, 1e300),
+            (false, f64::INFINITY)
+        );
+        assert_eq!(
+            1.0.is_greater_than(f64::INFINITY, 1e300),
+            (false, f64::INFINITY)
+        );
+        assert_eq!(1e-9.is_in_range(f64::NEG_INFINITY, 1e-8, 0.0), (true, 0.0));
+
+        // NaNs.
+        assert_eq!(f64::NAN.is_less_than(f64::NAN, 0.0), (true, 0.0));
+        let (result, excess) = f64::NAN.is_less_than(1.0, 1.0);
+        assert!(!result);
+        assert!(excess.is_nan());
+        let (result, excess) = 0.5.is_in_range(f64::NAN, 1.0, 1.0);
+        assert!(!result);
+        assert!(excess.is_nan());
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn complex_sigfigs() {
+        use num_complex::Complex64;
+
+        // The small imaginary parts only need to match relative to the modulus.
+        assert_eq!(
+            Complex64::new(1000.0, 1e-3).is_equal_to_sigfigs(Complex64::new(1000.0, 2e-3), 7),
+            (true, 7)
+        );
+        assert_eq!(
+            Complex64::new(1.0, 2.0).is_equal_to_sigfigs(Complex64::new(1.0, 2.0), 3),
+            (true, 17)
+        );
+        assert_eq!(
+            Complex64::new(f64::NAN, 2.0).is_equal_to_sigfigs(Complex64::new(f64::NAN, 2.01), 3),
+            (true, 3)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn complex_ordered() {
+        use num_complex::Complex64;
+
+        let lower = Complex64::new(0.0, 0.0);
+        let upper = Complex64::new(1.0, 1.0);
+        assert_eq!(
+            Complex64::new(0.5, 0.5).is_in_range(lower, upper, 0.0),
+            (true, 0.0)
+        );
+        assert_eq!(
+            Complex64::new(4.0, -4.0).is_in_range(lower, upper, 5.0),
+            (true, 5.0)
+        );
+        assert_eq!(
+            Complex64::new(1.0, 4.0).is_less_than(upper, 2.0),
+            (false, 3.0)
+        );
+        assert_eq!(
+            Complex64::new(-3.0, -4.0).is_greater_than(lower, 4.0),
+            (false, 5.0)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn half_precision() {
+        use half::{bf16, f16};
+
+        // f16 (MIN_10_EXP = -4, MAX_10_EXP = 4).
+        assert!(f16::NAN.is_equal(f16::NAN));
+        assert_eq!(
+            f16::from_f32(1.0).is_equal_to_decimal(f16::from_f32(1.01), 1),
+            (true, 2)
+        );
+        assert_eq!(f16::NAN.is_equal_to_decimal(f16::NAN, 2), (true, 4));
+        assert_eq!(f16::INFINITY.is_equal_to_decimal(f16::ONE, -4), (true, -4));
+        assert_eq!(
+            f16::from_f32(2.0).is_equal_to_atol(f16::from_f32(2.5), f16::from_f32(0.5)),
+            (true, f16::from_f32(0.5))
+        );
+        assert_eq!(
+            f16::ZERO.is_equal_to_rtol(f16::ZERO, f16::ZERO),
+            (true, f16::ZERO)
+        );
+        assert_eq!(
+            f16::ONE.is_equal_to_ulps(f16::ONE + f16::EPSILON, 1),
+            (true, 1)
+        );
+        assert_eq!(
+            f16::NEG_ZERO.is_equal_to_ulps(f16::from_bits(1), 1),
+            (true, 1)
+        );
+        assert_eq!(
+            f16::from_f32(1e-3).is_close(f16::ZERO, f16::ZERO, f16::from_f32(1e-2)),
+            (true, f16::from_f32(1e-3), f16::INFINITY)
+        );
+
+        // bf16 (MIN_10_EXP = -37, MAX_10_EXP = 38).
+        assert_eq!(bf16::NAN.is_equal_to_decimal(bf16::ONE, -38), (true, -38));
+        assert_eq!(
+            bf16::from_f32(100.0).is_equal_to_rtol(bf16::from_f32(101.0), bf16::from_f32(0.01)),
+            (true, bf16::from_f32(0.00990099))
+        );
+        assert_eq!(
+            bf16::ONE.is_equal_to_ulps(bf16::ONE + bf16::EPSILON, 0),
+            (false, 1)
+        );
+    }
+
+    // Custom floating-point type (implements num_traits::Float but is not a primitive float).
+    type Ordered = ordered_float::OrderedFloat<f64>;
+
+    impl Precision for Ordered {
+        const MAX_DECIMAL: u32 = f64::MAX_DECIMAL;
+        const NUM_BITS: u32 = f64::NUM_BITS;
+        fn max_decimal(&self) -> u32 {
+            self.0.max_decimal()
+        }
+        fn max_10_exp(&self) -> i32 {
+            self.0.max_10_exp()
+        }
+        fn min_10_exp(&self) -> i32 {
+            self.0.min_10_exp()
+        }
+        fn epsilon(&self) -> Self {
+            ordered_float::OrderedFloat(f64::EPSILON)
+        }
+        fn to_raw_bits(&self) -> u64 {
+            self.0.to_raw_bits()
+        }
+    }
+
+    #[test]
+    fn custom_float_type() {
+        let nan = Ordered::from(f64::NAN);
+        let inf = Ordered::from(f64::INFINITY);
+        let one = Ordered::from(1.0);
+
+        // Same special cases as for primitive floats.
+        assert!(nan.is_equal(nan));
+        assert_eq!(nan.is_equal_to_decimal(one, -308), (true, -308));
+        assert_eq!(
+            inf.is_equal_to_atol(inf, Ordered::from(0.0)),
+            (true, 0.0.into())
+        );
+        assert_eq!(inf.is_equal_to_rtol(-inf, one), (true, one));
+        assert_eq!(
+            one.is_equal_to_ulps(Ordered::from(1.0 + f64::EPSILON), 1),
+            (true, 1)
+        );
+        assert_eq!(
+            Ordered::from(1.0).is_close(Ordered::from(1.1), Ordered::from(0.1), Ordered::from(0.0)),
+            (
+                true,
+                Ordered::from(0.10000000000000009),
+                Ordered::from(0.09090909090909098)
+            )
+        );
+
+        // Works with the assertion macros.
+        crate::assert_equal_to_atol!(one, Ordered::from(1.0001), Ordered::from(1e-3));
+        let arr1 = [one, nan, inf];
+        let arr2 = [Ordered::from(1.0 + f64::EPSILON), nan, inf];
+        crate::assert_arrays_equal_to_ulps!(&arr1, &arr2, 1);
+        let error = crate::check_arrays_equal_to_ulps!(&arr1, &arr2, 0).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![0]);
+    }
 }
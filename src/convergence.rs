@@ -0,0 +1,463 @@
+use crate::compare::real_to_f64;
+use crate::report::{ComparisonError, ComparisonReport, plain_table};
+use num_traits::Float;
+use std::borrow::Borrow;
+
+/// Errors of a numerical method at a sequence of step sizes (e.g. from a mesh refinement or
+/// step-size study), used to estimate the method's observed order of convergence.
+///
+/// If the error behaves like `C * h^p` for small step sizes `h`, then `p` is the order of
+/// convergence. It is estimated from the slope of the least-squares line through the points
+/// `(ln h, ln error)` (see [`ConvergenceStudy::observed_order`]), and from each pair of
+/// consecutive step sizes (see [`ConvergenceStudy::local_orders`]). Errors may be signed, since
+/// only their absolute values are used.
+///
+/// # Example
+///
+/// ```
+/// use numtest::ConvergenceStudy;
+///
+/// // Error of the forward difference approximation of the derivative of exp at 0.
+/// let error = |h: f64| ((h.exp() - 1.0) / h - 1.0).abs();
+/// let study = ConvergenceStudy::from_fn(error, [0.1, 0.05, 0.025]);
+/// assert!((study.observed_order() - 1.0).abs() < 0.05);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvergenceStudy {
+    /// Step sizes.
+    steps: Vec<f64>,
+
+    /// Error at each step size.
+    errors: Vec<f64>,
+}
+
+impl ConvergenceStudy {
+    /// Creates a convergence study from pairs of step sizes and errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - `(step size, error)` pairs (or references to them), e.g. an array, slice, or
+    ///   [`Vec`] of tuples.
+    ///
+    /// # Returns
+    ///
+    /// Convergence study.
+    pub fn new<T, P, I>(pairs: I) -> Self
+    where
+        T: Float,
+        P: Borrow<(T, T)>,
+        I: IntoIterator<Item = P>,
+    {
+        let (steps, errors) = pairs
+            .into_iter()
+            .map(|pair| {
+                let &(step, error) = pair.borrow();
+                (real_to_f64(step), real_to_f64(error))
+            })
+            .unzip();
+        Self { steps, errors }
+    }
+
+    /// Creates a convergence study by evaluating the error of a numerical method at a sequence of
+    /// step sizes.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - Closure returning the error of the numerical method for a given step size.
+    /// * `steps` - Step sizes.
+    ///
+    /// # Returns
+    ///
+    /// Convergence study.
+    pub fn from_fn<T, F, I>(mut error: F, steps: I) -> Self
+    where
+        T: Float,
+        F: FnMut(T) -> T,
+        I: IntoIterator<Item = T>,
+    {
+        let (steps, errors) = steps
+            .into_iter()
+            .map(|step| (real_to_f64(step), real_to_f64(error(step))))
+            .unzip();
+        Self { steps, errors }
+    }
+
+    /// Step sizes of the study.
+    ///
+    /// # Returns
+    ///
+    /// Step sizes.
+    #[must_use]
+    pub fn steps(&self) -> &[f64] {
+        &self.steps
+    }
+
+    /// Errors of the study.
+    ///
+    /// # Returns
+    ///
+    /// Error at each step size.
+    #[must_use]
+    pub fn errors(&self) -> &[f64] {
+        &self.errors
+    }
+
+    /// Local orders of convergence between consecutive step sizes.
+    ///
+    /// The local order between step sizes `h_1` and `h_2` is
+    /// `ln(|error_1 / error_2|) / ln(h_1 / h_2)`.
+    ///
+    /// # Returns
+    ///
+    /// Local order between each pair of consecutive step sizes (one fewer than the number of step
+    /// sizes). Local orders involving a step size that is not positive or an error that is `0` are
+    /// `NaN` or infinite.
+    #[must_use]
+    pub fn local_orders(&self) -> Vec<f64> {
+        self.steps
+            .windows(2)
+            .zip(self.errors.windows(2))
+            .map(|(steps, errors)| (errors[0] / errors[1]).abs().ln() / (steps[0] / steps[1]).ln())
+            .collect()
+    }
+
+    /// Observed order of convergence, estimated by a least-squares fit of `ln(|error|)` against
+    /// `ln(h)`.
+    ///
+    /// # Returns
+    ///
+    /// Slope of the least-squares line (`NaN` if there are fewer than two distinct step sizes, if
+    /// any step size is not positive, or if any error is `0`).
+    #[must_use]
+    pub fn observed_order(&self) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let n = self.steps.len() as f64;
+        let log_steps: Vec<f64> = self.steps.iter().map(|step| step.ln()).collect();
+        let log_errors: Vec<f64> = self.errors.iter().map(|error| error.abs().ln()).collect();
+        let mean_log_step = log_steps.iter().sum::<f64>() / n;
+        let mean_log_error = log_errors.iter().sum::<f64>() / n;
+        let (covariance, variance) = log_steps.iter().zip(&log_errors).fold(
+            (0.0, 0.0),
+            |(covariance, variance), (log_step, log_error)| {
+                let dx = log_step - mean_log_step;
+                (
+                    covariance + dx * (log_error - mean_log_error),
+                    variance + dx * dx,
+                )
+            },
+        );
+        if variance > 0.0 {
+            covariance / variance
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Formats a table printing the step sizes, errors, and local orders of convergence.
+    ///
+    /// # Returns
+    ///
+    /// Table of the study.
+    fn table(&self) -> String {
+        let local_orders = std::iter::once(String::from("-")).chain(
+            self.local_orders()
+                .into_iter()
+                .map(|order| format!("{order:.3}")),
+        );
+        plain_table(&[
+            (
+                "h",
+                self.steps.iter().map(|step| format!("{step:e}")).collect(),
+            ),
+            (
+                "error",
+                self.errors
+                    .iter()
+                    .map(|error| format!("{error:e}"))
+                    .collect(),
+            ),
+            ("local order", local_orders.take(self.steps.len()).collect()),
+        ])
+    }
+}
+
+/// Checks that the observed order of convergence of a study matches an expected order (see
+/// [`check_convergence_order`](crate::check_convergence_order)).
+///
+/// # Arguments
+///
+/// * `study` - Convergence study.
+/// * `expected` - Expected order of convergence.
+/// * `tol` - Absolute tolerance on the order of convergence.
+///
+/// # Returns
+///
+/// Report summarizing the comparison, whose `worst_difference` is the absolute difference between
+/// the observed and expected orders.
+///
+/// # Errors
+///
+/// If any step size is not positive and finite, if any error is `0` or not finite, or if the
+/// observed order of convergence differs from the expected order by more than `tol` (or is `NaN`).
+#[doc(hidden)]
+pub fn __check_convergence_order(
+    study: &ConvergenceStudy,
+    expected: f64,
+    tol: f64,
+) -> Result<ComparisonReport, ComparisonError<f64>> {
+    // The order of convergence is estimated from the logarithms of the step sizes and errors.
+    let valid_step = |step: &f64| step.is_finite() && *step > 0.0;
+    let valid_error = |error: &f64| error.is_finite() && *error != 0.0;
+    if !(study.steps.iter().all(valid_step) && study.errors.iter().all(valid_error)) {
+        let table = study.table();
+        let mut error = ComparisonError::scalar(
+            f64::NAN,
+            expected,
+            f64::NAN,
+            format!(
+                "\nThe order of convergence cannot be estimated, since the step sizes must be \
+                positive and finite, and the errors must be nonzero and finite.\n --> Expected \
+                Order: {expected}\n --> Step Sizes: {}\n\n{table}",
+                study.steps.len()
+            ),
+        );
+        error.table = table.into();
+        return Err(error);
+    }
+
+    let observed = study.observed_order();
+    let difference = (observed - expected).abs();
+    if difference <= tol {
+        return Ok(ComparisonReport {
+            num_elements: study.steps.len(),
+            worst_difference: difference,
+        });
+    }
+    let table = study.table();
+    let mut error = ComparisonError::scalar(
+        observed,
+        expected,
+        difference,
+        format!(
+            "\nThe observed order of convergence does not match the expected order to within a \
+            tolerance of {tol}.\n --> Observed Order: {observed}\n --> Expected Order: \
+            {expected}\n --> Step Sizes: {}\n\n{table}",
+            study.steps.len()
+        ),
+    );
+    error.table = table.into();
+    Err(error)
+}
+
+/// Checks that the observed order of convergence of a numerical method matches an expected order.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_convergence_order`](crate::assert_convergence_order).
+///
+/// # Arguments
+///
+/// * `data` - `(step size, error)` pairs (e.g. an array, slice, or [`Vec`] of tuples), or
+/// * `error, steps = ...` - Closure returning the error for a given step size, followed by the step
+///   sizes at which to evaluate it (e.g. an array or a [`Vec`]).
+/// * `order` - Expected order of convergence (an [`f64`]).
+/// * `tol` - Absolute tolerance on the order of convergence (an [`f64`]).
+///
+/// # Returns
+///
+/// [`ComparisonReport`](crate::ComparisonReport) (whose `worst_difference` is the absolute
+/// difference between the observed and expected orders) if the orders match, otherwise a
+/// [`ComparisonError`](crate::ComparisonError) (whose single mismatch holds the observed order as
+/// `a` and the expected order as `b`).
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let data = [(0.1, 2.1e-3), (0.05, 5.2e-4), (0.025, 1.3e-4)];
+/// assert!(check_convergence_order!(data, 2.0, 0.1).is_ok());
+///
+/// let error = check_convergence_order!(data, 4.0, 0.1).unwrap_err();
+/// assert!((error.mismatches[0].a - 2.0).abs() < 0.1);
+/// ```
+#[macro_export]
+macro_rules! check_convergence_order {
+    ($error:expr, steps = $steps:expr, $order:expr, $tol:expr) => {
+        $crate::__check_convergence_order(
+            &$crate::ConvergenceStudy::from_fn($error, $steps),
+            $order,
+            $tol,
+        )
+    };
+    ($data:expr, $order:expr, $tol:expr) => {
+        $crate::__check_convergence_order(&$crate::ConvergenceStudy::new($data), $order, $tol)
+    };
+}
+
+/// Asserts that the observed order of convergence of a numerical method matches an expected
+/// order.
+///
+/// The observed order is the slope of the least-squares line through the points
+/// `(ln h, ln error)` (see [`ConvergenceStudy`](crate::ConvergenceStudy)), and it must be within
+/// `tol` of the expected order.
+///
+/// # Arguments
+///
+/// * `data` - `(step size, error)` pairs (e.g. an array, slice, or [`Vec`] of tuples), or
+/// * `error, steps = ...` - Closure returning the error for a given step size, followed by the step
+///   sizes at which to evaluate it (e.g. an array or a [`Vec`]).
+/// * `order` - Expected order of convergence (an [`f64`]).
+/// * `tol` - Absolute tolerance on the order of convergence (an [`f64`]).
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// If the observed order of convergence differs from the expected order by more than `tol`. In
+/// this case, the step sizes, errors, and local orders of convergence between consecutive step
+/// sizes are also printed.
+///
+/// # Note
+///
+/// The error only behaves like `C * h^p` in the asymptotic regime, where the step sizes are small
+/// enough for the leading error term to dominate, but large enough for rounding errors to be
+/// negligible. Step sizes outside of this regime (which show up as drifting local orders) should be
+/// left out of the study. Errors may be signed (only their absolute values are used), but studies
+/// with an error that is exactly `0` (or a step size that is not positive) are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// // Explicit Euler method for y' = -y, y(0) = 1, integrated to t = 1.
+/// let euler_error = |h: f64| {
+///     let n = (1.0 / h).round() as usize;
+///     let y = (0..n).fold(1.0, |y, _| y - h * y);
+///     (y - (-1.0_f64).exp()).abs()
+/// };
+///
+/// assert_convergence_order!(euler_error, steps = [0.01, 0.005, 0.0025, 0.00125], 1.0, 0.05);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let data = vec![(0.1, 1e-2), (0.05, 5e-3), (0.025, 2.5e-3)];
+/// assert_convergence_order!(data, 2.0, 0.1);
+/// ```
+#[macro_export]
+macro_rules! assert_convergence_order {
+    ($error:expr, steps = $steps:expr, $order:expr, $tol:expr) => {{
+        if let Err(error) = $crate::check_convergence_order!($error, steps = $steps, $order, $tol)
+        {
+            panic!("{}", error);
+        }
+    }};
+    ($error:expr, steps = $steps:expr, $order:expr, $tol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_convergence_order!($error, steps = $steps, $order, $tol)
+        {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+    ($data:expr, $order:expr, $tol:expr) => {{
+        if let Err(error) = $crate::check_convergence_order!($data, $order, $tol) {
+            panic!("{}", error);
+        }
+    }};
+    ($data:expr, $order:expr, $tol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_convergence_order!($data, $order, $tol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_observed_order() {
+        // error = 3 h^2 exactly.
+        let study = ConvergenceStudy::from_fn(|h: f64| 3.0 * h * h, [0.1, 0.05, 0.025]);
+        assert_eq!(study.steps(), &[0.1, 0.05, 0.025]);
+        assert!((study.observed_order() - 2.0).abs() < 1e-12);
+        for order in study.local_orders() {
+            assert!((order - 2.0).abs() < 1e-12);
+        }
+
+        // From pairs (f32, and by reference).
+        let pairs: Vec<(f32, f32)> = vec![(1.0, 1.0), (0.5, 0.125)];
+        let study = ConvergenceStudy::new(&pairs);
+        assert!((study.observed_order() - 3.0).abs() < 1e-6);
+        assert_eq!(study.local_orders().len(), 1);
+    }
+
+    #[test]
+    fn test_observed_order_degenerate() {
+        assert!(
+            ConvergenceStudy::new([(0.1, 1e-3)])
+                .observed_order()
+                .is_nan()
+        );
+        assert!(
+            ConvergenceStudy::new([(0.1, 1e-3), (0.05, 0.0)])
+                .observed_order()
+                .is_nan()
+        );
+        let error = check_convergence_order!([(0.1, 1e-3)], 1.0, 0.1).unwrap_err();
+        assert!(error.mismatches[0].a.is_nan());
+    }
+
+    #[test]
+    fn test_observed_order_signed_errors() {
+        // error = -3 h^2 exactly (e.g. the signed error of an approximation that overshoots).
+        let study = ConvergenceStudy::from_fn(|h: f64| -3.0 * h * h, [0.1, 0.05, 0.025]);
+        assert!((study.observed_order() - 2.0).abs() < 1e-12);
+        for order in study.local_orders() {
+            assert!((order - 2.0).abs() < 1e-12);
+        }
+        assert_convergence_order!([(0.1, 4e-2), (0.05, -1e-2), (0.025, 2.5e-3)], 2.0, 1e-12);
+    }
+
+    #[test]
+    fn test_convergence_order_zero_error() {
+        let data = [(0.1, 1e-2), (0.05, 0.0), (0.025, 2.5e-3)];
+        let error = check_convergence_order!(data, 1.0, 0.1).unwrap_err();
+        assert!(error.mismatches[0].a.is_nan());
+        assert_eq!(
+            error.message,
+            "\nThe order of convergence cannot be estimated, since the step sizes must be positive \
+            and finite, and the errors must be nonzero and finite.\n --> Expected Order: 1\n --> \
+            Step Sizes: 3\n\n     h   error  local order\n------  ------  -----------\n  1e-1    \
+            1e-2            -\n  5e-2     0e0          inf\n2.5e-2  2.5e-3         -inf\n"
+        );
+        assert!(check_convergence_order!([(-0.1, 1e-2), (0.05, 1e-3)], 1.0, 0.1).is_err());
+    }
+
+    #[test]
+    fn test_convergence_order() {
+        // Trapezoidal rule for the integral of exp over [0, 1].
+        let trapezoidal_error = |h: f64| {
+            #[allow(clippy::cast_possible_truncation)]
+            let n = (1.0 / h).round() as i32;
+            let sum: f64 = (1..n).map(|i| (f64::from(i) * h).exp()).sum();
+            let integral = h * (0.5 + sum + 0.5 * 1.0_f64.exp());
+            (integral - (1.0_f64.exp() - 1.0)).abs()
+        };
+        assert_convergence_order!(trapezoidal_error, steps = [0.1, 0.05, 0.025], 2.0, 0.01);
+        let report =
+            check_convergence_order!(trapezoidal_error, steps = vec![0.2, 0.1], 2.0, 0.01).unwrap();
+        assert_eq!(report.num_elements, 2);
+        assert!(report.worst_difference < 0.01);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "upwind scheme\nThe observed order of convergence does not match the expected order to within a tolerance of 0.1.\n --> Observed Order: 1\n --> Expected Order: 2\n --> Step Sizes: 3\n\n     h   error  local order\n------  ------  -----------\n  1e-1    1e-2            -\n  5e-2    5e-3        1.000\n2.5e-2  2.5e-3        1.000\n"
+    )]
+    fn test_convergence_order_fail() {
+        let data = [(0.1, 1e-2), (0.05, 5e-3), (0.025, 2.5e-3)];
+        assert_convergence_order!(data, 2.0, 0.1, "{} scheme", "upwind");
+    }
+}
@@ -0,0 +1,1470 @@
+use crate::compare::{Compare, real_to_f64};
+use crate::precision::Precision;
+use crate::report::{ComparisonError, ComparisonReport, compare_elements};
+use crate::shape::ShapedElements;
+use num_traits::Float;
+use std::fmt::{Display, LowerExp};
+
+/// Finite-difference scheme used to approximate derivatives.
+///
+/// The step size used to perturb each component `x_i` of the point at which the derivative is
+/// evaluated is `h_i = c * max(|x_i|, 1)`, where the base step `c` depends on the scheme and on
+/// the machine epsilon `ε` of the floating-point type (see [`Precision::epsilon`]). Each base step
+/// balances the truncation error of the scheme against the rounding error of the function
+/// evaluations.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| x[0].sin() * x[1];
+/// let grad = numerical_gradient(f, &[0.5, 2.0], DifferenceScheme::FourthOrder);
+/// assert_arrays_equal_to_rtol!(&grad, &[0.5_f64.cos() * 2.0, 0.5_f64.sin()], 1e-11);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DifferenceScheme {
+    /// Forward differences, `(f(x + h) - f(x)) / h` (first-order accurate, with `c = ε^(1/2)`).
+    Forward,
+
+    /// Central differences, `(f(x + h) - f(x - h)) / (2h)` (second-order accurate, with
+    /// `c = ε^(1/3)`). This is the default.
+    #[default]
+    Central,
+
+    /// Five-point central differences,
+    /// `(f(x - 2h) - 8f(x - h) + 8f(x + h) - f(x + 2h)) / (12h)` (fourth-order accurate, with
+    /// `c = ε^(1/5)`).
+    FourthOrder,
+}
+
+impl Display for DifferenceScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DifferenceScheme::Forward => "forward differences",
+            DifferenceScheme::Central => "central differences",
+            DifferenceScheme::FourthOrder => "fourth-order central differences",
+        })
+    }
+}
+
+/// Converts an `f64` constant into a floating-point type.
+///
+/// # Arguments
+///
+/// * `value` - Constant.
+///
+/// # Returns
+///
+/// Constant converted into the floating-point type.
+fn constant<T: Float>(value: f64) -> T {
+    T::from(value).unwrap_or_else(T::nan)
+}
+
+impl DifferenceScheme {
+    /// Base step `c` of this scheme for a floating-point type.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Any instance of the floating-point type.
+    ///
+    /// # Returns
+    ///
+    /// Base step of this scheme.
+    pub(crate) fn base_step<T: Float + Precision>(self, x: T) -> T {
+        let epsilon = x.epsilon();
+        match self {
+            DifferenceScheme::Forward => epsilon.sqrt(),
+            DifferenceScheme::Central => epsilon.cbrt(),
+            DifferenceScheme::FourthOrder => epsilon.powf(constant(0.2)),
+        }
+    }
+
+    /// Stencil of this scheme.
+    ///
+    /// # Returns
+    ///
+    /// Offsets (in multiples of the step) and weights of the function evaluations, along with the
+    /// multiple of the step that their weighted sum is divided by.
+    fn stencil(self) -> (&'static [(f64, f64)], f64) {
+        match self {
+            DifferenceScheme::Forward => (&[(0.0, -1.0), (1.0, 1.0)], 1.0),
+            DifferenceScheme::Central => (&[(-1.0, -1.0), (1.0, 1.0)], 2.0),
+            DifferenceScheme::FourthOrder => {
+                (&[(-2.0, 1.0), (-1.0, -8.0), (1.0, 8.0), (2.0, -1.0)], 12.0)
+            }
+        }
+    }
+
+    /// Approximates the derivative of a function of one variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function of one variable.
+    /// * `x` - Point at which to approximate the derivative.
+    ///
+    /// # Returns
+    ///
+    /// Approximate derivative.
+    pub(crate) fn derivative<T, F>(self, mut f: F, x: T) -> T
+    where
+        T: Float + Precision,
+        F: FnMut(T) -> T,
+    {
+        self.derivatives(|x| vec![f(x)], x)[0]
+    }
+
+    /// Approximates the derivatives of a vector-valued function of one variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Vector-valued function of one variable.
+    /// * `x` - Point at which to approximate the derivatives.
+    ///
+    /// # Returns
+    ///
+    /// Approximate derivatives of each component of `f`.
+    pub(crate) fn derivatives<T, F>(self, mut f: F, x: T) -> Vec<T>
+    where
+        T: Float + Precision,
+        F: FnMut(T) -> Vec<T>,
+    {
+        let h = step(self.base_step(x), x);
+        let (stencil, divisor) = self.stencil();
+        let mut sum: Vec<T> = Vec::new();
+        for &(offset, weight) in stencil {
+            let values = f(x + constant::<T>(offset) * h);
+            sum.resize(values.len(), T::zero());
+            for (total, value) in sum.iter_mut().zip(values) {
+                *total = *total + constant::<T>(weight) * value;
+            }
+        }
+        let denominator = constant::<T>(divisor) * h;
+        sum.into_iter().map(|total| total / denominator).collect()
+    }
+}
+
+/// Step size used to perturb a variable.
+///
+/// # Arguments
+///
+/// * `base_step` - Base step `c`.
+/// * `x` - Value of the variable.
+///
+/// # Returns
+///
+/// Step size `h = c * max(|x|, 1)`, rounded so that `x + h` is exactly representable.
+fn step<T: Float>(base_step: T, x: T) -> T {
+    (x + base_step * x.abs().max(T::one())) - x
+}
+
+/// Approximates the gradient of a scalar function using finite differences.
+///
+/// # Arguments
+///
+/// * `f` - Scalar function.
+/// * `x0` - Point at which to approximate the gradient.
+/// * `scheme` - Finite-difference scheme (see [`DifferenceScheme`]).
+///
+/// # Returns
+///
+/// Approximate gradient of `f` at `x0`.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| x[0] * x[0] + 3.0 * x[1];
+/// let grad = numerical_gradient(f, &[2.0, -1.0], DifferenceScheme::Central);
+/// assert_arrays_equal_to_rtol!(&grad, &[4.0, 3.0], 1e-9);
+/// ```
+pub fn numerical_gradient<T, F>(f: F, x0: &[T], scheme: DifferenceScheme) -> Vec<T>
+where
+    T: Float + Precision,
+    F: Fn(&[T]) -> T,
+{
+    let mut x = x0.to_vec();
+    (0..x0.len())
+        .map(|i| {
+            scheme.derivative(
+                |xi| {
+                    x[i] = xi;
+                    let value = f(&x);
+                    x[i] = x0[i];
+                    value
+                },
+                x0[i],
+            )
+        })
+        .collect()
+}
+
+/// Approximates the Jacobian of a vector-valued function using finite differences.
+///
+/// # Arguments
+///
+/// * `f` - Vector-valued function.
+/// * `x0` - Point at which to approximate the Jacobian.
+/// * `scheme` - Finite-difference scheme (see [`DifferenceScheme`]).
+///
+/// # Returns
+///
+/// Approximate Jacobian of `f` at `x0`, as a vector of rows (the element in row `i` and column
+/// `j` is the partial derivative of the `i`th component of `f` with respect to `x_j`).
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| vec![x[0] * x[1], x[0] + 2.0 * x[1], x[1].powi(2)];
+/// let jacobian = numerical_jacobian(f, &[3.0, 4.0], DifferenceScheme::Central);
+/// assert_arrays_equal_to_rtol!(&jacobian, &[[4.0, 3.0], [1.0, 2.0], [0.0, 8.0]], 1e-9);
+/// ```
+pub fn numerical_jacobian<T, F>(f: F, x0: &[T], scheme: DifferenceScheme) -> Vec<Vec<T>>
+where
+    T: Float + Precision,
+    F: Fn(&[T]) -> Vec<T>,
+{
+    // Approximate the Jacobian column by column.
+    let mut x = x0.to_vec();
+    let columns: Vec<Vec<T>> = (0..x0.len())
+        .map(|j| {
+            scheme.derivatives(
+                |xj| {
+                    x[j] = xj;
+                    let values = f(&x);
+                    x[j] = x0[j];
+                    values
+                },
+                x0[j],
+            )
+        })
+        .collect();
+
+    // Transpose the columns into rows.
+    let num_rows = columns.first().map_or_else(|| f(x0).len(), Vec::len);
+    (0..num_rows)
+        .map(|i| columns.iter().map(|column| column[i]).collect())
+        .collect()
+}
+
+/// Approximates the Hessian of a scalar function using second-order central differences.
+///
+/// The step size used to perturb each component `x_i` is `h_i = ε^(1/4) * max(|x_i|, 1)`, where
+/// `ε` is the machine epsilon of the floating-point type (see [`Precision::epsilon`]).
+///
+/// # Arguments
+///
+/// * `f` - Scalar function.
+/// * `x0` - Point at which to approximate the Hessian.
+///
+/// # Returns
+///
+/// Approximate Hessian of `f` at `x0`, as a vector of rows.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| x[0].powi(2) * x[1] + x[1].powi(3);
+/// let hessian = numerical_hessian(f, &[1.0, 2.0]);
+/// assert_arrays_equal_to_rtol!(&hessian, &[[4.0, 2.0], [2.0, 12.0]], 1e-6);
+/// ```
+pub fn numerical_hessian<T, F>(f: F, x0: &[T]) -> Vec<Vec<T>>
+where
+    T: Float + Precision,
+    F: Fn(&[T]) -> T,
+{
+    let steps: Vec<T> = x0
+        .iter()
+        .map(|&xi| step(xi.epsilon().sqrt().sqrt(), xi))
+        .collect();
+
+    // Evaluates f at x0 perturbed by the given offsets.
+    let mut x = x0.to_vec();
+    let mut evaluate = |offsets: &[(usize, T)]| {
+        for &(i, offset) in offsets {
+            x[i] = x0[i] + offset;
+        }
+        let value = f(&x);
+        for &(i, _) in offsets {
+            x[i] = x0[i];
+        }
+        value
+    };
+
+    // Approximate the lower triangle of the Hessian, and mirror it into the upper triangle.
+    let f0 = evaluate(&[]);
+    let mut hessian = vec![vec![T::zero(); x0.len()]; x0.len()];
+    for (i, &hi) in steps.iter().enumerate() {
+        hessian[i][i] = (evaluate(&[(i, hi)]) - (f0 + f0) + evaluate(&[(i, -hi)])) / (hi * hi);
+        for (j, &hj) in steps.iter().enumerate().take(i) {
+            let value = (evaluate(&[(i, hi), (j, hj)])
+                - evaluate(&[(i, hi), (j, -hj)])
+                - evaluate(&[(i, -hi), (j, hj)])
+                + evaluate(&[(i, -hi), (j, -hj)]))
+                / (constant::<T>(4.0) * hi * hj);
+            hessian[i][j] = value;
+            hessian[j][i] = value;
+        }
+    }
+    hessian
+}
+
+/// Approximates the gradient of a scalar function using the complex-step method.
+///
+/// The partial derivatives are computed as `Im(f(x + ih e_i)) / h`, which does not suffer from
+/// subtractive cancellation, so the step `h_i = ε * max(|x_i|, 1)` can be tiny and the result is
+/// accurate to machine precision. This requires `f` to be implemented for complex arguments
+/// (and to be analytic, i.e. to not use `abs`, comparisons, or conjugation).
+///
+/// # Arguments
+///
+/// * `f` - Scalar function of complex arguments.
+/// * `x0` - Point at which to approximate the gradient.
+///
+/// # Returns
+///
+/// Approximate gradient of `f` at `x0`.
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex;
+/// use numtest::*;
+///
+/// let f = |x: &[Complex<f64>]| x[0].exp() * x[1];
+/// let grad = complex_step_gradient(f, &[1.0, 2.0]);
+/// assert_arrays_equal_to_rtol!(&grad, &[2.0 * 1.0_f64.exp(), 1.0_f64.exp()], 1e-15);
+/// ```
+#[cfg(feature = "num-complex")]
+pub fn complex_step_gradient<T, F>(f: F, x0: &[T]) -> Vec<T>
+where
+    T: Float + Precision,
+    F: Fn(&[num_complex::Complex<T>]) -> num_complex::Complex<T>,
+{
+    let mut x: Vec<num_complex::Complex<T>> = x0
+        .iter()
+        .map(|&xi| num_complex::Complex::new(xi, T::zero()))
+        .collect();
+    (0..x0.len())
+        .map(|i| {
+            let h = x0[i].epsilon() * x0[i].abs().max(T::one());
+            x[i].im = h;
+            let derivative = f(&x).im / h;
+            x[i].im = T::zero();
+            derivative
+        })
+        .collect()
+}
+
+/// Compares an analytic derivative against its finite-difference approximation.
+///
+/// # Arguments
+///
+/// * `analytic` - Elements of the analytic derivative.
+/// * `numerical` - Elements of the finite-difference approximation.
+/// * `rtol` - Relative tolerance.
+/// * `atol` - Absolute tolerance (a floor for elements that vanish analytically).
+/// * `derivative` - Name of the derivative (e.g. `gradient`).
+/// * `method` - Description of the finite-difference method, including its step sizes.
+///
+/// # Returns
+///
+/// Report summarizing the comparison.
+///
+/// # Errors
+///
+/// If the two derivatives do not have the same shape (when both shapes are known) or the same
+/// number of elements, or if any of the element-wise comparisons fail.
+pub(crate) fn compare_derivatives<T>(
+    analytic: ShapedElements<T>,
+    numerical: ShapedElements<T>,
+    rtol: T,
+    atol: T,
+    derivative: &str,
+    method: &str,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: Compare<Real = T> + Float + LowerExp + Display,
+{
+    // Variable to track the maximum relative difference.
+    let mut max_rel_diff: f64 = 0.0;
+
+    // Compare the two derivatives element-wise.
+    compare_elements(analytic, numerical, |a, b| {
+        let (equal, _, rel_diff) = a.is_close_symmetric(b, rtol, atol);
+        let rel_diff: f64 = real_to_f64(rel_diff);
+        max_rel_diff = max_rel_diff.max(rel_diff);
+        (equal, rel_diff)
+    })
+    .and_then(|comparison| {
+        let tolerance = if atol.is_zero() {
+            format!("a relative tolerance of {rtol}")
+        } else {
+            format!("a relative tolerance of {rtol} and an absolute tolerance of {atol}")
+        };
+        comparison.with_labels("analytic", "numerical").finish(
+            max_rel_diff,
+            |num_mismatched, count| {
+                format!(
+                    "\nThe analytic {derivative} does not match the numerical {derivative} to \
+                    {tolerance}.\n --> Mismatched Elements: {num_mismatched}/{count}\n --> \
+                    Largest Relative Difference: {max_rel_diff}\n --> Method: {method}"
+                )
+            },
+        )
+    })
+}
+
+/// Checks an analytic gradient against a finite-difference approximation (see
+/// [`check_gradient_matches`](crate::check_gradient_matches)).
+///
+/// # Arguments
+///
+/// * `f` - Scalar function.
+/// * `analytic` - Elements of the analytic gradient (see
+///   [`shaped_elements`](crate::shaped_elements)).
+/// * `x0` - Point at which the gradient is evaluated.
+/// * `rtol` - Relative tolerance.
+/// * `atol` - Absolute tolerance.
+/// * `scheme` - Finite-difference scheme.
+///
+/// # Returns
+///
+/// Report summarizing the comparison.
+///
+/// # Errors
+///
+/// If the analytic gradient does not have one element per component of `x0`, or if any of its
+/// elements is not equal to the finite-difference approximation to within `atol` plus `rtol` times
+/// the larger magnitude.
+#[doc(hidden)]
+pub fn check_gradient<T, F>(
+    f: F,
+    analytic: ShapedElements<T>,
+    x0: &[T],
+    rtol: T,
+    atol: T,
+    scheme: DifferenceScheme,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: Float + Precision + LowerExp + Display,
+    F: Fn(&[T]) -> T,
+{
+    let numerical = ShapedElements {
+        layout: None,
+        elements: numerical_gradient(f, x0, scheme),
+        ragged: false,
+    };
+    let method = format!(
+        "{scheme} (h_i = {:e} * max(|x_i|, 1))",
+        scheme.base_step(rtol)
+    );
+    compare_derivatives(analytic, numerical, rtol, atol, "gradient", &method)
+}
+
+/// Checks an analytic gradient against a complex-step approximation (see
+/// [`check_complex_step_gradient_matches`](crate::check_complex_step_gradient_matches)).
+///
+/// # Arguments
+///
+/// * `f` - Scalar function of complex arguments.
+/// * `analytic` - Elements of the analytic gradient (see
+///   [`shaped_elements`](crate::shaped_elements)).
+/// * `x0` - Point at which the gradient is evaluated.
+/// * `rtol` - Relative tolerance.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// Report summarizing the comparison.
+///
+/// # Errors
+///
+/// If the analytic gradient does not have one element per component of `x0`, or if any of its
+/// elements is not equal to the complex-step approximation to within `atol` plus `rtol` times the
+/// larger magnitude.
+#[cfg(feature = "num-complex")]
+#[doc(hidden)]
+pub fn check_complex_step_gradient<T, F>(
+    f: F,
+    analytic: ShapedElements<T>,
+    x0: &[T],
+    rtol: T,
+    atol: T,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: Float + Precision + LowerExp + Display,
+    F: Fn(&[num_complex::Complex<T>]) -> num_complex::Complex<T>,
+{
+    let numerical = ShapedElements {
+        layout: None,
+        elements: complex_step_gradient(f, x0),
+        ragged: false,
+    };
+    let method = format!("complex step (h_i = {:e} * max(|x_i|, 1))", rtol.epsilon());
+    compare_derivatives(analytic, numerical, rtol, atol, "gradient", &method)
+}
+
+/// Checks an analytic Jacobian against a finite-difference approximation (see
+/// [`check_jacobian_matches`](crate::check_jacobian_matches)).
+///
+/// # Arguments
+///
+/// * `f` - Vector-valued function.
+/// * `analytic` - Elements of the analytic Jacobian (see
+///   [`shaped_elements`](crate::shaped_elements)).
+/// * `x0` - Point at which the Jacobian is evaluated.
+/// * `rtol` - Relative tolerance.
+/// * `atol` - Absolute tolerance.
+/// * `scheme` - Finite-difference scheme.
+///
+/// # Returns
+///
+/// Report summarizing the comparison.
+///
+/// # Errors
+///
+/// If the analytic Jacobian does not have the same shape as the numerical Jacobian (when its
+/// shape is known) or the same number of elements, or if any of its elements is not equal to the
+/// finite-difference approximation to within `atol` plus `rtol` times the larger magnitude.
+#[doc(hidden)]
+pub fn check_jacobian<T, F>(
+    f: F,
+    analytic: ShapedElements<T>,
+    x0: &[T],
+    rtol: T,
+    atol: T,
+    scheme: DifferenceScheme,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: Float + Precision + LowerExp + Display,
+    F: Fn(&[T]) -> Vec<T>,
+{
+    let numerical = ShapedElements::from_array_shape(&numerical_jacobian(f, x0, scheme));
+    let method = format!(
+        "{scheme} (h_j = {:e} * max(|x_j|, 1))",
+        scheme.base_step(rtol)
+    );
+    compare_derivatives(analytic, numerical, rtol, atol, "Jacobian", &method)
+}
+
+/// Checks an analytic Hessian against a finite-difference approximation (see
+/// [`check_hessian_matches`](crate::check_hessian_matches)).
+///
+/// # Arguments
+///
+/// * `f` - Scalar function.
+/// * `analytic` - Elements of the analytic Hessian (see
+///   [`shaped_elements`](crate::shaped_elements)).
+/// * `x0` - Point at which the Hessian is evaluated.
+/// * `rtol` - Relative tolerance.
+/// * `atol` - Absolute tolerance.
+///
+/// # Returns
+///
+/// Report summarizing the comparison.
+///
+/// # Errors
+///
+/// If the analytic Hessian is not a square matrix with one row per component of `x0` (when its
+/// shape is known) or does not have the same number of elements, or if any of its elements is not
+/// equal to the finite-difference approximation to within `atol` plus `rtol` times the larger
+/// magnitude.
+#[doc(hidden)]
+pub fn check_hessian<T, F>(
+    f: F,
+    analytic: ShapedElements<T>,
+    x0: &[T],
+    rtol: T,
+    atol: T,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: Float + Precision + LowerExp + Display,
+    F: Fn(&[T]) -> T,
+{
+    let numerical = ShapedElements::from_array_shape(&numerical_hessian(f, x0));
+    let method = format!(
+        "second-order central differences (h_i = {:e} * max(|x_i|, 1))",
+        rtol.epsilon().sqrt().sqrt()
+    );
+    compare_derivatives(analytic, numerical, rtol, atol, "Hessian", &method)
+}
+
+/// Checks an analytic gradient against a finite-difference approximation.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_gradient_matches`](crate::assert_gradient_matches).
+///
+/// # Arguments
+///
+/// * `f` - Scalar function, taking the point as a slice (e.g. `|x: &[f64]| x[0] * x[1]`).
+/// * `grad_f` - Analytic gradient, taking the point as a slice and returning an array-like struct
+///   (e.g. a [`Vec`] or an array).
+/// * `x0` - Point at which to evaluate the gradient (anything implementing `AsRef<[T]>`, e.g. an
+///   array or a [`Vec`]).
+/// * `rtol` - Relative tolerance.
+/// * `atol = ...` - Optional absolute tolerance, which lets elements that vanish analytically pass
+///   (see [`Compare::is_close_symmetric`](crate::Compare::is_close_symmetric)). Defaults to `0`.
+/// * `scheme = ...` - Optional finite-difference scheme (see
+///   [`DifferenceScheme`](crate::DifferenceScheme), central differences by default).
+///
+/// # Returns
+///
+/// [`ComparisonReport`](crate::ComparisonReport) if the gradients match, otherwise a
+/// [`ComparisonError`](crate::ComparisonError) (with the analytic gradient as the first
+/// array-like struct and the numerical gradient as the second).
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| x[0].powi(2) * x[1];
+/// let wrong_grad_f = |x: &[f64]| vec![x[0] * x[1], x[0].powi(2)];
+///
+/// let error = check_gradient_matches!(f, wrong_grad_f, [1.5, 2.0], 1e-6).unwrap_err();
+/// assert_eq!(error.mismatched_indices(), vec![0]);
+/// ```
+#[macro_export]
+macro_rules! check_gradient_matches {
+    (
+        $f:expr,
+        $grad_f:expr,
+        $x0:expr,
+        $rtol:expr,
+        atol = $atol:expr,
+        scheme = $scheme:expr
+    ) => {{
+        let x0 = &$x0;
+        let x0: &[_] = ::std::convert::AsRef::as_ref(x0);
+        let analytic = ($grad_f)(x0);
+        $crate::check_gradient(
+            &$f,
+            $crate::shaped_elements!(analytic),
+            x0,
+            $rtol,
+            $atol,
+            $scheme,
+        )
+    }};
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr) => {
+        $crate::check_gradient_matches!(
+            $f,
+            $grad_f,
+            $x0,
+            $rtol,
+            atol = $atol,
+            scheme = $crate::DifferenceScheme::Central
+        )
+    };
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, scheme = $scheme:expr) => {
+        $crate::check_gradient_matches!(
+            $f,
+            $grad_f,
+            $x0,
+            $rtol,
+            atol = $crate::__num_traits::Zero::zero(),
+            scheme = $scheme
+        )
+    };
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr) => {
+        $crate::check_gradient_matches!(
+            $f,
+            $grad_f,
+            $x0,
+            $rtol,
+            atol = $crate::__num_traits::Zero::zero(),
+            scheme = $crate::DifferenceScheme::Central
+        )
+    };
+}
+
+/// Asserts that an analytic gradient matches a finite-difference approximation.
+///
+/// The gradient of `f` at `x0` is approximated using finite differences (see
+/// [`DifferenceScheme`](crate::DifferenceScheme) for the available schemes and their step sizes),
+/// and each of its components is compared against the analytic gradient using
+/// [`Compare::is_close_symmetric`](crate::Compare::is_close_symmetric) (which reduces to
+/// [`Compare::is_equal_to_rtol`](crate::Compare::is_equal_to_rtol) for finite values unless `atol`
+/// is given).
+///
+/// # Arguments
+///
+/// * `f` - Scalar function, taking the point as a slice (e.g. `|x: &[f64]| x[0] * x[1]`).
+/// * `grad_f` - Analytic gradient, taking the point as a slice and returning an array-like struct
+///   (e.g. a [`Vec`] or an array).
+/// * `x0` - Point at which to evaluate the gradient (anything implementing `AsRef<[T]>`, e.g. an
+///   array or a [`Vec`]).
+/// * `rtol` - Relative tolerance.
+/// * `atol = ...` - Optional absolute tolerance, which lets elements that vanish analytically pass
+///   (see [`Compare::is_close_symmetric`](crate::Compare::is_close_symmetric)). Defaults to `0`.
+/// * `scheme = ...` - Optional finite-difference scheme (see
+///   [`DifferenceScheme`](crate::DifferenceScheme), central differences by default).
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If the analytic gradient does not have one element per component of `x0`. In this case, both
+///   numbers of elements are also printed.
+/// * If any component of the analytic gradient is not equal to the numerical gradient to within
+///   `rtol` (and `atol`). In this case, the analytic and numerical value of every component are
+///   printed, with the mismatched components shown in bolded red, along with the scheme and its step
+///   sizes.
+///
+/// # Note
+///
+/// The truncation and rounding errors of finite differences are typically around `1e-10` (relative
+/// to the magnitude of the function) for central differences in [`f64`], so `rtol` should not be
+/// much smaller than this. Components of the gradient that vanish at `x0` can only be matched to
+/// within this error, which no relative tolerance covers, so pass an `atol` of around this error
+/// (times the magnitude of the function) when the gradient has vanishing components.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| x[0].sin() * x[1].exp();
+/// let grad_f = |x: &[f64]| [x[0].cos() * x[1].exp(), x[0].sin() * x[1].exp()];
+///
+/// assert_gradient_matches!(f, grad_f, [0.3, -0.2], 1e-8);
+/// assert_gradient_matches!(f, grad_f, [0.3, -0.2], 1e-11, scheme = DifferenceScheme::FourthOrder);
+///
+/// // The first component of the gradient vanishes at the origin.
+/// let g = |x: &[f64]| x[0].powi(3) + x[1];
+/// let grad_g = |x: &[f64]| [3.0 * x[0].powi(2), 1.0];
+/// assert_gradient_matches!(g, grad_g, [0.0, 1.0], 1e-8, atol = 1e-8);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| x[0].powi(3);
+/// let wrong_grad_f = |x: &[f64]| vec![2.0 * x[0].powi(2)];
+///
+/// assert_gradient_matches!(f, wrong_grad_f, [2.0], 1e-6);
+/// ```
+#[macro_export]
+macro_rules! assert_gradient_matches {
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr, scheme = $scheme:expr) => {{
+        if let Err(error) = $crate::check_gradient_matches!($f, $grad_f, $x0, $rtol, atol = $atol, scheme = $scheme) {
+            panic!("{}", error);
+        }
+    }};
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr, scheme = $scheme:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_gradient_matches!($f, $grad_f, $x0, $rtol, atol = $atol, scheme = $scheme) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr) => {{
+        if let Err(error) = $crate::check_gradient_matches!($f, $grad_f, $x0, $rtol, atol = $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_gradient_matches!($f, $grad_f, $x0, $rtol, atol = $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, scheme = $scheme:expr) => {{
+        if let Err(error) = $crate::check_gradient_matches!($f, $grad_f, $x0, $rtol, scheme = $scheme) {
+            panic!("{}", error);
+        }
+    }};
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, scheme = $scheme:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_gradient_matches!($f, $grad_f, $x0, $rtol, scheme = $scheme) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr) => {{
+        if let Err(error) = $crate::check_gradient_matches!($f, $grad_f, $x0, $rtol) {
+            panic!("{}", error);
+        }
+    }};
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_gradient_matches!($f, $grad_f, $x0, $rtol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks an analytic gradient against a complex-step approximation.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_complex_step_gradient_matches`](crate::assert_complex_step_gradient_matches).
+///
+/// # Arguments
+///
+/// * `f` - Scalar function of complex arguments, taking the point as a slice (e.g.
+///   `|x: &[Complex<f64>]| x[0] * x[1]`).
+/// * `grad_f` - Analytic gradient, taking the (real) point as a slice and returning an
+///   array-like struct (e.g. a [`Vec`] or an array).
+/// * `x0` - Point at which to evaluate the gradient (anything implementing `AsRef<[T]>`, e.g. an
+///   array or a [`Vec`]).
+/// * `rtol` - Relative tolerance.
+/// * `atol = ...` - Optional absolute tolerance, which lets elements that vanish analytically pass
+///   (see [`Compare::is_close_symmetric`](crate::Compare::is_close_symmetric)). Defaults to `0`.
+///
+/// # Returns
+///
+/// [`ComparisonReport`](crate::ComparisonReport) if the gradients match, otherwise a
+/// [`ComparisonError`](crate::ComparisonError) (with the analytic gradient as the first
+/// array-like struct and the complex-step gradient as the second).
+#[cfg(feature = "num-complex")]
+#[macro_export]
+macro_rules! check_complex_step_gradient_matches {
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr) => {{
+        let x0 = &$x0;
+        let x0: &[_] = ::std::convert::AsRef::as_ref(x0);
+        let analytic = ($grad_f)(x0);
+        $crate::check_complex_step_gradient(
+            &$f,
+            $crate::shaped_elements!(analytic),
+            x0,
+            $rtol,
+            $atol,
+        )
+    }};
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr) => {
+        $crate::check_complex_step_gradient_matches!(
+            $f,
+            $grad_f,
+            $x0,
+            $rtol,
+            atol = $crate::__num_traits::Zero::zero()
+        )
+    };
+}
+
+/// Asserts that an analytic gradient matches a complex-step approximation (requires the
+/// `num-complex` feature).
+///
+/// This macro works like [`assert_gradient_matches`](crate::assert_gradient_matches), but
+/// approximates the gradient using the complex-step method (see
+/// [`complex_step_gradient`](crate::complex_step_gradient)), which is accurate to machine
+/// precision, so `rtol` can be much tighter. The function must therefore accept complex
+/// arguments.
+///
+/// # Arguments
+///
+/// * `f` - Scalar function of complex arguments, taking the point as a slice (e.g.
+///   `|x: &[Complex<f64>]| x[0] * x[1]`).
+/// * `grad_f` - Analytic gradient, taking the (real) point as a slice and returning an
+///   array-like struct (e.g. a [`Vec`] or an array).
+/// * `x0` - Point at which to evaluate the gradient (anything implementing `AsRef<[T]>`, e.g. an
+///   array or a [`Vec`]).
+/// * `rtol` - Relative tolerance.
+/// * `atol = ...` - Optional absolute tolerance, which lets elements that vanish analytically pass
+///   (see [`Compare::is_close_symmetric`](crate::Compare::is_close_symmetric)). Defaults to `0`.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If the analytic gradient does not have one element per component of `x0`. In this case, both
+///   numbers of elements are also printed.
+/// * If any component of the analytic gradient is not equal to the complex-step gradient to within
+///   `rtol` (and `atol`). In this case, the analytic and complex-step value of every component are
+///   printed, with the mismatched components shown in bolded red.
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex;
+/// use numtest::*;
+///
+/// let f = |x: &[Complex<f64>]| x[0].sin() * x[1].exp();
+/// let grad_f = |x: &[f64]| [x[0].cos() * x[1].exp(), x[0].sin() * x[1].exp()];
+///
+/// assert_complex_step_gradient_matches!(f, grad_f, [0.3, -0.2], 1e-14);
+/// ```
+#[cfg(feature = "num-complex")]
+#[macro_export]
+macro_rules! assert_complex_step_gradient_matches {
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr) => {{
+        if let Err(error) = $crate::check_complex_step_gradient_matches!($f, $grad_f, $x0, $rtol, atol = $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_complex_step_gradient_matches!($f, $grad_f, $x0, $rtol, atol = $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr) => {{
+        if let Err(error) = $crate::check_complex_step_gradient_matches!($f, $grad_f, $x0, $rtol) {
+            panic!("{}", error);
+        }
+    }};
+    ($f:expr, $grad_f:expr, $x0:expr, $rtol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_complex_step_gradient_matches!($f, $grad_f, $x0, $rtol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks an analytic Jacobian against a finite-difference approximation.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_jacobian_matches`](crate::assert_jacobian_matches).
+///
+/// # Arguments
+///
+/// * `f` - Vector-valued function, taking the point as a slice and returning a [`Vec`] (e.g.
+///   `|x: &[f64]| vec![x[0] * x[1], x[0] + x[1]]`).
+/// * `jac_f` - Analytic Jacobian, taking the point as a slice and returning a 2D array-like struct
+///   with one row per component of `f` and one column per component of the point (e.g. a nested
+///   [`Vec`] or array, or, with the `ndarray` and `nalgebra` features enabled, an `ndarray` array
+///   or a `nalgebra` matrix).
+/// * `x0` - Point at which to evaluate the Jacobian (anything implementing `AsRef<[T]>`, e.g. an
+///   array or a [`Vec`]).
+/// * `rtol` - Relative tolerance.
+/// * `atol = ...` - Optional absolute tolerance, which lets elements that vanish analytically pass
+///   (see [`Compare::is_close_symmetric`](crate::Compare::is_close_symmetric)). Defaults to `0`.
+/// * `scheme = ...` - Optional finite-difference scheme (see
+///   [`DifferenceScheme`](crate::DifferenceScheme), central differences by default).
+///
+/// # Returns
+///
+/// [`ComparisonReport`](crate::ComparisonReport) if the Jacobians match, otherwise a
+/// [`ComparisonError`](crate::ComparisonError) (with the analytic Jacobian as the first
+/// array-like struct and the numerical Jacobian as the second).
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| vec![x[0] * x[1], x[0].sin()];
+/// let wrong_jac_f = |x: &[f64]| [[x[1], x[0]], [x[0].cos(), 1.0]];
+///
+/// let error = check_jacobian_matches!(f, wrong_jac_f, [1.0, 2.0], 1e-6).unwrap_err();
+/// assert_eq!(error.mismatched_indices(), vec![3]);
+/// ```
+#[macro_export]
+macro_rules! check_jacobian_matches {
+    (
+        $f:expr,
+        $jac_f:expr,
+        $x0:expr,
+        $rtol:expr,
+        atol = $atol:expr,
+        scheme = $scheme:expr
+    ) => {{
+        let x0 = &$x0;
+        let x0: &[_] = ::std::convert::AsRef::as_ref(x0);
+        let analytic = ($jac_f)(x0);
+        $crate::check_jacobian(
+            &$f,
+            $crate::shaped_elements!(analytic),
+            x0,
+            $rtol,
+            $atol,
+            $scheme,
+        )
+    }};
+    ($f:expr, $jac_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr) => {
+        $crate::check_jacobian_matches!(
+            $f,
+            $jac_f,
+            $x0,
+            $rtol,
+            atol = $atol,
+            scheme = $crate::DifferenceScheme::Central
+        )
+    };
+    ($f:expr, $jac_f:expr, $x0:expr, $rtol:expr, scheme = $scheme:expr) => {
+        $crate::check_jacobian_matches!(
+            $f,
+            $jac_f,
+            $x0,
+            $rtol,
+            atol = $crate::__num_traits::Zero::zero(),
+            scheme = $scheme
+        )
+    };
+    ($f:expr, $jac_f:expr, $x0:expr, $rtol:expr) => {
+        $crate::check_jacobian_matches!(
+            $f,
+            $jac_f,
+            $x0,
+            $rtol,
+            atol = $crate::__num_traits::Zero::zero(),
+            scheme = $crate::DifferenceScheme::Central
+        )
+    };
+}
+
+/// Asserts that an analytic Jacobian matches a finite-difference approximation.
+///
+/// The Jacobian of `f` at `x0` is approximated column by column using finite differences (see
+/// [`DifferenceScheme`](crate::DifferenceScheme) for the available schemes and their step sizes),
+/// and each of its elements is compared against the analytic Jacobian using
+/// [`Compare::is_close_symmetric`](crate::Compare::is_close_symmetric) (which reduces to
+/// [`Compare::is_equal_to_rtol`](crate::Compare::is_equal_to_rtol) for finite values unless `atol`
+/// is given). The elements of the analytic Jacobian are collected the same way as by
+/// [`assert_arrays_equal_to_rtol`](crate::assert_arrays_equal_to_rtol), so its shape is also
+/// checked when it is known.
+///
+/// # Arguments
+///
+/// * `f` - Vector-valued function, taking the point as a slice and returning a [`Vec`] (e.g.
+///   `|x: &[f64]| vec![x[0] * x[1], x[0] + x[1]]`).
+/// * `jac_f` - Analytic Jacobian, taking the point as a slice and returning a 2D array-like struct
+///   with one row per component of `f` and one column per component of the point (e.g. a nested
+///   [`Vec`] or array, or, with the `ndarray` and `nalgebra` features enabled, an `ndarray` array
+///   or a `nalgebra` matrix).
+/// * `x0` - Point at which to evaluate the Jacobian (anything implementing `AsRef<[T]>`, e.g. an
+///   array or a [`Vec`]).
+/// * `rtol` - Relative tolerance.
+/// * `atol = ...` - Optional absolute tolerance, which lets elements that vanish analytically pass
+///   (see [`Compare::is_close_symmetric`](crate::Compare::is_close_symmetric)). Defaults to `0`.
+/// * `scheme = ...` - Optional finite-difference scheme (see
+///   [`DifferenceScheme`](crate::DifferenceScheme), central differences by default).
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If the analytic Jacobian does not have one row per component of `f` and one column per
+///   component of `x0`. In this case, both shapes (or numbers of elements) are also printed.
+/// * If any element of the analytic Jacobian is not equal to the numerical Jacobian to within
+///   `rtol` (and `atol`). In this case, the analytic and numerical value of every element are
+///   printed along with its `(row, col)` index, with the mismatched elements shown in bolded red,
+///   along with the scheme and its step sizes.
+///
+/// # Note
+///
+/// Elements of the Jacobian for which a component of `f` does not depend on a component of the
+/// point are approximated as exactly `0`, but elements that only vanish at `x0` can only be
+/// matched to within the error of the finite differences, so they need an `atol` (see
+/// [`assert_gradient_matches`](crate::assert_gradient_matches)).
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| vec![x[0] * x[1].exp(), x[0] - x[1].powi(3)];
+/// let jac_f = |x: &[f64]| {
+///     vec![
+///         vec![x[1].exp(), x[0] * x[1].exp()],
+///         vec![1.0, -3.0 * x[1].powi(2)],
+///     ]
+/// };
+///
+/// assert_jacobian_matches!(f, jac_f, [2.0, 0.5], 1e-8);
+/// assert_jacobian_matches!(f, jac_f, [2.0, 0.5], 1e-11, scheme = DifferenceScheme::FourthOrder);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| vec![x[0] * x[1], x[0].sin()];
+/// let wrong_jac_f = |x: &[f64]| [[x[1], x[0]], [x[0].cos(), 1.0]];
+///
+/// assert_jacobian_matches!(f, wrong_jac_f, [1.0, 2.0], 1e-6);
+/// ```
+#[macro_export]
+macro_rules! assert_jacobian_matches {
+    ($f:expr, $jac_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr, scheme = $scheme:expr) => {{
+        if let Err(error) = $crate::check_jacobian_matches!($f, $jac_f, $x0, $rtol, atol = $atol, scheme = $scheme) {
+            panic!("{}", error);
+        }
+    }};
+    ($f:expr, $jac_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr, scheme = $scheme:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_jacobian_matches!($f, $jac_f, $x0, $rtol, atol = $atol, scheme = $scheme) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+    ($f:expr, $jac_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr) => {{
+        if let Err(error) = $crate::check_jacobian_matches!($f, $jac_f, $x0, $rtol, atol = $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($f:expr, $jac_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_jacobian_matches!($f, $jac_f, $x0, $rtol, atol = $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+    ($f:expr, $jac_f:expr, $x0:expr, $rtol:expr, scheme = $scheme:expr) => {{
+        if let Err(error) = $crate::check_jacobian_matches!($f, $jac_f, $x0, $rtol, scheme = $scheme) {
+            panic!("{}", error);
+        }
+    }};
+    ($f:expr, $jac_f:expr, $x0:expr, $rtol:expr, scheme = $scheme:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_jacobian_matches!($f, $jac_f, $x0, $rtol, scheme = $scheme) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+    ($f:expr, $jac_f:expr, $x0:expr, $rtol:expr) => {{
+        if let Err(error) = $crate::check_jacobian_matches!($f, $jac_f, $x0, $rtol) {
+            panic!("{}", error);
+        }
+    }};
+    ($f:expr, $jac_f:expr, $x0:expr, $rtol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_jacobian_matches!($f, $jac_f, $x0, $rtol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+/// Checks an analytic Hessian against a finite-difference approximation.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_hessian_matches`](crate::assert_hessian_matches).
+///
+/// # Arguments
+///
+/// * `f` - Scalar function, taking the point as a slice (e.g. `|x: &[f64]| x[0] * x[1]`).
+/// * `hess_f` - Analytic Hessian, taking the point as a slice and returning a square 2D
+///   array-like struct (e.g. a nested [`Vec`] or array, or, with the `ndarray` and `nalgebra`
+///   features enabled, an `ndarray` array or a `nalgebra` matrix).
+/// * `x0` - Point at which to evaluate the Hessian (anything implementing `AsRef<[T]>`, e.g. an
+///   array or a [`Vec`]).
+/// * `rtol` - Relative tolerance.
+/// * `atol = ...` - Optional absolute tolerance, which lets elements that vanish analytically pass
+///   (see [`Compare::is_close_symmetric`](crate::Compare::is_close_symmetric)). Defaults to `0`.
+///
+/// # Returns
+///
+/// [`ComparisonReport`](crate::ComparisonReport) if the Hessians match, otherwise a
+/// [`ComparisonError`](crate::ComparisonError) (with the analytic Hessian as the first array-like
+/// struct and the numerical Hessian as the second).
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| x[0].powi(2) * x[1];
+/// let wrong_hess_f = |x: &[f64]| [[2.0 * x[1], x[0]], [x[0], 0.0]];
+///
+/// let error = check_hessian_matches!(f, wrong_hess_f, [1.5, 2.0], 1e-6).unwrap_err();
+/// assert_eq!(error.mismatched_indices(), vec![1, 2]);
+/// ```
+#[macro_export]
+macro_rules! check_hessian_matches {
+    ($f:expr, $hess_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr) => {{
+        let x0 = &$x0;
+        let x0: &[_] = ::std::convert::AsRef::as_ref(x0);
+        let analytic = ($hess_f)(x0);
+        $crate::check_hessian(&$f, $crate::shaped_elements!(analytic), x0, $rtol, $atol)
+    }};
+    ($f:expr, $hess_f:expr, $x0:expr, $rtol:expr) => {
+        $crate::check_hessian_matches!(
+            $f,
+            $hess_f,
+            $x0,
+            $rtol,
+            atol = $crate::__num_traits::Zero::zero()
+        )
+    };
+}
+
+/// Asserts that an analytic Hessian matches a finite-difference approximation.
+///
+/// The Hessian of `f` at `x0` is approximated using second-order central differences (see
+/// [`numerical_hessian`](crate::numerical_hessian)), and each of its elements is compared against
+/// the analytic Hessian using [`Compare::is_close_symmetric`](crate::Compare::is_close_symmetric)
+/// (which reduces to [`Compare::is_equal_to_rtol`](crate::Compare::is_equal_to_rtol) for finite
+/// values unless `atol` is given). The elements of the analytic Hessian are collected the same way
+/// as by [`assert_arrays_equal_to_rtol`](crate::assert_arrays_equal_to_rtol), so its shape is also
+/// checked when it is known.
+///
+/// # Arguments
+///
+/// * `f` - Scalar function, taking the point as a slice (e.g. `|x: &[f64]| x[0] * x[1]`).
+/// * `hess_f` - Analytic Hessian, taking the point as a slice and returning a square 2D
+///   array-like struct (e.g. a nested [`Vec`] or array, or, with the `ndarray` and `nalgebra`
+///   features enabled, an `ndarray` array or a `nalgebra` matrix).
+/// * `x0` - Point at which to evaluate the Hessian (anything implementing `AsRef<[T]>`, e.g. an
+///   array or a [`Vec`]).
+/// * `rtol` - Relative tolerance.
+/// * `atol = ...` - Optional absolute tolerance, which lets elements that vanish analytically pass
+///   (see [`Compare::is_close_symmetric`](crate::Compare::is_close_symmetric)). Defaults to `0`.
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If the analytic Hessian is not a square matrix with one row per component of `x0`. In this
+///   case, both shapes (or numbers of elements) are also printed.
+/// * If any element of the analytic Hessian is not equal to the numerical Hessian to within
+///   `rtol` (and `atol`). In this case, the analytic and numerical value of every element are
+///   printed along with its `(row, col)` index, with the mismatched elements shown in bolded red,
+///   along with the step sizes.
+///
+/// # Note
+///
+/// Second derivatives approximated by finite differences are only accurate to roughly the square
+/// root of the machine epsilon (around `1e-8` relative to the magnitude of the function in
+/// [`f64`]), so `rtol` should not be much smaller than `1e-6`, and elements that vanish at `x0`
+/// need an `atol` of around this accuracy.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| x[0].powi(2) * x[1].sin();
+/// let hess_f = |x: &[f64]| {
+///     [
+///         [2.0 * x[1].sin(), 2.0 * x[0] * x[1].cos()],
+///         [2.0 * x[0] * x[1].cos(), -x[0].powi(2) * x[1].sin()],
+///     ]
+/// };
+///
+/// assert_hessian_matches!(f, hess_f, [1.5, 0.7], 1e-6);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let f = |x: &[f64]| x[0].powi(2) * x[1];
+/// let wrong_hess_f = |x: &[f64]| [[2.0 * x[1], x[0]], [x[0], 0.0]];
+///
+/// assert_hessian_matches!(f, wrong_hess_f, [1.5, 2.0], 1e-6);
+/// ```
+#[macro_export]
+macro_rules! assert_hessian_matches {
+    ($f:expr, $hess_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr) => {{
+        if let Err(error) = $crate::check_hessian_matches!($f, $hess_f, $x0, $rtol, atol = $atol) {
+            panic!("{}", error);
+        }
+    }};
+    ($f:expr, $hess_f:expr, $x0:expr, $rtol:expr, atol = $atol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_hessian_matches!($f, $hess_f, $x0, $rtol, atol = $atol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+    ($f:expr, $hess_f:expr, $x0:expr, $rtol:expr) => {{
+        if let Err(error) = $crate::check_hessian_matches!($f, $hess_f, $x0, $rtol) {
+            panic!("{}", error);
+        }
+    }};
+    ($f:expr, $hess_f:expr, $x0:expr, $rtol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_hessian_matches!($f, $hess_f, $x0, $rtol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_arrays_equal_to_rtol;
+    use crate::{ColorChoice, with_color_choice};
+
+    /// Rosenbrock function.
+    fn rosenbrock(x: &[f64]) -> f64 {
+        (1.0 - x[0]).powi(2) + 100.0 * (x[1] - x[0].powi(2)).powi(2)
+    }
+
+    /// Gradient of the Rosenbrock function.
+    fn rosenbrock_gradient(x: &[f64]) -> Vec<f64> {
+        vec![
+            -2.0 * (1.0 - x[0]) - 400.0 * x[0] * (x[1] - x[0].powi(2)),
+            200.0 * (x[1] - x[0].powi(2)),
+        ]
+    }
+
+    #[test]
+    fn difference_scheme_derivative() {
+        let exact = 1.0_f64.cos();
+        for (scheme, rtol) in [
+            (DifferenceScheme::Forward, 1e-7),
+            (DifferenceScheme::Central, 1e-10),
+            (DifferenceScheme::FourthOrder, 1e-12),
+        ] {
+            let derivative = scheme.derivative(f64::sin, 1.0);
+            assert!(derivative.is_equal_to_rtol(exact, rtol).0, "{scheme}");
+        }
+
+        // f32.
+        let derivative = DifferenceScheme::Central.derivative(f32::exp, 2.0);
+        assert!(derivative.is_equal_to_rtol(2.0_f32.exp(), 1e-4).0);
+    }
+
+    #[test]
+    fn test_gradient_matches() {
+        let x0 = vec![-1.2, 1.0];
+        assert_gradient_matches!(rosenbrock, rosenbrock_gradient, x0, 1e-7);
+        assert_gradient_matches!(
+            rosenbrock,
+            rosenbrock_gradient,
+            x0,
+            1e-10,
+            scheme = DifferenceScheme::FourthOrder
+        );
+        let report = check_gradient_matches!(rosenbrock, rosenbrock_gradient, x0, 1e-7).unwrap();
+        assert_eq!(report.num_elements, 2);
+    }
+
+    #[test]
+    fn test_gradient_matches_wrong_length() {
+        let error = check_gradient_matches!(rosenbrock, |_: &[f64]| [0.0; 3], [1.0, 2.0], 1e-7)
+            .unwrap_err();
+        assert_eq!(
+            *error.kind,
+            crate::ComparisonErrorKind::CountMismatch {
+                count1: 3,
+                count2: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_jacobian_matches() {
+        let f = |x: &[f64]| vec![x[0] * x[1].exp(), x[0] - x[1].powi(3), 2.0 * x[0]];
+        let jac_f = |x: &[f64]| {
+            [
+                [x[1].exp(), x[0] * x[1].exp()],
+                [1.0, -3.0 * x[1].powi(2)],
+                [2.0, 0.0],
+            ]
+        };
+        assert_jacobian_matches!(f, jac_f, [2.0, 0.5], 1e-8);
+        assert_jacobian_matches!(
+            f,
+            jac_f,
+            vec![2.0, 0.5],
+            1e-11,
+            scheme = DifferenceScheme::FourthOrder
+        );
+
+        // Analytic Jacobian with the wrong shape.
+        let error =
+            check_jacobian_matches!(f, |_: &[f64]| [[0.0; 3]; 2], [2.0, 0.5], 1e-8).unwrap_err();
+        assert_eq!(
+            *error.kind,
+            crate::ComparisonErrorKind::ShapeMismatch {
+                shape1: vec![2, 3],
+                shape2: vec![3, 2]
+            }
+        );
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_jacobian_matches_nalgebra() {
+        // Elements of nalgebra matrices are iterated over in column-major order.
+        let f = |x: &[f64]| vec![x[0] * x[1], x[0].sin()];
+        let wrong_jac_f = |x: &[f64]| nalgebra::Matrix2::new(x[1], x[0], x[0].cos(), 1.0);
+        let error = check_jacobian_matches!(f, wrong_jac_f, [1.0, 2.0], 1e-6).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![3]);
+        assert!(error.to_string().contains("(1, 1)"));
+    }
+
+    #[test]
+    fn test_hessian_matches() {
+        let hessian = numerical_hessian(rosenbrock, &[-1.2, 1.0]);
+        assert_arrays_equal_to_rtol!(&hessian, &[[1330.0, 480.0], [480.0, 200.0]], 1e-6);
+        assert_hessian_matches!(
+            rosenbrock,
+            |x: &[f64]| {
+                vec![
+                    vec![2.0 - 400.0 * (x[1] - 3.0 * x[0].powi(2)), -400.0 * x[0]],
+                    vec![-400.0 * x[0], 200.0],
+                ]
+            },
+            [-1.2, 1.0],
+            1e-6
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe analytic Hessian does not match the numerical Hessian to a relative tolerance of 0.000001.\n --> Mismatched Elements: 2/4\n --> Largest Relative Difference: 0.5\n --> Method: second-order central differences (h_i = 1.220703125e-4 * max(|x_i|, 1))\n\n index  analytic  numerical  abs diff  rel diff  result\n------  --------  ---------  --------  --------  ------\n(0, 0)       4e0        4e0         0         0    pass\n\u{1b}[31;1m(0, 1)     1.5e0        3e0     1.5e0      5e-1    FAIL\u{1b}[0m\n\u{1b}[31;1m(1, 0)     1.5e0        3e0     1.5e0      5e-1    FAIL\u{1b}[0m\n(1, 1)       0e0        0e0         0         0    pass\n"
+    )]
+    fn test_hessian_matches_fail() {
+        let f = |x: &[f64]| x[0].powi(2) * x[1];
+        let wrong_hess_f = |x: &[f64]| [[2.0 * x[1], x[0]], [x[0], 0.0]];
+        with_color_choice(ColorChoice::Always, || {
+            assert_hessian_matches!(f, wrong_hess_f, [1.5, 2.0], 1e-6);
+        });
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_complex_step_gradient_matches() {
+        use num_complex::Complex;
+        let f = |x: &[Complex<f64>]| (Complex::new(1.0, 0.0) - x[0]).powi(2) * x[1].sqrt();
+        let grad_f = |x: &[f64]| {
+            [
+                -2.0 * (1.0 - x[0]) * x[1].sqrt(),
+                (1.0 - x[0]).powi(2) / (2.0 * x[1].sqrt()),
+            ]
+        };
+        assert_complex_step_gradient_matches!(f, grad_f, [0.25, 4.0], 1e-15);
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_complex_step_gradient_matches_atol() {
+        use num_complex::Complex;
+        let f = |x: &[Complex<f64>]| x[0].powi(3) + x[1];
+        let grad_f = |x: &[f64]| [3.0 * x[0].powi(2) + 1e-20, 1.0];
+        assert!(check_complex_step_gradient_matches!(f, grad_f, [0.0, 1.0], 1e-15).is_err());
+        assert_complex_step_gradient_matches!(f, grad_f, [0.0, 1.0], 1e-15, atol = 1e-15);
+    }
+
+    #[test]
+    fn test_derivatives_match_atol() {
+        // The first component of the gradient vanishes at the origin, where the finite-difference
+        // error cannot be matched to any relative tolerance.
+        let f = |x: &[f64]| x[0].powi(3) + x[1];
+        let grad_f = |x: &[f64]| [3.0 * x[0].powi(2), 1.0];
+        let error = check_gradient_matches!(f, grad_f, [0.0, 1.0], 1e-6).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![0]);
+        assert_gradient_matches!(f, grad_f, [0.0, 1.0], 1e-6, atol = 1e-8);
+        assert_gradient_matches!(
+            f,
+            grad_f,
+            [0.0, 1.0],
+            1e-6,
+            atol = 1e-8,
+            scheme = DifferenceScheme::Forward,
+            "gradient of {}",
+            "f"
+        );
+
+        let g = |x: &[f64]| vec![x[0].powi(3), x[0] * x[1]];
+        let jac_g = |x: &[f64]| [[3.0 * x[0].powi(2), 0.0], [x[1], x[0]]];
+        assert!(check_jacobian_matches!(g, jac_g, [0.0, 2.0], 1e-6).is_err());
+        assert_jacobian_matches!(g, jac_g, [0.0, 2.0], 1e-6, atol = 1e-8, "jacobian of g");
+
+        let h = |x: &[f64]| x[0].powi(4) + x[1].powi(2);
+        let hess_h = |x: &[f64]| [[12.0 * x[0].powi(2), 0.0], [0.0, 2.0]];
+        assert!(check_hessian_matches!(h, hess_h, [0.0, 1.0], 1e-6).is_err());
+        assert_hessian_matches!(h, hess_h, [0.0, 1.0], 1e-6, atol = 1e-6);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The analytic gradient does not match the numerical gradient to a relative tolerance of 0.000001 and an absolute tolerance of 0.00000001."
+    )]
+    fn test_gradient_matches_atol_fail() {
+        let f = |x: &[f64]| x[0].powi(3) + x[1];
+        let wrong_grad_f = |_: &[f64]| [1.0, 1.0];
+        assert_gradient_matches!(f, wrong_grad_f, [0.0, 1.0], 1e-6, atol = 1e-8);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "gradient of f\nThe analytic gradient does not match the numerical gradient to a relative tolerance of 0.000001.\n --> Mismatched Elements: 1/2\n --> Largest Relative Difference: 0.33333333334148185\n --> Method: central differences (h_i = 6.0554544523933395e-6 * max(|x_i|, 1))\n\nindex  analytic             numerical   abs diff   rel diff  result\n-----  --------  --------------------  ---------  ---------  ------\n\u{1b}[31;1m    0       8e0  1.2000000000146674e1        4e0   3.333e-1    FAIL\u{1b}[0m\n    1       1e0  1.0000000000183342e0  1.833e-11  1.833e-11    pass\n"
+    )]
+    fn test_gradient_matches_fail() {
+        let f = |x: &[f64]| x[0].powi(3) + x[1];
+        let wrong_grad_f = |x: &[f64]| vec![2.0 * x[0].powi(2), 1.0];
+        with_color_choice(ColorChoice::Always, || {
+            assert_gradient_matches!(f, wrong_grad_f, [2.0, 0.5], 1e-6, "gradient of {}", "f");
+        });
+    }
+}
@@ -0,0 +1,643 @@
+use crate::approx::Tolerance;
+use crate::compare::Compare;
+use crate::report::{ComparisonError, ComparisonReport, compare_elements_to_tolerance};
+use crate::shape::{Layout, ShapedElements};
+use std::fmt::{LowerExp, Write};
+use std::path::{Path, PathBuf};
+
+/// Environment variable that makes [`assert_matches_golden`](crate::assert_matches_golden)
+/// overwrite existing golden files (when it is set to anything other than an empty string or `0`).
+pub const GOLDEN_UPDATE_VAR: &str = "NUMTEST_UPDATE";
+
+/// Floating-point type that can be stored losslessly in a golden file (see
+/// [`assert_matches_golden`](crate::assert_matches_golden)).
+///
+/// This trait is implemented for [`f32`] and [`f64`], for `half::f16` and `half::bf16` when the
+/// `half` feature is enabled, and for `num_complex::Complex` numbers when the `num-complex` feature
+/// is enabled.
+///
+/// # Example
+///
+/// ```
+/// use numtest::GoldenValue;
+///
+/// assert_eq!(0.1_f64.to_golden(), "0.1");
+/// assert_eq!(f64::from_golden("1e-300"), Some(1e-300));
+/// ```
+pub trait GoldenValue: Sized {
+    /// Formats this value for storing in a golden file.
+    ///
+    /// # Returns
+    ///
+    /// Text that [`GoldenValue::from_golden`] parses back into exactly this value (without any
+    /// newlines).
+    fn to_golden(&self) -> String;
+
+    /// Parses a value stored in a golden file.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text produced by [`GoldenValue::to_golden`].
+    ///
+    /// # Returns
+    ///
+    /// Parsed value (`None` if the text is not a valid value).
+    fn from_golden(text: &str) -> Option<Self>;
+}
+
+/// Implements the [`GoldenValue`] trait for floating-point types whose [`Debug`](std::fmt::Debug)
+/// implementation prints the shortest decimal representation that round-trips through their
+/// [`FromStr`](std::str::FromStr) implementation.
+macro_rules! impl_golden_value_float {
+    ($($t:ty),*) => {$(
+        impl GoldenValue for $t {
+            fn to_golden(&self) -> String {
+                format!("{self:?}")
+            }
+
+            fn from_golden(text: &str) -> Option<Self> {
+                text.parse().ok()
+            }
+        }
+    )*};
+}
+impl_golden_value_float!(f32, f64);
+#[cfg(feature = "half")]
+impl_golden_value_float!(half::f16, half::bf16);
+
+// Implementing GoldenValue trait for complex numbers (stored as the real and imaginary parts,
+// separated by a space).
+#[cfg(feature = "num-complex")]
+impl<T: GoldenValue> GoldenValue for num_complex::Complex<T> {
+    fn to_golden(&self) -> String {
+        format!("{} {}", self.re.to_golden(), self.im.to_golden())
+    }
+
+    fn from_golden(text: &str) -> Option<Self> {
+        let mut parts = text.split_whitespace();
+        let re = T::from_golden(parts.next()?)?;
+        let im = T::from_golden(parts.next()?)?;
+        parts.next().is_none().then_some(Self { re, im })
+    }
+}
+
+/// Path of a golden file.
+///
+/// # Arguments
+///
+/// * `manifest_dir` - Directory containing the `Cargo.toml` of the crate being tested.
+/// * `name` - Name of the golden file (may contain `/` to group golden files in subdirectories).
+///
+/// # Returns
+///
+/// Path of the golden file (`tests/golden/<name>.golden`, relative to `manifest_dir`).
+fn golden_path(manifest_dir: &str, name: &str) -> PathBuf {
+    Path::new(manifest_dir)
+        .join("tests")
+        .join("golden")
+        .join(format!("{name}.golden"))
+}
+
+/// Formats the elements of an array-like struct as the contents of a golden file.
+///
+/// # Arguments
+///
+/// * `arr` - Elements of the array-like struct.
+///
+/// # Returns
+///
+/// Contents of the golden file: a comment, the shape and memory order of the array-like struct,
+/// and then one element per line (in iteration order).
+fn golden_str<T: GoldenValue>(arr: &ShapedElements<T>) -> String {
+    let (shape, column_major) = match &arr.layout {
+        Some(layout) => (layout.shape.clone(), layout.column_major),
+        None => (vec![arr.elements.len()], false),
+    };
+    let mut contents = format!(
+        "# numtest golden file (rerun the tests with {GOLDEN_UPDATE_VAR}=1 to update it)\n\
+        shape = {shape:?}\norder = {}\n",
+        if column_major {
+            "column-major"
+        } else {
+            "row-major"
+        }
+    );
+    for element in &arr.elements {
+        writeln!(contents, "{}", element.to_golden()).unwrap();
+    }
+    contents
+}
+
+/// Parses the contents of a golden file.
+///
+/// # Arguments
+///
+/// * `contents` - Contents of the golden file (see [`golden_str`]).
+///
+/// # Returns
+///
+/// Elements stored in the golden file, along with their layout.
+///
+/// # Errors
+///
+/// If a line cannot be parsed, or if the number of elements does not match the shape.
+fn parse_golden<T: GoldenValue>(contents: &str) -> Result<ShapedElements<T>, String> {
+    let mut shape: Option<Vec<usize>> = None;
+    let mut column_major = false;
+    let mut elements = Vec::new();
+    for (line_number, line) in contents
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+    {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = || format!("invalid line {line_number}: `{line}`");
+        if let Some(value) = line.strip_prefix("shape =") {
+            let dims = value
+                .trim()
+                .strip_prefix('[')
+                .and_then(|value| value.strip_suffix(']'))
+                .ok_or_else(invalid)?;
+            shape = Some(
+                dims.split(',')
+                    .map(str::trim)
+                    .filter(|dim| !dim.is_empty())
+                    .map(|dim| dim.parse().map_err(|_| invalid()))
+                    .collect::<Result<_, _>>()?,
+            );
+        } else if let Some(value) = line.strip_prefix("order =") {
+            column_major = match value.trim() {
+                "row-major" => false,
+                "column-major" => true,
+                _ => return Err(invalid()),
+            };
+        } else {
+            elements.push(T::from_golden(line).ok_or_else(invalid)?);
+        }
+    }
+
+    // Check that the number of elements matches the shape.
+    let shape = shape.ok_or_else(|| "missing `shape = [...]` line".to_string())?;
+    let count: usize = shape.iter().product();
+    if count != elements.len() {
+        return Err(format!(
+            "shape {shape:?} has {count} elements, but the file stores {} elements",
+            elements.len()
+        ));
+    }
+    Ok(ShapedElements {
+        layout: Some(Layout {
+            shape,
+            column_major,
+        }),
+        elements,
+        ragged: false,
+    })
+}
+
+/// Compares an array-like struct against a golden file, writing the golden file first if it does
+/// not exist (or if `update` is `true`).
+///
+/// # Arguments
+///
+/// * `path` - Path of the golden file.
+/// * `actual` - Elements of the array-like struct.
+/// * `tolerance` - Tolerance.
+/// * `update` - Whether to overwrite the golden file if it already exists.
+///
+/// # Returns
+///
+/// Report summarizing the comparison.
+///
+/// # Errors
+///
+/// If the golden file cannot be read, parsed, or written, if the array-like struct does not have
+/// the same shape or number of elements as the golden file, or if any of the element-wise
+/// comparisons fail.
+fn check_golden<T>(
+    path: &Path,
+    actual: ShapedElements<T>,
+    tolerance: Tolerance,
+    update: bool,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: Compare + Copy + LowerExp + GoldenValue,
+{
+    // Ragged array-like structs have no shape to store in the golden file.
+    if actual.ragged {
+        return Err(ComparisonError::ragged("actual", actual.elements.len()));
+    }
+
+    // Write the golden file (it is then read back below, so that the stored values are checked
+    // to round-trip).
+    if update || !path.exists() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|error| ComparisonError::reference_file(path, error.to_string()))?;
+        }
+        std::fs::write(path, golden_str(&actual))
+            .map_err(|error| ComparisonError::reference_file(path, error.to_string()))?;
+    }
+
+    // Read the golden file.
+    let contents = std::fs::read_to_string(path)
+        .map_err(|error| ComparisonError::reference_file(path, error.to_string()))?;
+    let golden =
+        parse_golden(&contents).map_err(|reason| ComparisonError::reference_file(path, reason))?;
+
+    // Compare the array-like struct against the golden file element-wise.
+    compare_elements_to_tolerance(
+        actual,
+        golden,
+        tolerance,
+        ("actual", "golden"),
+        |num_mismatched, count, worst_line| {
+            format!(
+                "\nThe array-like struct does not match the golden file ({tolerance}).\n --> Golden \
+                File: {}\n --> Mismatched Elements: {num_mismatched}/{count}\n{worst_line}\n --> \
+                Rerun with {GOLDEN_UPDATE_VAR}=1 to update the golden file.",
+                path.display()
+            )
+        },
+    )
+}
+
+/// Compares an array-like struct against a golden file of the crate being tested (see
+/// [`check_matches_golden`](crate::check_matches_golden)).
+///
+/// # Arguments
+///
+/// * `manifest_dir` - Directory containing the `Cargo.toml` of the crate being tested.
+/// * `name` - Name of the golden file.
+/// * `actual` - Elements of the array-like struct (see
+///   [`shaped_elements`](crate::shaped_elements)).
+/// * `tolerance` - Tolerance.
+///
+/// # Returns
+///
+/// Report summarizing the comparison.
+///
+/// # Errors
+///
+/// If the golden file cannot be read, parsed, or written, if the array-like struct does not have
+/// the same shape or number of elements as the golden file, or if any of the element-wise
+/// comparisons fail.
+#[doc(hidden)]
+pub fn check_golden_file<T>(
+    manifest_dir: &str,
+    name: &str,
+    actual: ShapedElements<T>,
+    tolerance: Tolerance,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: Compare + Copy + LowerExp + GoldenValue,
+{
+    let update =
+        std::env::var_os(GOLDEN_UPDATE_VAR).is_some_and(|value| !value.is_empty() && value != "0");
+    check_golden(&golden_path(manifest_dir, name), actual, tolerance, update)
+}
+
+/// Checks that an array-like struct matches a golden (reference) file.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_matches_golden`](crate::assert_matches_golden).
+///
+/// # Arguments
+///
+/// * `name` - Name of the golden file.
+/// * `arr` - Array-like struct to compare. Must either implement the [`Iterator`] trait or have an
+///   `iter` method, and its elements must implement [`GoldenValue`](crate::GoldenValue).
+/// * `kind = tol` - Tolerance, where `kind` is one of `atol`, `rtol`, `decimal`, or `ulps` (see
+///   [`Tolerance`](crate::Tolerance)).
+///
+/// # Returns
+///
+/// [`ComparisonReport`](crate::ComparisonReport) if the array-like struct matches the golden file,
+/// otherwise a [`ComparisonError`](crate::ComparisonError) (with the golden file's elements as the
+/// second array-like struct).
+///
+/// # Example
+///
+/// ```no_run
+/// use numtest::*;
+///
+/// let arr = [1.0, 2.0, 3.0];
+/// let report = check_matches_golden!("three_values", &arr, rtol = 1e-10).unwrap();
+/// assert_eq!(report.num_elements, 3);
+/// ```
+#[macro_export]
+macro_rules! check_matches_golden {
+    ($name:expr, $arr:expr, $kind:ident = $tol:expr) => {
+        $crate::check_golden_file(
+            env!("CARGO_MANIFEST_DIR"),
+            $name,
+            $crate::shaped_elements!($arr),
+            $crate::__tolerance!($kind, $tol),
+        )
+    };
+}
+
+/// Asserts that an array-like struct matches a golden (reference) file.
+///
+/// Golden files are stored in the `tests/golden` directory of the crate being tested (as
+/// `tests/golden/<name>.golden`). If the golden file does not exist yet, it is written from the
+/// array-like struct (so the assertion passes on the first run), and it should then be committed
+/// alongside the tests. If the `NUMTEST_UPDATE` environment variable is set (to anything other
+/// than an empty string or `0`), existing golden files are overwritten instead of compared
+/// against, e.g.
+///
+/// ```text
+/// NUMTEST_UPDATE=1 cargo test
+/// ```
+///
+/// Golden files are plain text, storing the shape and memory order of the array-like struct
+/// followed by one element per line, formatted as the shortest decimal representation that parses
+/// back into exactly the same value (see [`GoldenValue`](crate::GoldenValue)). This makes them
+/// lossless, while keeping changes to them reviewable in version control. Since the memory order is
+/// stored, a golden file written from a column-major array-like struct (e.g. a `nalgebra` matrix)
+/// can be compared against a row-major one (e.g. a nested [`Vec`] or an `ndarray` array) holding
+/// the same values.
+///
+/// # Arguments
+///
+/// * `name` - Name of the golden file (may contain `/` to group golden files in subdirectories).
+/// * `arr` - Array-like struct to compare. Must either implement the [`Iterator`] trait or have an
+///   `iter` method, and its elements must implement [`GoldenValue`](crate::GoldenValue).
+/// * `kind = tol` - Tolerance, where `kind` is one of `atol`, `rtol`, `decimal`, or `ulps` (see
+///   [`Tolerance`](crate::Tolerance)).
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If the golden file cannot be read, parsed, or written. In this case, the path of the golden
+///   file and the problem are also printed.
+/// * If the array-like struct implements [`ArrayShape`](crate::ArrayShape) and does not have the
+///   same shape as the golden file, or if it does not have the same number of elements. In this
+///   case, the shapes or the numbers of elements are also printed.
+/// * If any of the element-wise comparisons fail. In this case, the array-like struct and the
+///   golden file will be printed side by side, with the mismatched elements shown in bolded red.
+///
+/// # Example
+///
+/// ```no_run
+/// use numtest::*;
+///
+/// let trajectory = [[0.0, 1.0], [0.5, 0.8660254037844386], [0.8660254037844386, 0.5]];
+/// assert_matches_golden!("orbit/trajectory", &trajectory, rtol = 1e-10);
+/// ```
+#[macro_export]
+macro_rules! assert_matches_golden {
+    ($name:expr, $arr:expr, $kind:ident = $tol:expr) => {{
+        if let Err(error) = $crate::check_matches_golden!($name, $arr, $kind = $tol) {
+            panic!("{}", error);
+        }
+    }};
+    ($name:expr, $arr:expr, $kind:ident = $tol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_matches_golden!($name, $arr, $kind = $tol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ComparisonErrorKind;
+
+    /// Path of a golden file in a temporary directory that is unique to this test run.
+    fn temp_golden_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("numtest-golden-{}", std::process::id()))
+            .join(format!("{name}.golden"))
+    }
+
+    /// Elements of a 1D array-like struct without a layout.
+    fn flat<T>(elements: Vec<T>) -> ShapedElements<T> {
+        ShapedElements {
+            layout: None,
+            elements,
+            ragged: false,
+        }
+    }
+
+    #[test]
+    fn golden_str_round_trip() {
+        let values = vec![
+            0.1,
+            -1e-300,
+            6.02214076e23,
+            f64::MAX,
+            f64::INFINITY,
+            f64::NAN,
+        ];
+        let contents = golden_str(&flat(values.clone()));
+        assert_eq!(
+            contents,
+            "# numtest golden file (rerun the tests with NUMTEST_UPDATE=1 to update it)\nshape = \
+            [6]\norder = row-major\n0.1\n-1e-300\n6.02214076e23\n1.7976931348623157e308\ninf\nNaN\n"
+        );
+        let parsed: ShapedElements<f64> = parse_golden(&contents).unwrap();
+        assert_eq!(parsed.layout.unwrap().shape, vec![6]);
+        for (value, parsed) in values.iter().zip(&parsed.elements) {
+            assert_eq!(value.to_bits(), parsed.to_bits());
+        }
+
+        // f32 values are stored with their own shortest representation.
+        assert_eq!(0.1_f32.to_golden(), "0.1");
+        assert_eq!(f32::from_golden("0.1"), Some(0.1_f32));
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn golden_value_complex() {
+        let value = num_complex::Complex::new(0.1, -1e-300);
+        assert_eq!(value.to_golden(), "0.1 -1e-300");
+        assert_eq!(
+            num_complex::Complex::from_golden("0.1 -1e-300"),
+            Some(value)
+        );
+        assert_eq!(num_complex::Complex::<f64>::from_golden("0.1"), None);
+        assert_eq!(
+            num_complex::Complex::<f64>::from_golden("0.1 0.2 0.3"),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_golden_layout() {
+        let parsed: ShapedElements<f64> =
+            parse_golden("shape = [2, 1]\norder = column-major\n\n# comment\n1.0\n2.0\n").unwrap();
+        let layout = parsed.layout.unwrap();
+        assert_eq!(layout.shape, vec![2, 1]);
+        assert!(layout.column_major);
+        assert_eq!(parsed.elements, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn parse_golden_errors() {
+        assert_eq!(
+            parse_golden::<f64>("1.0\n").err().unwrap(),
+            "missing `shape = [...]` line"
+        );
+        assert_eq!(
+            parse_golden::<f64>("shape = [2]\n1.0\n1.o\n")
+                .err()
+                .unwrap(),
+            "invalid line 3: `1.o`"
+        );
+        assert_eq!(
+            parse_golden::<f64>("shape = [2, 2]\n1.0\n").err().unwrap(),
+            "shape [2, 2] has 4 elements, but the file stores 1 elements"
+        );
+    }
+
+    #[test]
+    fn check_golden_write_and_compare() {
+        let path = temp_golden_path("write_and_compare");
+        let _ = std::fs::remove_file(&path);
+
+        // The first run writes the golden file.
+        let tolerance = Tolerance::Rtol(1e-10);
+        let report = check_golden(&path, flat(vec![1.0, 2.0]), tolerance, false).unwrap();
+        assert_eq!(report.num_elements, 2);
+        assert!(
+            std::fs::read_to_string(&path)
+                .unwrap()
+                .ends_with("1.0\n2.0\n")
+        );
+
+        // Later runs compare against it.
+        assert!(check_golden(&path, flat(vec![1.0, 2.0 + 1e-12]), tolerance, false).is_ok());
+        let error = check_golden(&path, flat(vec![1.0, 2.1]), tolerance, false).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![1]);
+        assert!(error.message.contains(" --> Mismatched Elements: 1/2\n"));
+        assert!(error.message.contains("Rerun with NUMTEST_UPDATE=1"));
+        assert!(error.table.contains("actual"));
+        assert!(error.table.contains("golden"));
+
+        // Updating overwrites the golden file.
+        assert!(check_golden(&path, flat(vec![1.0, 2.1]), tolerance, true).is_ok());
+        assert!(check_golden(&path, flat(vec![1.0, 2.1]), tolerance, false).is_ok());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn check_golden_shape_mismatch() {
+        let path = temp_golden_path("shape_mismatch");
+        let _ = std::fs::remove_file(&path);
+        let tolerance = Tolerance::Ulps(0);
+        check_golden(
+            &path,
+            crate::shaped_elements!([[1.0, 2.0], [3.0, 4.0]]),
+            tolerance,
+            false,
+        )
+        .unwrap();
+        let error = check_golden(
+            &path,
+            crate::shaped_elements!([[1.0, 2.0, 3.0, 4.0]]),
+            tolerance,
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            *error.kind,
+            ComparisonErrorKind::ShapeMismatch {
+                shape1: vec![1, 4],
+                shape2: vec![2, 2]
+            }
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn check_golden_mixed_layouts() {
+        let path = temp_golden_path("mixed_layouts");
+        let _ = std::fs::remove_file(&path);
+        let tolerance = Tolerance::Ulps(0);
+
+        // Write the golden file from a column-major matrix.
+        let column_major = ShapedElements {
+            layout: Some(Layout {
+                shape: vec![2, 3],
+                column_major: true,
+            }),
+            elements: vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0],
+            ragged: false,
+        };
+        check_golden(&path, column_major, tolerance, false).unwrap();
+        assert!(
+            std::fs::read_to_string(&path)
+                .unwrap()
+                .contains("order = column-major\n")
+        );
+
+        // Compare a row-major matrix with the same values against it.
+        let row_major = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        assert!(check_golden(&path, crate::shaped_elements!(row_major), tolerance, false).is_ok());
+        let row_major = [[1.0, 2.0, 3.0], [4.0, 5.5, 6.0]];
+        let error =
+            check_golden(&path, crate::shaped_elements!(row_major), tolerance, false).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![4]);
+        assert_eq!(error.mismatches[0].multi_index, Some(vec![1, 1]));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn check_golden_ragged() {
+        let path = temp_golden_path("ragged");
+        let _ = std::fs::remove_file(&path);
+        let ragged = vec![vec![1.0, 2.0], vec![3.0], vec![4.0, 5.0, 6.0]];
+        let error = check_golden(
+            &path,
+            crate::shaped_elements!(&ragged),
+            Tolerance::Ulps(0),
+            false,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            *error.kind,
+            ComparisonErrorKind::InvalidShape { .. }
+        ));
+        assert!(!path.exists());
+    }
+
+    #[cfg(all(feature = "nalgebra", feature = "ndarray"))]
+    #[test]
+    fn check_golden_nalgebra_ndarray() {
+        let path = temp_golden_path("nalgebra_ndarray");
+        let _ = std::fs::remove_file(&path);
+        let tolerance = Tolerance::Ulps(0);
+        let mat = nalgebra::Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        let arr = ndarray::arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        check_golden(&path, crate::shaped_elements!(mat), tolerance, false).unwrap();
+        assert!(check_golden(&path, crate::shaped_elements!(arr), tolerance, false).is_ok());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn check_golden_invalid_file() {
+        let path = temp_golden_path("invalid_file");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "shape = [1]\nnot a number\n").unwrap();
+        let error = check_golden(&path, flat(vec![1.0]), Tolerance::Atol(0.0), false).unwrap_err();
+        assert_eq!(
+            *error.kind,
+            ComparisonErrorKind::ReferenceFile {
+                path: path.clone(),
+                reason: "invalid line 2: `not a number`".to_string()
+            }
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_matches_golden() {
+        let mat = [[1.0, 0.1], [-2.5e-8, 1.2345678901234567]];
+        assert_matches_golden!("matrix", &mat, rtol = 1e-12);
+        assert_matches_golden!("matrix", &mat, ulps = 0, "matrix changed");
+    }
+}
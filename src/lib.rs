@@ -8,28 +8,99 @@
 //!
 //! # Summary
 //!
-//! This crate provides four main sets of utilities:
+//! This crate provides ten main sets of utilities:
 //!
 //! 1. Macros for asserting equality between floating-point ([`f32`] and [`f64`]) numbers:
 //!
 //!     * [`assert_equal`]
 //!     * [`assert_equal_to_decimal`]
+//!     * [`assert_equal_to_sigfigs`]
 //!     * [`assert_equal_to_atol`]
 //!     * [`assert_equal_to_rtol`]
+//!     * [`assert_equal_to_ulps`]
+//!     * [`assert_close`]
+//!     * [`assert_less_than`], [`assert_greater_than`], and [`assert_in_range`] (one-sided and
+//!       interval bounds to within an absolute tolerance)
 //!
 //! 1. Macros for asserting equality between array-like structs of floats (the structs just need to
 //!    either implement the [`Iterator`] trait or have an `iter` method):
 //!
 //!     * [`assert_arrays_equal`]
 //!     * [`assert_arrays_equal_to_decimal`]
+//!     * [`assert_arrays_equal_to_sigfigs`]
 //!     * [`assert_arrays_equal_to_atol`]
 //!     * [`assert_arrays_equal_to_rtol`]
+//!     * [`assert_arrays_equal_to_ulps`]
+//!     * [`assert_arrays_close`]
+//!     * [`assert_arrays_equal_to_atols`] and [`assert_arrays_equal_to_rtols`] (separate tolerance
+//!       for each pair of elements, see [`Tolerances`])
+//!     * [`assert_arrays_equal_in_norm`] and [`assert_arrays_equal_in_rel_norm`] (tolerance on the
+//!       [`Norm`] of the difference)
+//!     * [`assert_arrays_less_than`], [`assert_arrays_greater_than`], and [`assert_arrays_in_range`]
+//!       (element-wise one-sided and interval bounds)
 //!
-//! 1. The [`Compare`] trait[^compare_note] (implemented for [`f32`] and [`f64`] types) for
-//!    performing comparisons between floating-point numbers.
-//! 1. The [`Precision`] trait[^precision_note] (implemented for [`f32`] and [`f64`] types)
-//!    providing methods for accessing information regarding the precision of an _instance_ of a
-//!    floating-point type.
+//! 1. Non-panicking counterparts of the assertion macros, which return a [`ComparisonReport`] on
+//!    success and a [`ComparisonError`] (carrying the mismatched indices, values, and per-element
+//!    differences) on failure:
+//!
+//!     * [`check_equal`], [`check_equal_to_decimal`], [`check_equal_to_sigfigs`],
+//!       [`check_equal_to_atol`], [`check_equal_to_rtol`], [`check_equal_to_ulps`],
+//!       [`check_close`], [`check_less_than`], [`check_greater_than`], [`check_in_range`]
+//!     * [`check_arrays_equal`], [`check_arrays_equal_to_decimal`],
+//!       [`check_arrays_equal_to_sigfigs`], [`check_arrays_equal_to_atol`],
+//!       [`check_arrays_equal_to_rtol`], [`check_arrays_equal_to_ulps`], [`check_arrays_close`],
+//!       [`check_arrays_equal_to_atols`], [`check_arrays_equal_to_rtols`],
+//!       [`check_arrays_equal_in_norm`], [`check_arrays_equal_in_rel_norm`],
+//!       [`check_arrays_less_than`], [`check_arrays_greater_than`], [`check_arrays_in_range`]
+//!
+//! 1. The [`SoftAssert`] collector, which records labelled failures from many comparisons and
+//!    panics once with a consolidated report.
+//!
+//! 1. The [`ApproxCompare`] trait (derivable for user structs when the `derive` feature is
+//!    enabled), along with the [`assert_approx_eq`] and [`check_approx_eq`] macros, which compare
+//!    structs field by field (recursing into arrays, vectors, options, and nested structs) and
+//!    name the path of every mismatched value.
+//!
+//! 1. Utilities for comparing against reference data stored in files:
+//!
+//!     * [`assert_matches_golden`] and [`check_matches_golden`], which compare an array-like
+//!       struct against a golden (reference) file stored losslessly as plain text, writing the
+//!       file on the first run (or when the `NUMTEST_UPDATE` environment variable is set)
+//!     * [`read_table`], [`assert_column_matches`], and [`check_column_matches`], which load CSV
+//!       and whitespace-delimited tables (e.g. exported from MATLAB or spreadsheets) and compare
+//!       array-like structs against their columns
+//!
+//! 1. Macros for verifying the building blocks of numerical algorithms:
+//!
+//!     * [`assert_gradient_matches`] and [`check_gradient_matches`], which compare an analytic
+//!       gradient against a finite-difference approximation (see [`DifferenceScheme`]), or, with
+//!       `assert_complex_step_gradient_matches` and `check_complex_step_gradient_matches`, a
+//!       complex-step approximation when the `num-complex` feature is enabled
+//!     * [`assert_jacobian_matches`] and [`check_jacobian_matches`], and
+//!       [`assert_hessian_matches`] and [`check_hessian_matches`], which compare analytic
+//!       Jacobians and Hessians (nested vectors or arrays, `ndarray` arrays, or `nalgebra`
+//!       matrices) against finite-difference approximations, reporting mismatches by their
+//!       `(row, col)` index
+//!     * [`assert_convergence_order`] and [`check_convergence_order`], which estimate the observed
+//!       order of convergence of a method from a step-size or mesh refinement study (see
+//!       [`ConvergenceStudy`]) and compare it against the expected order
+//!     * [`assert_solves`] and [`check_solves`], which check that a computed solution of a square
+//!       linear system has a small normwise backward error relative to the machine epsilon
+//!     * [`assert_matrix_is`] and [`check_matrix_is`], which check that a matrix is symmetric,
+//!       Hermitian, orthogonal, unitary, the identity, diagonal, upper or lower triangular, or
+//!       positive (semi)definite to within a tolerance (see [`MatrixProperty`]), reporting the
+//!       `(row, col)` index of the worst-violating element
+//!
+//! 1. The [`ArrayShape`] trait (implemented for nested `std` arrays and vectors, and for `ndarray`
+//!    arrays and `nalgebra` matrices when the `ndarray` and `nalgebra` features are enabled), which
+//!    the array macros use to reject comparisons between array-like structs of different shapes
+//!    and to print multi-dimensional indices of mismatched elements.
+//! 1. The [`Compare`] trait[^compare_note] (implemented for every `num_traits::Float` type that
+//!    implements [`Precision`], and for `num_complex::Complex` numbers when the `num-complex`
+//!    feature is enabled) for performing comparisons between floating-point numbers.
+//! 1. The [`Precision`] trait[^precision_note] (implemented for [`f32`] and [`f64`] types, and for
+//!    `half::f16` and `half::bf16` types when the `half` feature is enabled) providing methods for
+//!    accessing information regarding the precision of an _instance_ of a floating-point type.
 //!
 //! [^compare_note]: The methods implemented on this trait are used by the assertion macros for
 //! performing float comparisons.
@@ -37,6 +108,18 @@
 //! [^precision_note]: Some of the methods implemented on this trait are used by the methods on the
 //! [`Compare`] trait.
 //!
+//! The failure tables printed by the array macros list the index, the two elements, and the
+//! absolute and relative differences of each pair of elements (with the relative difference
+//! replaced by the comparison metric of the macro where it is more informative, e.g. the achieved
+//! decimal precision or ULP distance), and mark each row as `pass` or `FAIL`. Mismatched rows are
+//! also shown in bolded red when stderr is a terminal, respecting the `NO_COLOR` and
+//! `CLICOLOR_FORCE` environment variables (see [`ColorChoice`], [`set_color_choice`], and
+//! [`with_color_choice`]).
+//!
+//! For array-like structs with more elements than the report limit (see [`set_report_limit`]), the
+//! tables only print the first mismatched elements and the worst one, along with their indices,
+//! and are followed by summary statistics of the differences of all mismatched elements.
+//!
 //! # Equality assertions for floats
 //!
 //! ```
@@ -44,8 +127,25 @@
 //!
 //! assert_equal!(2.0, 2.0);
 //! assert_equal_to_decimal!(2.0, 2.012, 1);
+//! assert_equal_to_sigfigs!(6.02214076e23, 6.022e23, 4);
 //! assert_equal_to_atol!(2.0, 2.00001, 1e-3);
 //! assert_equal_to_rtol!(2.0, 2.01, 0.01);
+//! assert_equal_to_ulps!(1.0, 1.0 + f64::EPSILON, 1);
+//! assert_close!(1e-10, 0.0, 1e-5, 1e-8);
+//! ```
+//!
+//! # Ordered assertions for floats and arrays
+//!
+//! ```
+//! use numtest::*;
+//!
+//! assert_less_than!(3e-9, 1e-8, 0.0);
+//! assert_greater_than!(-1e-17, 0.0, 1e-15);
+//! assert_in_range!(1.0000000000000002, 0.0, 1.0, 1e-12);
+//!
+//! let residuals = [1e-2, 1e-3, 1e-3, 1e-5];
+//! assert_arrays_less_than!(&residuals[1..], &residuals[..3], 0.0);
+//! assert_arrays_in_range!(&residuals, 0.0, 1.0, 0.0);
 //! ```
 //!
 //! # Equality assertions for arrays
@@ -92,17 +192,244 @@
 //!
 //! assert_arrays_equal_to_decimal!(&mat1, &mat2, 1);
 //! ```
+//!
+//! # Non-panicking checks
+//!
+//! ```
+//! use numtest::*;
+//!
+//! let arr1 = [1.1, 2.2, 3.3];
+//! let arr2 = [1.1, 2.22, 3.33];
+//!
+//! let error = check_arrays_equal_to_atol!(&arr1, &arr2, 0.01).unwrap_err();
+//! assert_eq!(error.mismatched_indices(), vec![1, 2]);
+//! assert!(check_arrays_equal_to_atol!(&arr1, &arr2, 0.05).is_ok());
+//! ```
+//!
+//! # Soft assertions
+//!
+//! ```
+//! use numtest::*;
+//!
+//! let mut soft = SoftAssert::new();
+//! soft.atol("x", 1.0, 1.001, 1e-2)
+//!     .arrays_rtol("y", &[1.0, 2.0], &[1.0, 2.01], 1e-2);
+//! soft.finish();
+//! ```
+//!
+//! # Approximate equality of structs
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use numtest::*;
+//!
+//! #[derive(ApproxCompare)]
+//! struct State {
+//!     position: [f64; 3],
+//!     #[approx(atol = 1e-6)]
+//!     time: f64,
+//! }
+//!
+//! let state = State { position: [1.0, 2.0, 3.0], time: 0.0 };
+//! let expected = State { position: [1.0, 2.0, 3.0 + 1e-12], time: 1e-9 };
+//! assert_approx_eq!(state, expected, rtol = 1e-10);
+//! # }
+//! ```
+//!
+//! # Golden files
+//!
+//! ```no_run
+//! use numtest::*;
+//!
+//! let arr = [1.0, 0.1, 1e-300];
+//! assert_matches_golden!("small_values", &arr, rtol = 1e-10);
+//! ```
+//!
+//! # Reference tables
+//!
+//! ```
+//! use numtest::*;
+//!
+//! let table = parse_table("t, x\n0.0, 1.0\n1.0, 3.6787944117144233D-01\n").unwrap();
+//! let x: Vec<f64> = [0.0_f64, 1.0].iter().map(|t| (-t).exp()).collect();
+//! assert_arrays_equal_to_rtol!(&x, &table.column("x").unwrap(), 1e-12);
+//! ```
+//!
+//! # Reference arrays from `.npy` files
+//!
+//! With the `npy` feature enabled, arrays saved by `numpy.save` and `numpy.savez` (`.npy` files
+//! and `.npz` archives of `f4`, `f8`, `c8`, or `c16` arrays) can be loaded with `read_npy` and
+//! `read_npz`, and passed directly to the array macros (which then also check their shapes).
+//!
+//! ```no_run
+//! # #[cfg(feature = "npy")] {
+//! use numtest::*;
+//!
+//! let computed = [[1.0, 2.0], [3.0, 4.0]];
+//! let reference: NpyArray<f64> = read_npy("tests/data/reference.npy").unwrap();
+//! assert_arrays_equal_to_rtol!(&computed, &reference, 1e-12);
+//! # }
+//! ```
+//!
+//! # Derivative checks
+//!
+//! An analytic gradient can be checked against a finite-difference approximation of the function
+//! it differentiates, with step sizes chosen from the machine epsilon of the floating-point type.
+//! On failure, the analytic and numerical value of every component are printed.
+//!
+//! ```
+//! use numtest::*;
+//!
+//! let f = |x: &[f64]| x[0].powi(2) * x[1].sin();
+//! let grad_f = |x: &[f64]| [2.0 * x[0] * x[1].sin(), x[0].powi(2) * x[1].cos()];
+//! assert_gradient_matches!(f, grad_f, [1.5, 0.7], 1e-8);
+//! ```
+//!
+//! Jacobians of vector-valued functions and Hessians of scalar functions can be checked the same
+//! way, with mismatched elements reported by their `(row, col)` index.
+//!
+//! ```
+//! use numtest::*;
+//!
+//! let f = |x: &[f64]| vec![x[0] * x[1], x[0] + x[1].powi(2)];
+//! let jac_f = |x: &[f64]| [[x[1], x[0]], [1.0, 2.0 * x[1]]];
+//! assert_jacobian_matches!(f, jac_f, [1.5, 0.7], 1e-8);
+//! ```
+//!
+//! # Convergence order
+//!
+//! The observed order of accuracy of a discretization can be checked from its errors at a sequence
+//! of step sizes (given as `(step size, error)` pairs, or as a closure evaluated at each step
+//! size). On failure, the step sizes, errors, and local orders are printed.
+//!
+//! ```
+//! use numtest::*;
+//!
+//! // Central difference approximation of the derivative of sin at 1.
+//! let error = |h: f64| (((1.0 + h).sin() - (1.0 - h).sin()) / (2.0 * h) - 1.0_f64.cos()).abs();
+//! assert_convergence_order!(error, steps = [0.1, 0.05, 0.025, 0.0125], 2.0, 0.01);
+//! ```
+//!
+//! # Linear systems
+//!
+//! Rather than comparing a computed solution against a reference solution element-wise (which is
+//! ill-conditioned), a solver can be tested through the normwise backward error of its solution,
+//! in multiples of the machine epsilon. On failure, the residual norm, backward error, and an
+//! estimate of the condition number are printed.
+//!
+//! ```
+//! use numtest::*;
+//!
+//! let a = [[2.0, 1.0], [1.0, 4.0]];
+//! let b = [3.0, 5.0];
+//! let x = [1.0, 1.0];
+//! assert_solves!(&a, &x, &b, 10.0);
+//! ```
+//!
+//! # Matrix properties
+//!
+//! Structural properties of a matrix are checked element-wise against the same tolerances as the
+//! array macros, while positive (semi)definiteness is checked through the eigenvalues of the
+//! matrix.
+//!
+//! ```
+//! use numtest::*;
+//!
+//! let a = [[4.0, 1.0], [1.0, 3.0]];
+//! assert_matrix_is!(a, Symmetric, atol = 0.0);
+//! assert_matrix_is!(a, PositiveDefinite, atol = 1e-12);
+//!
+//! let q = [[0.6, -0.8], [0.8, 0.6]];
+//! assert_matrix_is!(q, Orthogonal, atol = 1e-15);
+//! ```
 
 // Linter setup.
 #![warn(missing_docs, warnings, clippy::all, clippy::pedantic, clippy::cargo)]
 #![allow(clippy::float_cmp, clippy::unreadable_literal)]
 
+// Allows the derive macros to refer to this crate as `numtest` from within this crate.
+extern crate self as numtest;
+
 // Module declarations.
+pub(crate) mod approx;
 pub(crate) mod assert_array;
 pub(crate) mod assert_float;
+pub(crate) mod assert_norm;
+pub(crate) mod assert_order;
+pub(crate) mod color;
 pub(crate) mod compare;
+pub(crate) mod convergence;
+pub(crate) mod derivative;
+pub(crate) mod golden;
+pub(crate) mod limit;
+pub(crate) mod linear_system;
+pub(crate) mod matrix;
+pub(crate) mod norm;
+#[cfg(feature = "npy")]
+pub(crate) mod npy;
 pub(crate) mod precision;
+pub(crate) mod report;
+pub(crate) mod shape;
+pub(crate) mod soft;
+pub(crate) mod table;
+pub(crate) mod tolerance;
 
 // Re-exports.
+pub use crate::approx::{
+    ApproxCompare, FieldComparison, FieldComparisonError, FieldMismatch, Tolerance,
+};
+pub use crate::color::{ColorChoice, color_choice, set_color_choice, with_color_choice};
 pub use crate::compare::Compare;
+pub use crate::convergence::ConvergenceStudy;
+#[cfg(feature = "num-complex")]
+pub use crate::derivative::complex_step_gradient;
+pub use crate::derivative::{
+    DifferenceScheme, numerical_gradient, numerical_hessian, numerical_jacobian,
+};
+pub use crate::golden::{GOLDEN_UPDATE_VAR, GoldenValue};
+pub use crate::limit::{DEFAULT_REPORT_LIMIT, report_limit, set_report_limit};
+pub use crate::matrix::MatrixProperty;
+pub use crate::norm::Norm;
+#[cfg(feature = "npy")]
+pub use crate::npy::{NpyArray, NpyElement, NpyError, parse_npy, read_npy, read_npz};
 pub use crate::precision::Precision;
+pub use crate::report::{ComparisonError, ComparisonErrorKind, ComparisonReport, Mismatch};
+pub use crate::shape::ArrayShape;
+pub use crate::soft::SoftAssert;
+pub use crate::table::{ReferenceTable, TableError, parse_table, read_table};
+pub use crate::tolerance::Tolerances;
+#[cfg(feature = "derive")]
+pub use numtest_derive::ApproxCompare;
+
+// Re-exports used by the macros.
+#[doc(hidden)]
+pub use crate::approx::field_path;
+#[doc(hidden)]
+pub use crate::assert_norm::check_norm;
+#[doc(hidden)]
+pub use crate::compare::real_to_f64;
+#[doc(hidden)]
+pub use crate::convergence::__check_convergence_order;
+#[cfg(feature = "num-complex")]
+#[doc(hidden)]
+pub use crate::derivative::check_complex_step_gradient;
+#[doc(hidden)]
+pub use crate::derivative::{check_gradient, check_hessian, check_jacobian};
+#[doc(hidden)]
+pub use crate::golden::check_golden_file;
+#[doc(hidden)]
+pub use crate::linear_system::check_solves;
+#[doc(hidden)]
+pub use crate::matrix::{MatrixElement, check_matrix_property};
+#[doc(hidden)]
+pub use crate::report::{
+    ElementComparison, compare_elements, compare_elements_with_tolerances, mismatched_elements_str,
+};
+#[doc(hidden)]
+pub use crate::shape::{Layout, ShapeProbe, ShapedElements, ViaArrayShape, ViaIter};
+#[doc(hidden)]
+pub use crate::table::check_reference_column;
+#[doc(hidden)]
+pub use crate::tolerance::{ToleranceProbe, ViaTolerances};
+#[doc(hidden)]
+pub use num_traits as __num_traits;
@@ -0,0 +1,62 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Default report limit (see [`set_report_limit`]).
+pub const DEFAULT_REPORT_LIMIT: usize = 100;
+
+/// Report limit set by [`set_report_limit`] ([`usize::MAX`] if there is no limit).
+static REPORT_LIMIT: AtomicUsize = AtomicUsize::new(DEFAULT_REPORT_LIMIT);
+
+/// Sets the maximum number of mismatched elements printed by the array assertion macros.
+///
+/// When the compared array-like structs have more elements than this limit, the failure table
+/// only prints the first `limit` mismatched elements (along with the worst mismatched element, if
+/// it is not among them) and their indices. Runs of rows that are not printed are elided with
+/// `...`, and the table is followed by summary statistics of the absolute and relative differences
+/// over all mismatched elements. Smaller array-like structs are always printed in full.
+///
+/// This setting is global, so it applies to all threads (and therefore to all tests in the same
+/// test binary). The default limit is [`DEFAULT_REPORT_LIMIT`].
+///
+/// # Arguments
+///
+/// * `limit` - Maximum number of mismatched elements to print (`None` to always print every
+///   element).
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use numtest::*;
+///
+/// set_report_limit(Some(2));
+///
+/// let arr1 = [0.0; 1000];
+/// let mut arr2 = [0.0; 1000];
+/// arr2[10] = 1.0;
+/// arr2[500] = 2.0;
+/// arr2[900] = 3.0;
+///
+/// let error = check_arrays_equal!(&arr1, &arr2).unwrap_err();
+/// assert_eq!(error.table.lines().count(), 9);
+/// assert!(error.message.contains("Summary of the 3 mismatched elements (3 shown):"));
+/// assert!(error.message.contains(" --> Max Abs Diff: 3\n"));
+/// assert!(error.message.contains(" --> Worst Element (Largest Abs Diff): 900\n"));
+///
+/// set_report_limit(Some(DEFAULT_REPORT_LIMIT));
+/// ```
+pub fn set_report_limit(limit: Option<usize>) {
+    REPORT_LIMIT.store(limit.unwrap_or(usize::MAX), Ordering::Relaxed);
+}
+
+/// Gets the report limit set by [`set_report_limit`].
+///
+/// # Returns
+///
+/// Maximum number of mismatched elements printed by the array assertion macros (`None` if every
+/// element is always printed).
+#[must_use]
+pub fn report_limit() -> Option<usize> {
+    match REPORT_LIMIT.load(Ordering::Relaxed) {
+        usize::MAX => None,
+        limit => Some(limit),
+    }
+}
@@ -0,0 +1,414 @@
+use crate::compare::real_to_f64;
+use crate::matrix::{Matrix, shape};
+use crate::norm::Norm;
+use crate::precision::Precision;
+use crate::report::{ComparisonError, ComparisonReport, compare_elements};
+use crate::shape::ShapedElements;
+use num_traits::Float;
+use std::fmt::LowerExp;
+
+/// Computes the L∞ norm of the inverse of a square matrix using an LU factorization with partial
+/// pivoting.
+///
+/// # Arguments
+///
+/// * `a` - Square matrix.
+///
+/// # Returns
+///
+/// L∞ norm (maximum absolute row sum) of the inverse of `a` (infinite if `a` is singular).
+// Indexing is clearer than iterators for the LU factorization and triangular solves.
+#[allow(clippy::needless_range_loop)]
+fn inverse_norm<T: Float>(a: &Matrix<T>) -> f64 {
+    let n = a.num_rows;
+    let mut lu: Vec<Vec<f64>> = a
+        .rows()
+        .take(n)
+        .map(|row| row.iter().map(|&aij| real_to_f64(aij)).collect())
+        .collect();
+    let mut permutation: Vec<usize> = (0..n).collect();
+
+    // Factorize PA = LU, storing L (without its unit diagonal) and U in place.
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|&i, &j| lu[i][k].abs().total_cmp(&lu[j][k].abs()))
+            .unwrap_or(k);
+        if lu[pivot][k] == 0.0 || !lu[pivot][k].is_finite() {
+            return f64::INFINITY;
+        }
+        lu.swap(k, pivot);
+        permutation.swap(k, pivot);
+        for i in (k + 1)..n {
+            let factor = lu[i][k] / lu[k][k];
+            lu[i][k] = factor;
+            for j in (k + 1)..n {
+                lu[i][j] -= factor * lu[k][j];
+            }
+        }
+    }
+
+    // Solve for each column of the inverse, accumulating the absolute row sums.
+    let mut row_sums = vec![0.0; n];
+    for col in 0..n {
+        let mut z: Vec<f64> = permutation
+            .iter()
+            .map(|&row| if row == col { 1.0 } else { 0.0 })
+            .collect();
+        for i in 0..n {
+            for k in 0..i {
+                z[i] -= lu[i][k] * z[k];
+            }
+        }
+        for i in (0..n).rev() {
+            for k in (i + 1)..n {
+                z[i] -= lu[i][k] * z[k];
+            }
+            z[i] /= lu[i][i];
+        }
+        for (row_sum, zi) in row_sums.iter_mut().zip(z) {
+            *row_sum += zi.abs();
+        }
+    }
+    Norm::Linf.compute(row_sums)
+}
+
+/// Checks that a vector solves a linear system to within a backward error (see
+/// [`check_solves`](crate::check_solves!)).
+///
+/// # Arguments
+///
+/// * `a` - Elements of the matrix of the linear system (see
+///   [`shaped_elements`](crate::shaped_elements)).
+/// * `x` - Elements of the computed solution.
+/// * `b` - Elements of the right-hand side.
+/// * `tol` - Tolerance on the backward error, in multiples of the machine epsilon.
+///
+/// # Returns
+///
+/// Report summarizing the comparison, whose `worst_difference` is the normwise backward error.
+///
+/// # Errors
+///
+/// If `a` is not a square matrix, if `x` or `b` does not have one element per row of `a`, or if
+/// the normwise backward error exceeds `tol` times the machine epsilon.
+#[doc(hidden)]
+pub fn check_solves<T>(
+    a: ShapedElements<T>,
+    x: ShapedElements<T>,
+    b: ShapedElements<T>,
+    tol: f64,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: Float + Precision + LowerExp,
+{
+    // Check the dimensions of the linear system.
+    let a_shape = shape(&a);
+    let a = match Matrix::from_elements(a) {
+        Ok(a) if a.num_rows == a.num_cols => a,
+        _ => {
+            return Err(ComparisonError::invalid_shape(
+                "A",
+                a_shape,
+                String::from("a square matrix"),
+            ));
+        }
+    };
+    let n = a.num_rows;
+    for (name, vector) in [("x", &x), ("b", &b)] {
+        if vector.elements.len() != n {
+            return Err(ComparisonError::invalid_shape(
+                name,
+                shape(vector),
+                format!("a vector with {n} elements"),
+            ));
+        }
+    }
+
+    // Compute the residual, and the normwise backward error (in f64, so that the residual of
+    // lower-precision systems is not polluted by rounding errors).
+    let x_f64: Vec<f64> = x.elements.into_iter().map(real_to_f64).collect();
+    let ax: Vec<f64> = a
+        .rows()
+        .take(n)
+        .map(|row| {
+            row.iter()
+                .zip(&x_f64)
+                .map(|(&aij, xj)| real_to_f64(aij) * xj)
+                .sum()
+        })
+        .collect();
+    let residuals: Vec<f64> = ax
+        .iter()
+        .zip(&b.elements)
+        .map(|(axi, &bi)| (axi - real_to_f64(bi)).abs())
+        .collect();
+    let norm_a = Norm::Linf.compute(
+        a.rows()
+            .take(n)
+            .map(|row| Norm::L1.compute(row.iter().map(|&aij| real_to_f64(aij).abs()))),
+    );
+    let norm_x = Norm::Linf.compute(x_f64.iter().map(|xi| xi.abs()));
+    let norm_b = Norm::Linf.compute(b.elements.iter().map(|&bi| real_to_f64(bi).abs()));
+    let norm_r = Norm::Linf.compute(residuals.iter().copied());
+    let backward_error = if norm_r == 0.0 {
+        0.0
+    } else {
+        norm_r / (norm_a * norm_x + norm_b)
+    };
+    let threshold = tol * real_to_f64(T::one().epsilon());
+    if backward_error <= threshold {
+        return Ok(ComparisonReport {
+            num_elements: n,
+            worst_difference: backward_error,
+        });
+    }
+
+    // Print the rows with the largest residuals.
+    let condition_number = norm_a * inverse_norm(&a);
+    let ax = ShapedElements {
+        layout: None,
+        elements: ax
+            .iter()
+            .map(|&axi| T::from(axi).unwrap_or_else(T::nan))
+            .collect(),
+        ragged: false,
+    };
+    let header = |num_mismatched, count| {
+        format!(
+            "\nThe solution does not solve the linear system to within a backward error of {tol} \
+            * epsilon ({threshold:e}).\n --> Residual Norm: {norm_r:e}\n --> Backward Error: \
+            {backward_error:e}\n --> Estimated Condition Number: {condition_number:e}\n --> \
+            Worst Offenders: {num_mismatched}/{count}"
+        )
+    };
+    let mut residuals = residuals.into_iter();
+    compare_elements(ax, b, |_, _| (true, residuals.next().unwrap_or(f64::NAN))).and_then(
+        |comparison| {
+            comparison
+                .flag_worst(5)
+                .with_labels("A*x", "b")
+                .finish(backward_error, header)
+        },
+    )
+}
+
+/// Checks that a vector solves a linear system `Ax = b` to within a normwise backward error.
+///
+/// This macro is the non-panicking counterpart of [`assert_solves`](crate::assert_solves).
+///
+/// # Arguments
+///
+/// * `a` - Square matrix of the linear system (a 2D array-like struct implementing
+///   [`ArrayShape`](crate::ArrayShape), e.g. a nested [`Vec`] or array, or, with the `ndarray` and
+///   `nalgebra` features enabled, an `ndarray` array or a `nalgebra` matrix).
+/// * `x` - Computed solution (an array-like struct with one element per row of `a`).
+/// * `b` - Right-hand side (an array-like struct with one element per row of `a`).
+/// * `tol` - Tolerance on the backward error, in multiples of the machine epsilon (an [`f64`]).
+///
+/// # Returns
+///
+/// A [`ComparisonReport`](crate::ComparisonReport) whose `worst_difference` is the normwise
+/// backward error, if it is within the tolerance.
+///
+/// # Errors
+///
+/// A [`ComparisonError`](crate::ComparisonError) whose message is the message that the
+/// corresponding assertion macro panics with, if `a` is not a square matrix (or `x` or `b` does
+/// not have one element per row of `a`), or if the backward error exceeds the tolerance. In the
+/// latter case, the error's mismatches are the rows with the largest residuals, with `A*x` as `a`
+/// and `b` as `b`.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let a = [[4.0, 1.0], [1.0, 3.0]];
+/// let b = [1.0, 2.0];
+///
+/// let report = check_solves!(&a, &[1.0 / 11.0, 7.0 / 11.0], &b, 10.0).unwrap();
+/// assert!(report.worst_difference < 1e-15);
+///
+/// let error = check_solves!(&a, &[0.09, 0.64], &b, 10.0).unwrap_err();
+/// assert_eq!(error.mismatched_indices(), vec![1]);
+/// ```
+#[macro_export]
+macro_rules! check_solves {
+    ($a:expr, $x:expr, $b:expr, $tol:expr) => {
+        $crate::check_solves(
+            $crate::shaped_elements!($a),
+            $crate::shaped_elements!($x),
+            $crate::shaped_elements!($b),
+            $tol,
+        )
+    };
+}
+
+/// Asserts that a vector solves a linear system `Ax = b` to within a normwise backward error.
+///
+/// This macro computes the normwise backward error
+///
+/// $$\eta=\frac{\|\|A\mathbf{x}-\mathbf{b}\|\|}{\|\|A\|\|\\,\|\|\mathbf{x}\|\|+\|\|\mathbf{b}\|\|}$$
+///
+/// (using the L∞ norm for vectors and the induced L∞ norm, i.e. the maximum absolute row sum, for
+/// the matrix), which is the smallest relative perturbation of `A` and `b` for which `x` is the
+/// exact solution, and checks that $\eta\leq\mathrm{tol}\cdot\varepsilon$, where $\varepsilon$ is
+/// the machine epsilon of the element type (see [`Precision::epsilon`](crate::Precision::epsilon)).
+/// Unlike comparing `x` element-wise against a reference solution, this does not depend on the
+/// conditioning of `A`: a backward stable solver (e.g. Gaussian elimination with partial
+/// pivoting) achieves a backward error of a small multiple of $\varepsilon$ even when `x` itself
+/// is inaccurate.
+///
+/// # Arguments
+///
+/// * `a` - Square matrix of the linear system (a 2D array-like struct implementing
+///   [`ArrayShape`](crate::ArrayShape), e.g. a nested [`Vec`] or array, or, with the `ndarray` and
+///   `nalgebra` features enabled, an `ndarray` array or a `nalgebra` matrix).
+/// * `x` - Computed solution (an array-like struct with one element per row of `a`).
+/// * `b` - Right-hand side (an array-like struct with one element per row of `a`).
+/// * `tol` - Tolerance on the backward error, in multiples of the machine epsilon (an [`f64`]).
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If `a` is not a square matrix, or if `x` or `b` does not have one element per row of `a`. In
+///   this case, the shape of the offending array-like struct is also printed.
+/// * If the backward error exceeds `tol` times the machine epsilon. In this case, the residual
+///   norm, the backward error, and the L∞ condition number of `a` (computed from an LU
+///   factorization) are printed, along with `A*x` and `b`, with the (up to five) rows with the
+///   largest residuals shown in bolded red.
+///
+/// # Note
+///
+/// The residual is computed in [`f64`] arithmetic, so for [`f64`] systems, it is itself only
+/// accurate to about $\varepsilon\\,\|\|A\|\|\\,\|\|\mathbf{x}\|\|$, and `tol` should not be
+/// smaller than about the number of rows of `a`.
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let a = vec![vec![4.0, 1.0, 0.0], vec![1.0, 3.0, 1.0], vec![0.0, 1.0, 2.0]];
+/// let b = vec![1.5, 3.25, 3.5];
+/// let x = vec![0.25, 0.5, 1.5];
+/// assert_solves!(&a, &x, &b, 10.0);
+/// ```
+///
+/// ```
+/// # #[cfg(feature = "nalgebra")] {
+/// use nalgebra::{Matrix2, Vector2};
+/// use numtest::*;
+///
+/// let a = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+/// let b = Vector2::new(5.0, 6.0);
+/// let x = a.lu().solve(&b).unwrap();
+/// assert_solves!(&a, &x, &b, 10.0);
+/// # }
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let a = [[4.0, 1.0], [1.0, 3.0]];
+/// let b = [1.0, 2.0];
+/// assert_solves!(&a, &[0.09, 0.64], &b, 10.0, "solver {}", "jacobi");
+/// ```
+#[macro_export]
+macro_rules! assert_solves {
+    ($a:expr, $x:expr, $b:expr, $tol:expr) => {{
+        if let Err(error) = $crate::check_solves!($a, $x, $b, $tol) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $x:expr, $b:expr, $tol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_solves!($a, $x, $b, $tol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ComparisonErrorKind;
+    use crate::{ColorChoice, with_color_choice};
+
+    #[test]
+    fn test_inverse_norm() {
+        let shaped = |elements: Vec<f64>| ShapedElements {
+            layout: Some(crate::shape::Layout {
+                shape: vec![2, 2],
+                column_major: false,
+            }),
+            elements,
+            ragged: false,
+        };
+
+        // inv([[4, 1], [1, 3]]) = [[3, -1], [-1, 4]] / 11.
+        let a = Matrix::from_elements(shaped(vec![4.0, 1.0, 1.0, 3.0])).unwrap();
+        assert!((inverse_norm(&a) - 5.0 / 11.0).abs() < 1e-15);
+
+        // Requires pivoting.
+        let a = Matrix::from_elements(shaped(vec![0.0, 2.0, 1.0, 0.0])).unwrap();
+        assert_eq!(inverse_norm(&a), 1.0);
+
+        // Singular.
+        let a = Matrix::from_elements(shaped(vec![1.0, 2.0, 2.0, 4.0])).unwrap();
+        assert_eq!(inverse_norm(&a), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_solves() {
+        let a = vec![
+            vec![4.0, 1.0, 0.0],
+            vec![1.0, 3.0, 1.0],
+            vec![0.0, 1.0, 2.0],
+        ];
+        let b = vec![1.5, 3.25, 3.5];
+        assert_solves!(&a, &[0.25, 0.5, 1.5], &b, 10.0);
+
+        // f32.
+        let a = [[2.0_f32, 1.0], [1.0, 1.0]];
+        let report = check_solves!(&a, &[1.0_f32, 1.0], &[3.0_f32, 2.0], 1.0).unwrap();
+        assert_eq!(report.worst_difference, 0.0);
+        assert!(check_solves!(&a, &[1.001_f32, 1.0], &[3.0_f32, 2.0], 10.0).is_err());
+    }
+
+    #[test]
+    fn test_solves_invalid_shapes() {
+        let error = check_solves!(&[[1.0, 2.0, 3.0]], &[1.0], &[1.0], 10.0).unwrap_err();
+        assert_eq!(
+            *error.kind,
+            ComparisonErrorKind::InvalidShape {
+                name: String::from("A"),
+                shape: vec![1, 3],
+                expected: String::from("a square matrix"),
+            }
+        );
+        let error =
+            check_solves!(&[[1.0, 0.0], [0.0, 1.0]], &[1.0], &[1.0, 2.0], 10.0).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "\nx must be a vector with 2 elements.\n --> x shape: [1]\n"
+        );
+        let ragged = vec![vec![1.0, 2.0], vec![3.0]];
+        let error = check_solves!(&ragged, &[1.0, 1.0], &[3.0, 3.0], 10.0).unwrap_err();
+        assert!(matches!(
+            *error.kind,
+            ComparisonErrorKind::InvalidShape { ref expected, .. } if expected == "a square matrix"
+        ));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "solver jacobi\nThe solution does not solve the linear system to within a backward error of 10 * epsilon (2.220446049250313e-15).\n --> Residual Norm: 9.999999999999787e-3\n --> Backward Error: 1.923076923076882e-3\n --> Estimated Condition Number: 2.272727272727273e0\n --> Worst Offenders: 1/2\n\nindex     A*x    b  abs diff  rel diff  result\n-----  ------  ---  --------  --------  ------\n    0     1e0  1e0         0         0    pass\n\u{1b}[31;1m    1  2.01e0  2e0      1e-2  4.975e-3    FAIL\u{1b}[0m\n"
+    )]
+    fn test_solves_fail() {
+        let a = [[4.0, 1.0], [1.0, 3.0]];
+        with_color_choice(ColorChoice::Always, || {
+            assert_solves!(&a, &[0.09, 0.64], &[1.0, 2.0], 10.0, "solver {}", "jacobi");
+        });
+    }
+}
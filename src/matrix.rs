@@ -0,0 +1,838 @@
+use crate::approx::Tolerance;
+use crate::compare::{Compare, real_to_f64};
+use crate::precision::Precision;
+use crate::report::{ComparisonError, ComparisonReport, compare_elements_to_tolerance};
+use crate::shape::{Layout, ShapedElements, format_multi_index};
+use num_traits::{Float, Num};
+use std::fmt::{Display, LowerExp};
+
+/// Properties of matrices checked by [`assert_matrix_is`](crate::assert_matrix_is).
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let q = [[0.6, -0.8], [0.8, 0.6]];
+/// assert!(check_matrix_is!(q, Orthogonal, atol = 1e-15).is_ok());
+/// assert!(check_matrix_is!(q, Symmetric, atol = 1e-15).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatrixProperty {
+    /// `A = A^T` (`A` must be square).
+    Symmetric,
+
+    /// `A = A^H`, where `A^H` is the conjugate transpose of `A` (`A` must be square). For real
+    /// matrices, this is the same as [`MatrixProperty::Symmetric`].
+    Hermitian,
+
+    /// `A^T A = I` (i.e. the columns of `A` are orthonormal, so `A` may have more rows than
+    /// columns).
+    Orthogonal,
+
+    /// `A^H A = I` (i.e. the columns of `A` are orthonormal with respect to the complex inner
+    /// product, so `A` may have more rows than columns). For real matrices, this is the same as
+    /// [`MatrixProperty::Orthogonal`].
+    Unitary,
+
+    /// `A = I` (`A` must be square).
+    Identity,
+
+    /// Every element off the main diagonal of `A` is zero.
+    Diagonal,
+
+    /// Every element below the main diagonal of `A` is zero.
+    UpperTriangular,
+
+    /// Every element above the main diagonal of `A` is zero.
+    LowerTriangular,
+
+    /// `A` is Hermitian (symmetric, for real matrices) and all of its eigenvalues are positive
+    /// (`A` must be square).
+    PositiveDefinite,
+
+    /// `A` is Hermitian (symmetric, for real matrices) and all of its eigenvalues are nonnegative
+    /// (`A` must be square).
+    PositiveSemidefinite,
+}
+
+impl Display for MatrixProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            MatrixProperty::Symmetric => "symmetric",
+            MatrixProperty::Hermitian => "Hermitian",
+            MatrixProperty::Orthogonal => "orthogonal",
+            MatrixProperty::Unitary => "unitary",
+            MatrixProperty::Identity => "the identity",
+            MatrixProperty::Diagonal => "diagonal",
+            MatrixProperty::UpperTriangular => "upper triangular",
+            MatrixProperty::LowerTriangular => "lower triangular",
+            MatrixProperty::PositiveDefinite => "positive definite",
+            MatrixProperty::PositiveSemidefinite => "positive semidefinite",
+        })
+    }
+}
+
+/// Element of a matrix whose properties can be checked (see [`MatrixProperty`]).
+///
+/// This trait is implemented for every `num_traits::Float` type that implements [`Precision`],
+/// and for `num_complex::Complex` numbers when the `num-complex` feature is enabled.
+#[doc(hidden)]
+pub trait MatrixElement: Compare + Copy + Num {
+    /// Whether the element type is complex.
+    const IS_COMPLEX: bool;
+
+    /// Complex conjugate of the element (the element itself for real numbers).
+    ///
+    /// # Returns
+    ///
+    /// Complex conjugate.
+    #[must_use]
+    fn conjugate(self) -> Self;
+
+    /// Real and imaginary parts of the element, as [`f64`]s.
+    ///
+    /// # Returns
+    ///
+    /// Real and imaginary parts (the imaginary part is `0` for real numbers).
+    fn parts(self) -> (f64, f64);
+
+    /// Converts a real number into an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Real number.
+    ///
+    /// # Returns
+    ///
+    /// Element with real part `value` (`NaN` if it cannot be represented).
+    fn from_real(value: f64) -> Self;
+
+    /// Machine epsilon of the element type (of its real and imaginary parts, for complex numbers).
+    ///
+    /// # Returns
+    ///
+    /// Machine epsilon, as an [`f64`].
+    fn machine_epsilon() -> f64;
+}
+
+impl<T: Float + Precision> MatrixElement for T {
+    const IS_COMPLEX: bool = false;
+
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn parts(self) -> (f64, f64) {
+        (real_to_f64(self), 0.0)
+    }
+
+    fn from_real(value: f64) -> Self {
+        T::from(value).unwrap_or_else(T::nan)
+    }
+
+    fn machine_epsilon() -> f64 {
+        real_to_f64(T::one().epsilon())
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl<T: Float + Precision> MatrixElement for num_complex::Complex<T> {
+    const IS_COMPLEX: bool = true;
+
+    fn conjugate(self) -> Self {
+        self.conj()
+    }
+
+    fn parts(self) -> (f64, f64) {
+        (real_to_f64(self.re), real_to_f64(self.im))
+    }
+
+    fn from_real(value: f64) -> Self {
+        num_complex::Complex::new(T::from(value).unwrap_or_else(T::nan), T::zero())
+    }
+
+    fn machine_epsilon() -> f64 {
+        real_to_f64(T::one().epsilon())
+    }
+}
+
+/// Dense matrix collected from a 2D array-like struct, with its elements stored in row-major
+/// order.
+pub(crate) struct Matrix<T> {
+    /// Number of rows.
+    pub(crate) num_rows: usize,
+
+    /// Number of columns.
+    pub(crate) num_cols: usize,
+
+    /// Elements, in row-major order.
+    elements: Vec<T>,
+}
+
+impl<T: Copy> Matrix<T> {
+    /// Collects the elements of a 2D array-like struct into a matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - Elements of the array-like struct (see
+    ///   [`shaped_elements`](crate::shaped_elements)).
+    ///
+    /// # Returns
+    ///
+    /// Matrix.
+    ///
+    /// # Errors
+    ///
+    /// If the array-like struct is not known to be 2D, or its number of elements does not match its
+    /// shape (e.g. a ragged nested vector), in which case its shape (see [`shape`]) is returned.
+    pub(crate) fn from_elements(arr: ShapedElements<T>) -> Result<Self, Vec<usize>> {
+        let Some(layout) = arr.layout.as_ref().filter(|layout| {
+            layout.shape.len() == 2 && layout.shape.iter().product::<usize>() == arr.elements.len()
+        }) else {
+            return Err(shape(&arr));
+        };
+        let (num_rows, num_cols) = (layout.shape[0], layout.shape[1]);
+        let elements = if layout.column_major {
+            (0..num_rows)
+                .flat_map(|i| (0..num_cols).map(move |j| (i, j)))
+                .map(|(i, j)| arr.elements[j * num_rows + i])
+                .collect()
+        } else {
+            arr.elements
+        };
+        Ok(Self {
+            num_rows,
+            num_cols,
+            elements,
+        })
+    }
+
+    /// Element of the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - Row index.
+    /// * `col` - Column index.
+    ///
+    /// # Returns
+    ///
+    /// Element in row `row` and column `col`.
+    pub(crate) fn get(&self, row: usize, col: usize) -> T {
+        self.elements[row * self.num_cols + col]
+    }
+
+    /// Rows of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// Iterator over the rows of the matrix.
+    pub(crate) fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.elements.chunks(self.num_cols.max(1))
+    }
+}
+
+/// Shape of an array-like struct.
+///
+/// # Arguments
+///
+/// * `arr` - Elements of the array-like struct.
+///
+/// # Returns
+///
+/// Shape of the array-like struct, or its number of elements if its shape is not known.
+pub(crate) fn shape<T>(arr: &ShapedElements<T>) -> Vec<usize> {
+    arr.layout
+        .as_ref()
+        .map_or_else(|| vec![arr.elements.len()], |layout| layout.shape.clone())
+}
+
+/// Computes the eigenvalues of a real symmetric matrix using the cyclic Jacobi method.
+///
+/// # Arguments
+///
+/// * `a` - Rows of a real symmetric matrix.
+///
+/// # Returns
+///
+/// Eigenvalues of the matrix (in no particular order).
+// Indexing is clearer than iterators for the Jacobi rotations.
+#[allow(clippy::needless_range_loop)]
+fn symmetric_eigenvalues(mut a: Vec<Vec<f64>>) -> Vec<f64> {
+    let n = a.len();
+    let norm_squared: f64 = a.iter().flatten().map(|aij| aij * aij).sum();
+    for _ in 0..100 {
+        // Stop once the off-diagonal elements are negligible.
+        let off_diagonal_squared: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum();
+        if off_diagonal_squared <= f64::EPSILON * f64::EPSILON * norm_squared {
+            break;
+        }
+
+        // Sweep over the off-diagonal elements, zeroing each one with a rotation.
+        for p in 0..n {
+            for q in (p + 1)..n {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let tangent = theta.signum() / (theta.abs() + theta.hypot(1.0));
+                let cosine = 1.0 / tangent.hypot(1.0);
+                let sine = tangent * cosine;
+                for k in 0..n {
+                    let (akp, akq) = (a[k][p], a[k][q]);
+                    a[k][p] = cosine * akp - sine * akq;
+                    a[k][q] = sine * akp + cosine * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p][k], a[q][k]);
+                    a[p][k] = cosine * apk - sine * aqk;
+                    a[q][k] = sine * apk + cosine * aqk;
+                }
+            }
+        }
+    }
+    (0..n).map(|i| a[i][i]).collect()
+}
+
+/// Checks a matrix property defined element-wise (i.e. every property except definiteness).
+///
+/// # Arguments
+///
+/// * `a` - Matrix.
+/// * `property` - Property.
+/// * `tolerance` - Tolerance.
+///
+/// # Returns
+///
+/// Report summarizing the comparison.
+///
+/// # Errors
+///
+/// If any element of the matrix (or of `A^T A` or `A^H A`, for orthogonality and unitarity)
+/// differs from the value required by the property by more than the tolerance.
+fn check_elements<T>(
+    a: &Matrix<T>,
+    property: MatrixProperty,
+    tolerance: Tolerance,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: MatrixElement + LowerExp,
+{
+    let kronecker = |i: usize, j: usize| if i == j { T::one() } else { T::zero() };
+
+    // Element of the matrix (or of its Gram matrix), and the value required by the property.
+    let element = |i: usize, j: usize| -> (T, T) {
+        match property {
+            MatrixProperty::Symmetric => (a.get(i, j), a.get(j, i)),
+            MatrixProperty::Hermitian
+            | MatrixProperty::PositiveDefinite
+            | MatrixProperty::PositiveSemidefinite => (a.get(i, j), a.get(j, i).conjugate()),
+            MatrixProperty::Orthogonal | MatrixProperty::Unitary => {
+                let gram = (0..a.num_rows).fold(T::zero(), |sum, k| {
+                    let aki = if property == MatrixProperty::Unitary {
+                        a.get(k, i).conjugate()
+                    } else {
+                        a.get(k, i)
+                    };
+                    sum + aki * a.get(k, j)
+                });
+                (gram, kronecker(i, j))
+            }
+            MatrixProperty::Identity => (a.get(i, j), kronecker(i, j)),
+            MatrixProperty::Diagonal if i != j => (a.get(i, j), T::zero()),
+            MatrixProperty::UpperTriangular if i > j => (a.get(i, j), T::zero()),
+            MatrixProperty::LowerTriangular if i < j => (a.get(i, j), T::zero()),
+            MatrixProperty::Diagonal
+            | MatrixProperty::UpperTriangular
+            | MatrixProperty::LowerTriangular => (a.get(i, j), a.get(i, j)),
+        }
+    };
+    let labels = match property {
+        MatrixProperty::Symmetric => ("A", "A^T"),
+        MatrixProperty::Hermitian
+        | MatrixProperty::PositiveDefinite
+        | MatrixProperty::PositiveSemidefinite => ("A", "A^H"),
+        MatrixProperty::Orthogonal => ("A^T*A", "I"),
+        MatrixProperty::Unitary => ("A^H*A", "I"),
+        MatrixProperty::Identity => ("A", "I"),
+        MatrixProperty::Diagonal => ("A", "diag(A)"),
+        MatrixProperty::UpperTriangular => ("A", "triu(A)"),
+        MatrixProperty::LowerTriangular => ("A", "tril(A)"),
+    };
+    let shape = match property {
+        MatrixProperty::Orthogonal | MatrixProperty::Unitary => vec![a.num_cols, a.num_cols],
+        _ => vec![a.num_rows, a.num_cols],
+    };
+    let (actual, required): (Vec<T>, Vec<T>) = (0..shape[0])
+        .flat_map(|i| (0..shape[1]).map(move |j| (i, j)))
+        .map(|(i, j)| element(i, j))
+        .unzip();
+
+    // Locate the worst-violating element (treating NaN as the worst).
+    let num_cols = shape[1];
+    let worst_index = actual
+        .iter()
+        .zip(&required)
+        .map(|(&a, &b)| {
+            let (_, difference) = tolerance.compare(a, b);
+            match (tolerance, difference.is_nan()) {
+                (_, true) => f64::INFINITY,
+                (Tolerance::Decimal(_), false) => -difference,
+                (_, false) => difference,
+            }
+        })
+        .enumerate()
+        .max_by(|(_, x), (_, y)| x.total_cmp(y))
+        .map_or(0, |(index, _)| index);
+    let worst_element =
+        format_multi_index(&[worst_index / num_cols.max(1), worst_index % num_cols.max(1)]);
+
+    let actual = ShapedElements {
+        layout: Some(Layout {
+            shape,
+            column_major: false,
+        }),
+        elements: actual,
+        ragged: false,
+    };
+    let required = ShapedElements {
+        layout: None,
+        elements: required,
+        ragged: false,
+    };
+    compare_elements_to_tolerance(
+        actual,
+        required,
+        tolerance,
+        labels,
+        |num_mismatched, count, worst_line| {
+            format!(
+                "\nThe matrix is not {property} ({tolerance}).\n --> Mismatched Elements: \
+                {num_mismatched}/{count}\n{worst_line}\n --> Worst Element: {worst_element}"
+            )
+        },
+    )
+}
+
+/// Checks that a Hermitian (or real symmetric) matrix is positive definite or positive
+/// semidefinite.
+///
+/// # Arguments
+///
+/// * `a` - Square matrix, assumed to be Hermitian to within the tolerance.
+/// * `property` - [`MatrixProperty::PositiveDefinite`] or
+///   [`MatrixProperty::PositiveSemidefinite`].
+/// * `tolerance` - Tolerance.
+///
+/// # Returns
+///
+/// Report summarizing the check, whose `worst_difference` is the smallest eigenvalue.
+///
+/// # Errors
+///
+/// If the smallest eigenvalue of the Hermitian part of the matrix is not larger than the threshold
+/// derived from the tolerance (positive definiteness), or is smaller than the negated threshold
+/// (positive semidefiniteness).
+fn check_definiteness<T>(
+    a: &Matrix<T>,
+    property: MatrixProperty,
+    tolerance: Tolerance,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: MatrixElement,
+{
+    // Real symmetric matrix with the same eigenvalues as the Hermitian part X + iY of the matrix
+    // (the complex case uses the embedding [[X, -Y], [Y, X]], whose eigenvalues are those of
+    // X + iY, each repeated twice).
+    let n = a.num_rows;
+    let hermitian_part = |i: usize, j: usize| {
+        let (re_ij, im_ij) = a.get(i, j).parts();
+        let (re_ji, im_ji) = a.get(j, i).parts();
+        (f64::midpoint(re_ij, re_ji), f64::midpoint(im_ij, -im_ji))
+    };
+    let size = if T::IS_COMPLEX { 2 * n } else { n };
+    let symmetric: Vec<Vec<f64>> = (0..size)
+        .map(|i| {
+            (0..size)
+                .map(|j| {
+                    let (x, y) = hermitian_part(i % n, j % n);
+                    match (i < n, j < n) {
+                        (true, false) => -y,
+                        (false, true) => y,
+                        _ => x,
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let eigenvalues = symmetric_eigenvalues(symmetric);
+
+    // Smallest and largest eigenvalues (NaN if any eigenvalue is NaN).
+    let extreme = |pick: fn(f64, f64) -> f64, init: f64| {
+        eigenvalues.iter().fold(init, |extreme, &eigenvalue| {
+            if extreme.is_nan() || eigenvalue.is_nan() {
+                f64::NAN
+            } else {
+                pick(extreme, eigenvalue)
+            }
+        })
+    };
+    let min_eigenvalue = extreme(f64::min, f64::INFINITY);
+    let max_eigenvalue = extreme(f64::max, f64::NEG_INFINITY);
+
+    // Threshold that the eigenvalues are compared against.
+    let scale = min_eigenvalue.abs().max(max_eigenvalue.abs());
+    #[allow(clippy::cast_precision_loss)]
+    let threshold = match tolerance {
+        Tolerance::Atol(atol) => atol,
+        Tolerance::Rtol(rtol) => rtol * scale,
+        Tolerance::Decimal(decimal) => 1.5 * 10.0_f64.powi(-decimal),
+        Tolerance::Ulps(ulps) => ulps as f64 * T::machine_epsilon() * scale,
+    };
+    let (passed, bound, requirement) = if property == MatrixProperty::PositiveDefinite {
+        (min_eigenvalue > threshold, threshold, ">")
+    } else {
+        (min_eigenvalue >= -threshold, -threshold, ">=")
+    };
+    if passed {
+        return Ok(ComparisonReport {
+            num_elements: n * n,
+            worst_difference: min_eigenvalue,
+        });
+    }
+    Err(ComparisonError::scalar(
+        T::from_real(min_eigenvalue),
+        T::from_real(bound),
+        min_eigenvalue,
+        format!(
+            "\nThe matrix is not {property} ({tolerance}).\n --> Smallest Eigenvalue: \
+            {min_eigenvalue:e}\n --> Largest Eigenvalue: {max_eigenvalue:e}\n --> Required: \
+            smallest eigenvalue {requirement} {bound:e}\n"
+        ),
+    ))
+}
+
+/// Checks that a matrix has a property (see [`check_matrix_is`](crate::check_matrix_is)).
+///
+/// # Arguments
+///
+/// * `a` - Elements of the matrix (see [`shaped_elements`](crate::shaped_elements)).
+/// * `property` - Property.
+/// * `tolerance` - Tolerance.
+///
+/// # Returns
+///
+/// Report summarizing the check.
+///
+/// # Errors
+///
+/// If the matrix is not 2D (or not square, for properties that require it), or if it does not
+/// have the property to within the tolerance.
+#[doc(hidden)]
+pub fn check_matrix_property<T>(
+    a: ShapedElements<T>,
+    property: MatrixProperty,
+    tolerance: Tolerance,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: MatrixElement + LowerExp,
+{
+    let a_shape = shape(&a);
+    let Ok(a) = Matrix::from_elements(a) else {
+        return Err(ComparisonError::invalid_shape(
+            "A",
+            a_shape,
+            String::from("a 2D matrix"),
+        ));
+    };
+    let requires_square = matches!(
+        property,
+        MatrixProperty::Symmetric
+            | MatrixProperty::Hermitian
+            | MatrixProperty::Identity
+            | MatrixProperty::PositiveDefinite
+            | MatrixProperty::PositiveSemidefinite
+    );
+    if requires_square && a.num_rows != a.num_cols {
+        return Err(ComparisonError::invalid_shape(
+            "A",
+            a_shape,
+            String::from("a square matrix"),
+        ));
+    }
+    match property {
+        MatrixProperty::PositiveDefinite | MatrixProperty::PositiveSemidefinite => {
+            let symmetry = if T::IS_COMPLEX {
+                MatrixProperty::Hermitian
+            } else {
+                MatrixProperty::Symmetric
+            };
+            check_elements(&a, symmetry, tolerance)?;
+            check_definiteness(&a, property, tolerance)
+        }
+        _ => check_elements(&a, property, tolerance),
+    }
+}
+
+/// Checks that a matrix has a property (e.g. symmetry or orthogonality) to within a tolerance.
+///
+/// This macro is the non-panicking counterpart of [`assert_matrix_is`](crate::assert_matrix_is).
+///
+/// # Arguments
+///
+/// * `a` - Matrix (a 2D array-like struct implementing [`ArrayShape`](crate::ArrayShape), e.g. a
+///   nested [`Vec`] or array, or, with the `ndarray` and `nalgebra` features enabled, an `ndarray`
+///   array or a `nalgebra` matrix).
+/// * `property` - [`MatrixProperty`](crate::MatrixProperty) variant (e.g. `Symmetric`).
+/// * `kind = tol` - Tolerance, where `kind` is one of `atol`, `rtol`, `decimal`, or `ulps` (see
+///   [`Tolerance`](crate::Tolerance)).
+///
+/// # Returns
+///
+/// [`ComparisonReport`](crate::ComparisonReport) if the matrix has the property, otherwise a
+/// [`ComparisonError`](crate::ComparisonError).
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let a = [[1.0, 2.0], [2.001, 3.0]];
+/// assert!(check_matrix_is!(a, Symmetric, atol = 1e-2).is_ok());
+///
+/// let error = check_matrix_is!(a, Symmetric, atol = 1e-6).unwrap_err();
+/// assert_eq!(error.mismatched_indices(), vec![1, 2]);
+/// ```
+#[macro_export]
+macro_rules! check_matrix_is {
+    ($a:expr, $property:ident, $kind:ident = $tol:expr) => {
+        $crate::check_matrix_property(
+            $crate::shaped_elements!($a),
+            $crate::MatrixProperty::$property,
+            $crate::__tolerance!($kind, $tol),
+        )
+    };
+}
+
+/// Asserts that a matrix has a property (e.g. symmetry or orthogonality) to within a tolerance.
+///
+/// Every property except definiteness is checked element-wise, by comparing each element of the
+/// matrix (or of `A^T A` or `A^H A`, for orthogonality and unitarity) against the value the
+/// property requires (e.g. the element mirrored across the diagonal for symmetry, or `0` below the
+/// diagonal for upper triangularity) using the same tolerance semantics as the array macros (see
+/// [`Tolerance`](crate::Tolerance)). For positive (semi)definiteness, the matrix must first be
+/// symmetric (Hermitian, for complex matrices), and the smallest eigenvalue of its Hermitian part
+/// must then be larger than the threshold `t` (positive definiteness) or at least `-t` (positive
+/// semidefiniteness), where `t` is `atol`, `rtol` times the largest absolute eigenvalue,
+/// `1.5 * 10^(-decimal)`, or `ulps` machine epsilons times the largest absolute eigenvalue.
+///
+/// # Arguments
+///
+/// * `a` - Matrix (a 2D array-like struct implementing [`ArrayShape`](crate::ArrayShape), e.g. a
+///   nested [`Vec`] or array, or, with the `ndarray` and `nalgebra` features enabled, an `ndarray`
+///   array or a `nalgebra` matrix).
+/// * `property` - [`MatrixProperty`](crate::MatrixProperty) variant (`Symmetric`, `Hermitian`,
+///   `Orthogonal`, `Unitary`, `Identity`, `Diagonal`, `UpperTriangular`, `LowerTriangular`,
+///   `PositiveDefinite`, or `PositiveSemidefinite`).
+/// * `kind = tol` - Tolerance, where `kind` is one of `atol`, `rtol`, `decimal`, or `ulps` (see
+///   [`Tolerance`](crate::Tolerance)).
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If the matrix is not 2D, or not square for properties that require it (`Symmetric`,
+///   `Hermitian`, `Identity`, `PositiveDefinite`, and `PositiveSemidefinite`). In this case, the
+///   shape of the matrix is also printed.
+/// * If the matrix does not have the property to within the tolerance. For element-wise
+///   properties, the `(i, j)` index of the worst-violating element is printed, along with every
+///   element and the value the property requires, with the violating elements shown in bolded
+///   red. For definiteness, the smallest and largest eigenvalues are printed.
+///
+/// # Note
+///
+/// A relative tolerance only passes elements that are required to be `0` if they are exactly `0`
+/// (see [`Compare::is_equal_to_rtol`](crate::Compare::is_equal_to_rtol)), so an absolute tolerance
+/// is usually more appropriate for properties requiring zeros (e.g. `Orthogonal`, `Identity`,
+/// `Diagonal`, and the triangular properties).
+///
+/// # Examples
+///
+/// ```
+/// use numtest::*;
+///
+/// let c = 0.6;
+/// let s = 0.8;
+/// let q = vec![vec![c, -s], vec![s, c]];
+/// assert_matrix_is!(&q, Orthogonal, atol = 1e-15);
+///
+/// let l = [[2.0, 0.0, 0.0], [1.0, 3.0, 0.0], [4.0, 5.0, 6.0]];
+/// assert_matrix_is!(l, LowerTriangular, atol = 0.0);
+///
+/// let a = [[2.0, -1.0], [-1.0, 2.0]];
+/// assert_matrix_is!(a, PositiveDefinite, atol = 1e-12);
+/// ```
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let a = [[1.0, 2.0], [2.0, 1.0]];
+/// assert_matrix_is!(a, PositiveSemidefinite, atol = 1e-12, "Hessian at iteration {}", 3);
+/// ```
+#[macro_export]
+macro_rules! assert_matrix_is {
+    ($a:expr, $property:ident, $kind:ident = $tol:expr) => {{
+        if let Err(error) = $crate::check_matrix_is!($a, $property, $kind = $tol) {
+            panic!("{}", error);
+        }
+    }};
+    ($a:expr, $property:ident, $kind:ident = $tol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_matrix_is!($a, $property, $kind = $tol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Layout;
+    use crate::{ColorChoice, with_color_choice};
+
+    #[test]
+    fn test_from_elements() {
+        // Column-major elements of [[1, 2, 3], [4, 5, 6]].
+        let arr = ShapedElements {
+            layout: Some(Layout {
+                shape: vec![2, 3],
+                column_major: true,
+            }),
+            elements: vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0],
+            ragged: false,
+        };
+        let matrix = Matrix::from_elements(arr).unwrap();
+        assert_eq!((matrix.num_rows, matrix.num_cols), (2, 3));
+        assert_eq!(matrix.get(0, 2), 3.0);
+        assert_eq!(matrix.rows().nth(1), Some(&[4.0, 5.0, 6.0][..]));
+
+        // Array-like struct that is not 2D.
+        let arr = ShapedElements {
+            layout: None,
+            elements: vec![1.0, 2.0],
+            ragged: false,
+        };
+        assert_eq!(Matrix::from_elements(arr).err(), Some(vec![2]));
+
+        // Layout whose shape does not match the number of elements.
+        let arr = ShapedElements {
+            layout: Some(Layout {
+                shape: vec![2, 2],
+                column_major: false,
+            }),
+            elements: vec![1.0, 2.0, 3.0],
+            ragged: false,
+        };
+        assert_eq!(Matrix::from_elements(arr).err(), Some(vec![2, 2]));
+    }
+
+    #[test]
+    fn test_symmetric_eigenvalues() {
+        let mut eigenvalues = symmetric_eigenvalues(vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ]);
+        eigenvalues.sort_by(f64::total_cmp);
+        let sqrt_2 = 2.0_f64.sqrt();
+        for (eigenvalue, expected) in eigenvalues
+            .into_iter()
+            .zip([2.0 - sqrt_2, 2.0, 2.0 + sqrt_2])
+        {
+            assert!((eigenvalue - expected).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_matrix_is() {
+        let a = [[4.0, 1.0, 2.0], [1.0, 3.0, 0.5], [2.0, 0.5, 5.0]];
+        assert_matrix_is!(a, Symmetric, rtol = 0.0);
+        assert_matrix_is!(a, Hermitian, ulps = 0);
+        assert_matrix_is!(a, PositiveDefinite, atol = 1e-12);
+        assert_matrix_is!(a, PositiveSemidefinite, atol = 0.0);
+        assert!(check_matrix_is!(a, Diagonal, atol = 1e-12).is_err());
+
+        // Rectangular matrix with orthonormal columns.
+        let q = vec![vec![0.6, 0.0], vec![0.8, 0.0], vec![0.0, 1.0]];
+        assert_matrix_is!(&q, Orthogonal, atol = 1e-15);
+        assert_matrix_is!(&q, Unitary, atol = 1e-15);
+        let error = check_matrix_is!(&q, Symmetric, atol = 1e-15).unwrap_err();
+        assert_eq!(
+            error.message,
+            "\nA must be a square matrix.\n --> A shape: [3, 2]\n"
+        );
+
+        // Singular positive semidefinite matrix.
+        let a = [[1.0, 1.0], [1.0, 1.0]];
+        assert_matrix_is!(a, PositiveSemidefinite, atol = 1e-12);
+        assert!(check_matrix_is!(a, PositiveDefinite, atol = 1e-12).is_err());
+
+        // Not symmetric.
+        let error =
+            check_matrix_is!([[2.0, 1.0], [0.0, 2.0]], PositiveDefinite, atol = 1e-12).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![1, 2]);
+        assert_matrix_is!([[2.0, 1.0], [0.0, 2.0]], UpperTriangular, atol = 0.0);
+        assert_matrix_is!([[1.0_f32, 0.0], [0.0, 1.0]], Identity, decimal = 6);
+
+        // Ragged nested vector.
+        let ragged = vec![vec![1.0, 2.0], vec![3.0]];
+        let error = check_matrix_is!(&ragged, Symmetric, atol = 1e-12).unwrap_err();
+        assert_eq!(
+            error.message,
+            "\nA must be a 2D matrix.\n --> A shape: [3]\n"
+        );
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_matrix_is_complex() {
+        use num_complex::Complex;
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::new(1.0, 0.0);
+
+        // Hermitian positive definite matrix with eigenvalues 1 and 3.
+        let a = [[2.0 * one, i], [-i, 2.0 * one]];
+        assert_matrix_is!(a, Hermitian, atol = 1e-15);
+        assert_matrix_is!(a, PositiveDefinite, atol = 0.5);
+        assert!(check_matrix_is!(a, PositiveDefinite, atol = 1.5).is_err());
+        assert!(check_matrix_is!(a, Symmetric, atol = 1e-15).is_err());
+
+        // Unitary (but not orthogonal) matrix.
+        let s = std::f64::consts::FRAC_1_SQRT_2;
+        let u = [[s * one, s * i], [s * i, s * one]];
+        assert_matrix_is!(u, Unitary, atol = 1e-15);
+        assert!(check_matrix_is!(u, Orthogonal, atol = 1e-15).is_err());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe matrix is not upper triangular (atol = 0.000000000001).\n --> Mismatched Elements: 2/9\n --> Largest Absolute Difference: 0.00000002\n --> Worst Element: (2, 1)\n\n index      A  triu(A)  abs diff  rel diff  result\n------  -----  -------  --------  --------  ------\n(0, 0)    1e0      1e0         0         0    pass\n(0, 1)    2e0      2e0         0         0    pass\n(0, 2)    3e0      3e0         0         0    pass\n\u{1b}[31;1m(1, 0)   1e-9      0e0      1e-9         1    FAIL\u{1b}[0m\n(1, 1)    4e0      4e0         0         0    pass\n(1, 2)    5e0      5e0         0         0    pass\n(2, 0)    0e0      0e0         0         0    pass\n\u{1b}[31;1m(2, 1)  -2e-8      0e0      2e-8         1    FAIL\u{1b}[0m\n(2, 2)    6e0      6e0         0         0    pass\n"
+    )]
+    fn test_matrix_is_fail() {
+        let r = [[1.0, 2.0, 3.0], [1e-9, 4.0, 5.0], [0.0, -2e-8, 6.0]];
+        with_color_choice(ColorChoice::Always, || {
+            assert_matrix_is!(r, UpperTriangular, atol = 1e-12);
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\nThe matrix is not positive semidefinite (atol = 0.000000000001).\n --> Smallest Eigenvalue: -9.99999"
+    )]
+    fn test_matrix_is_definite_fail() {
+        let a = [[1.0, 2.0], [2.0, 1.0]];
+        assert_matrix_is!(a, PositiveSemidefinite, atol = 1e-12);
+    }
+}
@@ -0,0 +1,134 @@
+use std::fmt::Display;
+
+/// Vector norms used by the norm-based array assertion macros (e.g.
+/// [`assert_arrays_equal_in_norm`](crate::assert_arrays_equal_in_norm)).
+///
+/// All norms are computed over the flattened elements of the array-like structs (i.e. they are
+/// _entry-wise_ norms, so for matrices, [`Norm::L1`] and [`Norm::Linf`] are not the induced matrix
+/// norms).
+///
+/// # Example
+///
+/// ```
+/// use numtest::Norm;
+///
+/// let abs_values = [3.0, 4.0];
+/// assert_eq!(Norm::L1.compute(abs_values), 7.0);
+/// assert_eq!(Norm::L2.compute(abs_values), 5.0);
+/// assert_eq!(Norm::Linf.compute(abs_values), 4.0);
+/// assert_eq!(Norm::Frobenius.compute(abs_values), 5.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Norm {
+    /// L1 norm (sum of the absolute values).
+    L1,
+
+    /// L2 (Euclidean) norm (square root of the sum of the squared absolute values).
+    L2,
+
+    /// L∞ norm (largest absolute value).
+    Linf,
+
+    /// Frobenius norm (the L2 norm of the flattened elements of a matrix).
+    Frobenius,
+}
+
+impl Norm {
+    /// Computes the norm of a set of values given their absolute values (or moduli, for complex
+    /// numbers).
+    ///
+    /// # Arguments
+    ///
+    /// * `abs_values` - Absolute values.
+    ///
+    /// # Returns
+    ///
+    /// Norm of the values (`NaN` if any of the absolute values is `NaN`, and `0` if there are no
+    /// values).
+    #[must_use]
+    pub fn compute<I: IntoIterator<Item = f64>>(self, abs_values: I) -> f64 {
+        let abs_values = abs_values.into_iter();
+        match self {
+            Norm::L1 => abs_values.sum(),
+            Norm::L2 | Norm::Frobenius => {
+                // Accumulate the sum of squares scaled by the largest absolute value seen so far
+                // (as in LAPACK's dnrm2), so that it neither overflows nor underflows.
+                let (scale, sum_squares) =
+                    abs_values.fold((0.0_f64, 1.0_f64), |(scale, sum_squares), x| {
+                        if x.is_nan() || scale.is_nan() {
+                            (f64::NAN, sum_squares)
+                        } else if x == 0.0 || scale.is_infinite() {
+                            (scale, sum_squares)
+                        } else if x.is_infinite() {
+                            (f64::INFINITY, 1.0)
+                        } else if x > scale {
+                            (x, 1.0 + sum_squares * (scale / x).powi(2))
+                        } else {
+                            (scale, sum_squares + (x / scale).powi(2))
+                        }
+                    });
+                scale * sum_squares.sqrt()
+            }
+            Norm::Linf => abs_values.fold(0.0, |max: f64, x| {
+                if x.is_nan() || max.is_nan() {
+                    f64::NAN
+                } else {
+                    max.max(x)
+                }
+            }),
+        }
+    }
+}
+
+impl Display for Norm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Norm::L1 => "L1",
+            Norm::L2 => "L2",
+            Norm::Linf => "Linf",
+            Norm::Frobenius => "Frobenius",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_norms() {
+        let abs_values = [1.0, 2.0, 2.0];
+        assert_eq!(Norm::L1.compute(abs_values), 5.0);
+        assert_eq!(Norm::L2.compute(abs_values), 3.0);
+        assert_eq!(Norm::Linf.compute(abs_values), 2.0);
+        assert_eq!(Norm::Frobenius.compute(abs_values), 3.0);
+    }
+
+    #[test]
+    fn compute_norms_edge_cases() {
+        assert_eq!(Norm::L2.compute([]), 0.0);
+        assert_eq!(Norm::Linf.compute([]), 0.0);
+        assert!(Norm::Linf.compute([1.0, f64::NAN, 2.0]).is_nan());
+        assert!(Norm::L1.compute([1.0, f64::NAN]).is_nan());
+        assert_eq!(Norm::L2.compute([f64::INFINITY, 1.0]), f64::INFINITY);
+        assert_eq!(
+            Norm::L2.compute([1.0, f64::INFINITY, f64::INFINITY]),
+            f64::INFINITY
+        );
+        assert!(Norm::L2.compute([f64::INFINITY, f64::NAN]).is_nan());
+    }
+
+    #[test]
+    fn compute_l2_norm_without_overflow_or_underflow() {
+        assert!((Norm::L2.compute([3e200, 4e200]) / 5e200 - 1.0).abs() < 1e-15);
+        assert!((Norm::Frobenius.compute([3e-200, 4e-200]) / 5e-200 - 1.0).abs() < 1e-15);
+        assert_eq!(Norm::L2.compute([0.0, 0.0]), 0.0);
+    }
+
+    #[test]
+    fn display_norms() {
+        assert_eq!(Norm::L1.to_string(), "L1");
+        assert_eq!(Norm::Linf.to_string(), "Linf");
+        assert_eq!(Norm::Frobenius.to_string(), "Frobenius");
+    }
+}
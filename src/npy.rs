@@ -0,0 +1,742 @@
+use crate::shape::{ArrayShape, Layout};
+use std::fmt::Display;
+use std::io::Read;
+use std::path::Path;
+
+/// Magic string at the start of every `.npy` file.
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Error describing a `.npy` or `.npz` file that could not be loaded.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum NpyError {
+    /// The file could not be read.
+    Io(std::io::Error),
+
+    /// The `.npz` archive could not be read.
+    Zip(String),
+
+    /// The `.npz` archive does not contain the requested array.
+    MissingArray {
+        /// Name of the requested array.
+        name: String,
+        /// Names of the arrays stored in the archive.
+        available: Vec<String>,
+    },
+
+    /// The file is not a valid `.npy` file (e.g. its header is malformed or its data is
+    /// truncated).
+    InvalidFormat(String),
+
+    /// The data type stored in the file does not match the requested element type.
+    DtypeMismatch {
+        /// Data type of the requested element type (e.g. `f8`).
+        expected: &'static str,
+        /// Data type description stored in the file (e.g. `<f4`).
+        found: String,
+    },
+}
+
+impl Display for NpyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NpyError::Io(error) => write!(f, "failed to read file: {error}"),
+            NpyError::Zip(reason) => write!(f, "failed to read .npz archive: {reason}"),
+            NpyError::MissingArray { name, available } => write!(
+                f,
+                "array '{name}' not found in .npz archive (available arrays: {})",
+                available.join(", ")
+            ),
+            NpyError::InvalidFormat(reason) => write!(f, "invalid .npy file: {reason}"),
+            NpyError::DtypeMismatch { expected, found } => write!(
+                f,
+                "data type mismatch: expected '{expected}' (with any byte order), found '{found}'"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NpyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NpyError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for NpyError {
+    fn from(error: std::io::Error) -> Self {
+        NpyError::Io(error)
+    }
+}
+
+/// Element type that can be loaded from a `.npy` file.
+///
+/// This trait is implemented for [`f32`] (data type `f4`) and [`f64`] (`f8`), and for
+/// `num_complex::Complex<f32>` (`c8`) and `num_complex::Complex<f64>` (`c16`) when the
+/// `num-complex` feature is enabled. Both byte orders are supported.
+pub trait NpyElement: Copy {
+    /// Data type code of this element type, without the byte order (e.g. `f8`).
+    const DTYPE: &'static str;
+
+    /// Decodes an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Bytes of the element (of length `size_of::<Self>()`).
+    /// * `big_endian` - Whether the bytes are stored in big-endian (as opposed to little-endian)
+    ///   byte order.
+    ///
+    /// # Returns
+    ///
+    /// Decoded element.
+    fn from_npy_bytes(bytes: &[u8], big_endian: bool) -> Self;
+}
+
+/// Implements the [`NpyElement`] trait for floating-point types.
+macro_rules! impl_npy_element_float {
+    ($($t:ty => $dtype:literal),*) => {$(
+        impl NpyElement for $t {
+            const DTYPE: &'static str = $dtype;
+            fn from_npy_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let bytes = bytes.try_into().unwrap();
+                if big_endian {
+                    <$t>::from_be_bytes(bytes)
+                } else {
+                    <$t>::from_le_bytes(bytes)
+                }
+            }
+        }
+    )*};
+}
+impl_npy_element_float!(f32 => "f4", f64 => "f8");
+
+/// Implements the [`NpyElement`] trait for complex numbers (stored as the real part followed by
+/// the imaginary part).
+#[cfg(feature = "num-complex")]
+macro_rules! impl_npy_element_complex {
+    ($($t:ty => $dtype:literal),*) => {$(
+        impl NpyElement for num_complex::Complex<$t> {
+            const DTYPE: &'static str = $dtype;
+            fn from_npy_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let (re, im) = bytes.split_at(bytes.len() / 2);
+                num_complex::Complex::new(
+                    <$t>::from_npy_bytes(re, big_endian),
+                    <$t>::from_npy_bytes(im, big_endian),
+                )
+            }
+        }
+    )*};
+}
+#[cfg(feature = "num-complex")]
+impl_npy_element_complex!(f32 => "c8", f64 => "c16");
+
+/// Array loaded from a `.npy` file (or from an array stored in a `.npz` archive).
+///
+/// The elements are always stored in row-major (C) order, so arrays saved in Fortran order are
+/// transposed into row-major order when they are loaded. This struct implements
+/// [`ArrayShape`](crate::ArrayShape) and has an `iter` method, so it can be passed directly to the
+/// array assertion macros, which then also check its shape.
+///
+/// # Example
+///
+/// ```no_run
+/// use numtest::*;
+///
+/// let computed = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+/// let reference: NpyArray<f64> = read_npy("tests/data/reference.npy").unwrap();
+/// assert_arrays_equal_to_rtol!(&computed, &reference, 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpyArray<T> {
+    /// Shape of the array.
+    shape: Vec<usize>,
+
+    /// Elements of the array, in row-major order.
+    data: Vec<T>,
+}
+
+impl<T> NpyArray<T> {
+    /// Iterates over the elements of the array, in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// Iterator over the elements of the array.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Elements of the array, in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// Slice of the elements of the array.
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Converts the array into a vector of its elements, in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// Vector of the elements of the array.
+    #[must_use]
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Converts the array into an `ndarray` array with the same shape.
+    ///
+    /// # Returns
+    ///
+    /// Dynamic-dimensional `ndarray` array.
+    #[cfg(feature = "ndarray")]
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn into_ndarray(self) -> ndarray::ArrayD<T> {
+        // The shape of a loaded array always matches its number of elements.
+        ndarray::ArrayD::from_shape_vec(self.shape, self.data).unwrap()
+    }
+
+    /// Converts a 1D or 2D array into a `nalgebra` matrix (1D arrays are converted into column
+    /// vectors).
+    ///
+    /// # Returns
+    ///
+    /// Dynamically-sized `nalgebra` matrix (`None` if the array has more than 2 dimensions).
+    #[cfg(feature = "nalgebra")]
+    #[must_use]
+    pub fn to_dmatrix(&self) -> Option<nalgebra::DMatrix<T>>
+    where
+        T: nalgebra::Scalar,
+    {
+        let (nrows, ncols) = match self.shape[..] {
+            [] => (1, 1),
+            [len] => (len, 1),
+            [nrows, ncols] => (nrows, ncols),
+            _ => return None,
+        };
+        Some(nalgebra::DMatrix::from_row_slice(nrows, ncols, &self.data))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NpyArray<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+// Implementing ArrayShape trait for arrays loaded from .npy files.
+impl<T> ArrayShape for NpyArray<T> {
+    type Element = T;
+    fn shape(&self) -> Vec<usize> {
+        self.shape.clone()
+    }
+    fn elements(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+}
+
+/// Header of a `.npy` file.
+struct NpyHeader {
+    /// Data type description (e.g. `<f8`).
+    descr: String,
+
+    /// Whether the data is stored in Fortran (column-major) order.
+    fortran_order: bool,
+
+    /// Shape of the array.
+    shape: Vec<usize>,
+}
+
+/// Splits a Python literal at its top-level commas (ignoring commas nested in parentheses,
+/// brackets, or braces).
+///
+/// # Arguments
+///
+/// * `literal` - Python literal (without its enclosing delimiters).
+///
+/// # Returns
+///
+/// Trimmed, non-empty items of the literal.
+fn split_top_level(literal: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut depth = 0_i32;
+    let mut start = 0;
+    for (i, c) in literal.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(&literal[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&literal[start..]);
+    items
+        .into_iter()
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Parses the header of a `.npy` file (a Python dictionary literal).
+///
+/// # Arguments
+///
+/// * `header` - Header of the `.npy` file.
+///
+/// # Returns
+///
+/// Parsed header.
+///
+/// # Errors
+///
+/// If the header is malformed, or if it describes a structured data type.
+fn parse_header(header: &str) -> Result<NpyHeader, NpyError> {
+    let invalid = || NpyError::InvalidFormat(format!("malformed header: {}", header.trim()));
+    let body = header
+        .trim()
+        .strip_prefix('{')
+        .and_then(|body| body.strip_suffix('}'))
+        .ok_or_else(invalid)?;
+    let (mut descr, mut fortran_order, mut shape) = (None, None, None);
+    for item in split_top_level(body) {
+        let (key, value) = item.split_once(':').ok_or_else(invalid)?;
+        let value = value.trim();
+        match key.trim().trim_matches(|c| c == '\'' || c == '"') {
+            "descr" => {
+                if !value.starts_with(['\'', '"']) {
+                    return Err(NpyError::InvalidFormat(format!(
+                        "structured data types are not supported (descr: {value})"
+                    )));
+                }
+                descr = Some(value.trim_matches(|c| c == '\'' || c == '"').to_string());
+            }
+            "fortran_order" => {
+                fortran_order = Some(match value {
+                    "True" => true,
+                    "False" => false,
+                    _ => return Err(invalid()),
+                });
+            }
+            "shape" => {
+                let dims = value
+                    .strip_prefix('(')
+                    .and_then(|value| value.strip_suffix(')'))
+                    .ok_or_else(invalid)?;
+                shape = Some(
+                    split_top_level(dims)
+                        .into_iter()
+                        .map(|dim| dim.parse().map_err(|_| invalid()))
+                        .collect::<Result<_, _>>()?,
+                );
+            }
+            _ => {}
+        }
+    }
+    Ok(NpyHeader {
+        descr: descr.ok_or_else(invalid)?,
+        fortran_order: fortran_order.ok_or_else(invalid)?,
+        shape: shape.ok_or_else(invalid)?,
+    })
+}
+
+/// Validates the data type description of a `.npy` file against the requested element type.
+///
+/// # Arguments
+///
+/// * `descr` - Data type description stored in the file (e.g. `<f8`).
+///
+/// # Returns
+///
+/// `true` if the data is stored in big-endian byte order, `false` otherwise.
+///
+/// # Errors
+///
+/// If the data type does not match [`NpyElement::DTYPE`].
+fn validate_dtype<T: NpyElement>(descr: &str) -> Result<bool, NpyError> {
+    let mismatch = || NpyError::DtypeMismatch {
+        expected: T::DTYPE,
+        found: descr.to_string(),
+    };
+    let (order, dtype) = descr.split_at_checked(1).ok_or_else(mismatch)?;
+    if dtype != T::DTYPE {
+        return Err(mismatch());
+    }
+    match order {
+        "<" | "|" => Ok(false),
+        ">" => Ok(true),
+        "=" => Ok(cfg!(target_endian = "big")),
+        _ => Err(mismatch()),
+    }
+}
+
+/// Parses the contents of a `.npy` file.
+///
+/// # Arguments
+///
+/// * `bytes` - Contents of the `.npy` file.
+///
+/// # Returns
+///
+/// Array stored in the file (in row-major order).
+///
+/// # Errors
+///
+/// If the contents are not a valid `.npy` file, or if the stored data type does not match the
+/// requested element type.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let header = "{'descr': '<f8', 'fortran_order': False, 'shape': (2,), }";
+/// let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+/// bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+/// bytes.extend_from_slice(header.as_bytes());
+/// bytes.extend_from_slice(&1.5_f64.to_le_bytes());
+/// bytes.extend_from_slice(&2.5_f64.to_le_bytes());
+///
+/// let arr: NpyArray<f64> = parse_npy(&bytes).unwrap();
+/// assert_eq!(arr.shape(), vec![2]);
+/// assert_arrays_equal!(&arr, &[1.5, 2.5]);
+/// ```
+pub fn parse_npy<T: NpyElement>(bytes: &[u8]) -> Result<NpyArray<T>, NpyError> {
+    let truncated = || NpyError::InvalidFormat("file is truncated".to_string());
+
+    // Magic string and format version.
+    let rest = bytes
+        .strip_prefix(NPY_MAGIC)
+        .ok_or_else(|| NpyError::InvalidFormat("missing magic string".to_string()))?;
+    let (&[major, _minor], rest) = rest.split_first_chunk::<2>().ok_or_else(truncated)?;
+
+    // Header (its length is stored in 2 bytes for version 1.0, and in 4 bytes otherwise).
+    let (header_len, rest) = match major {
+        1 => {
+            let (len, rest) = rest.split_first_chunk::<2>().ok_or_else(truncated)?;
+            (usize::from(u16::from_le_bytes(*len)), rest)
+        }
+        2 | 3 => {
+            let (len, rest) = rest.split_first_chunk::<4>().ok_or_else(truncated)?;
+            let len = usize::try_from(u32::from_le_bytes(*len)).map_err(|_| truncated())?;
+            (len, rest)
+        }
+        _ => {
+            return Err(NpyError::InvalidFormat(format!(
+                "unsupported format version {major}"
+            )));
+        }
+    };
+    let (header, data) = rest.split_at_checked(header_len).ok_or_else(truncated)?;
+    let header = std::str::from_utf8(header)
+        .map_err(|_| NpyError::InvalidFormat("header is not valid text".to_string()))?;
+    let header = parse_header(header)?;
+    let big_endian = validate_dtype::<T>(&header.descr)?;
+
+    // Data.
+    let too_large = || NpyError::InvalidFormat(format!("shape {:?} is too large", header.shape));
+    let count = header
+        .shape
+        .iter()
+        .try_fold(1_usize, |count, &len| count.checked_mul(len))
+        .ok_or_else(too_large)?;
+    let size = std::mem::size_of::<T>();
+    if data.len() != count.checked_mul(size).ok_or_else(too_large)? {
+        return Err(NpyError::InvalidFormat(format!(
+            "expected {count} elements of type '{}', found {} bytes of data",
+            header.descr,
+            data.len()
+        )));
+    }
+    let elements: Vec<T> = data
+        .chunks_exact(size)
+        .map(|chunk| T::from_npy_bytes(chunk, big_endian))
+        .collect();
+
+    // Transpose data stored in Fortran order into row-major order.
+    let data = if header.fortran_order {
+        let layout = Layout {
+            shape: header.shape.clone(),
+            column_major: false,
+        };
+        (0..count)
+            .map(|position| {
+                let (fortran_position, _) = layout
+                    .multi_index(position)
+                    .iter()
+                    .zip(&header.shape)
+                    .fold((0, 1), |(fortran_position, stride), (&index, &len)| {
+                        (fortran_position + index * stride, stride * len)
+                    });
+                elements[fortran_position]
+            })
+            .collect()
+    } else {
+        elements
+    };
+    Ok(NpyArray {
+        shape: header.shape,
+        data,
+    })
+}
+
+/// Reads an array from a `.npy` file.
+///
+/// # Arguments
+///
+/// * `path` - Path of the `.npy` file.
+///
+/// # Returns
+///
+/// Array stored in the file (in row-major order).
+///
+/// # Errors
+///
+/// If the file cannot be read or is not a valid `.npy` file, or if the stored data type does not
+/// match the requested element type.
+///
+/// # Example
+///
+/// ```no_run
+/// use numtest::*;
+///
+/// let reference: NpyArray<f64> = read_npy("tests/data/reference.npy").unwrap();
+/// assert_eq!(reference.shape(), vec![3, 4]);
+/// ```
+pub fn read_npy<T: NpyElement>(path: impl AsRef<Path>) -> Result<NpyArray<T>, NpyError> {
+    parse_npy(&std::fs::read(path)?)
+}
+
+/// Reads an array from a `.npz` archive (as written by `numpy.savez` or
+/// `numpy.savez_compressed`).
+///
+/// # Arguments
+///
+/// * `path` - Path of the `.npz` archive.
+/// * `name` - Name of the array (the keyword used when saving it, e.g. `x` for
+///   `numpy.savez("data.npz", x=x)`, or `arr_0` for the first positional array).
+///
+/// # Returns
+///
+/// Array stored in the archive (in row-major order).
+///
+/// # Errors
+///
+/// If the archive cannot be read, if it does not contain the array, or if the array is not a
+/// valid `.npy` file or its data type does not match the requested element type.
+///
+/// # Example
+///
+/// ```no_run
+/// use numtest::*;
+///
+/// let computed = vec![0.0, 0.5, 1.0];
+/// let times: NpyArray<f64> = read_npz("tests/data/solution.npz", "t").unwrap();
+/// assert_arrays_equal_to_atol!(&computed, &times, 1e-12);
+/// ```
+pub fn read_npz<T: NpyElement>(
+    path: impl AsRef<Path>,
+    name: &str,
+) -> Result<NpyArray<T>, NpyError> {
+    let file = std::fs::File::open(path)?;
+    let mut archive =
+        zip::ZipArchive::new(file).map_err(|error| NpyError::Zip(error.to_string()))?;
+    let Some(index) = archive.index_for_name(&format!("{name}.npy")) else {
+        return Err(NpyError::MissingArray {
+            name: name.to_string(),
+            available: archive
+                .file_names()
+                .map(|file_name| file_name.trim_end_matches(".npy").to_string())
+                .collect(),
+        });
+    };
+    let mut bytes = Vec::new();
+    archive
+        .by_index(index)
+        .map_err(|error| NpyError::Zip(error.to_string()))?
+        .read_to_end(&mut bytes)?;
+    parse_npy(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    /// Contents of a version 1.0 `.npy` file.
+    fn npy_bytes(descr: &str, fortran_order: bool, shape: &str, data: &[u8]) -> Vec<u8> {
+        let fortran_order = if fortran_order { "True" } else { "False" };
+        let mut header =
+            format!("{{'descr': '{descr}', 'fortran_order': {fortran_order}, 'shape': {shape}, }}");
+        header.push_str(&" ".repeat(63 - (header.len() + 10) % 64));
+        header.push('\n');
+        let mut bytes = NPY_MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&u16::try_from(header.len()).unwrap().to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    /// Little-endian bytes of a sequence of `f64` values.
+    fn f64_bytes(values: &[f64]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn parse_npy_c_order() {
+        let bytes = npy_bytes(
+            "<f8",
+            false,
+            "(2, 3)",
+            &f64_bytes(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+        );
+        let arr: NpyArray<f64> = parse_npy(&bytes).unwrap();
+        assert_eq!(arr.shape(), vec![2, 3]);
+        assert_arrays_equal!(&arr, &[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    }
+
+    #[test]
+    fn parse_npy_fortran_order() {
+        let bytes = npy_bytes(
+            "<f8",
+            true,
+            "(2, 3)",
+            &f64_bytes(&[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]),
+        );
+        let arr: NpyArray<f64> = parse_npy(&bytes).unwrap();
+        assert_eq!(arr.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        // 3D arrays.
+        let data: Vec<f64> = (0..24).map(f64::from).collect();
+        let bytes = npy_bytes("<f8", true, "(2, 3, 4)", &f64_bytes(&data));
+        let arr: NpyArray<f64> = parse_npy(&bytes).unwrap();
+        assert_eq!(arr.as_slice()[1], 6.0);
+        assert_eq!(arr.as_slice()[4], 2.0);
+        assert_eq!(arr.as_slice()[12], 1.0);
+    }
+
+    #[cfg(all(feature = "ndarray", feature = "nalgebra"))]
+    #[test]
+    fn npy_array_conversions() {
+        let bytes = npy_bytes(
+            "<f8",
+            false,
+            "(2, 3)",
+            &f64_bytes(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+        );
+        let arr: NpyArray<f64> = parse_npy(&bytes).unwrap();
+        let mat = nalgebra::Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(arr.to_dmatrix().unwrap(), mat);
+        assert_eq!(
+            arr.into_ndarray(),
+            ndarray::arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).into_dyn()
+        );
+    }
+
+    #[test]
+    fn parse_npy_big_endian_f32() {
+        let data: Vec<u8> = [1.5_f32, -2.0]
+            .iter()
+            .flat_map(|v| v.to_be_bytes())
+            .collect();
+        let arr: NpyArray<f32> = parse_npy(&npy_bytes(">f4", false, "(2,)", &data)).unwrap();
+        assert_eq!(arr.into_vec(), vec![1.5, -2.0]);
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn parse_npy_complex() {
+        let bytes = npy_bytes("<c16", false, "(2,)", &f64_bytes(&[1.0, 2.0, 3.0, -4.0]));
+        let arr: NpyArray<num_complex::Complex<f64>> = parse_npy(&bytes).unwrap();
+        assert_eq!(
+            arr.as_slice(),
+            &[
+                num_complex::Complex::new(1.0, 2.0),
+                num_complex::Complex::new(3.0, -4.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_npy_errors() {
+        let data = f64_bytes(&[1.0, 2.0]);
+        let error = parse_npy::<f32>(&npy_bytes("<f8", false, "(2,)", &data)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "data type mismatch: expected 'f4' (with any byte order), found '<f8'"
+        );
+        let error = parse_npy::<f64>(&npy_bytes("<f8", false, "(3,)", &data)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid .npy file: expected 3 elements of type '<f8', found 16 bytes of data"
+        );
+        let error = parse_npy::<f64>(&npy_bytes("<i8", false, "(2,)", &data)).unwrap_err();
+        assert!(matches!(error, NpyError::DtypeMismatch { .. }));
+        let error = parse_npy::<f64>(b"not a npy file").unwrap_err();
+        assert_eq!(error.to_string(), "invalid .npy file: missing magic string");
+    }
+
+    #[test]
+    fn parse_npy_corrupt_shape() {
+        let data = f64_bytes(&[1.0, 2.0]);
+        let bytes = npy_bytes("<f8", false, "(4294967296, 4294967296, 16)", &data);
+        let error = parse_npy::<f64>(&bytes).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid .npy file: shape [4294967296, 4294967296, 16] is too large"
+        );
+        let bytes = npy_bytes("<f8", false, "(2305843009213693952,)", &data);
+        let error = parse_npy::<f64>(&bytes).unwrap_err();
+        assert!(matches!(error, NpyError::InvalidFormat(_)));
+    }
+
+    #[test]
+    fn parse_npy_shape_mismatch() {
+        let bytes = npy_bytes("<f8", false, "(3, 2)", &f64_bytes(&[1.0; 6]));
+        let arr: NpyArray<f64> = parse_npy(&bytes).unwrap();
+        let error = check_arrays_equal!(&[[1.0; 3]; 2], &arr).unwrap_err();
+        assert_eq!(
+            *error.kind,
+            ComparisonErrorKind::ShapeMismatch {
+                shape1: vec![2, 3],
+                shape2: vec![3, 2]
+            }
+        );
+    }
+
+    #[test]
+    fn read_npz_archive() {
+        use std::io::Write;
+
+        let path = std::env::temp_dir().join(format!("numtest-npz-{}.npz", std::process::id()));
+        let mut writer = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+        writer
+            .start_file("x.npy", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer
+            .write_all(&npy_bytes("<f8", false, "(2,)", &f64_bytes(&[0.5, 1.5])))
+            .unwrap();
+        writer.finish().unwrap();
+
+        let arr: NpyArray<f64> = read_npz(&path, "x").unwrap();
+        assert_arrays_equal_to_rtol!(&arr, &vec![0.5, 1.5], 1e-15);
+        let error = read_npz::<f64>(&path, "y").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "array 'y' not found in .npz archive (available arrays: x)"
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+}
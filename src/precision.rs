@@ -5,10 +5,85 @@
 ///
 /// * [Wikipedia: Single-precision floating-point format](https://en.wikipedia.org/wiki/Single-precision_floating-point_format)
 /// * [Wikipedia: Double-precision floating-point format](https://en.wikipedia.org/wiki/Double-precision_floating-point_format)
+/// * [Wikipedia: Half-precision floating-point format](https://en.wikipedia.org/wiki/Half-precision_floating-point_format)
+/// * [Wikipedia: bfloat16 floating-point format](https://en.wikipedia.org/wiki/Bfloat16_floating-point_format)
+///
+/// # Note
+///
+/// With the `half` feature enabled, this trait is also implemented for
+/// [`half::f16`](https://docs.rs/half/latest/half/struct.f16.html) and
+/// [`half::bf16`](https://docs.rs/half/latest/half/struct.bf16.html).
+///
+/// # Custom floating-point types
+///
+/// [`Compare`](crate::Compare) is implemented for every type that implements both this trait and
+/// [`num_traits::Float`](https://docs.rs/num-traits/latest/num_traits/float/trait.Float.html).
+/// Implementing this trait for a custom floating-point type that already implements `Float` (e.g.
+/// a newtype wrapping an [`f64`]) is therefore all that is needed to use it with the assertion
+/// macros, with the same `NaN` and `Inf` handling as for [`f32`] and [`f64`] (the failure messages
+/// additionally require [`Debug`](std::fmt::Debug), [`Display`](std::fmt::Display), and
+/// [`LowerExp`](std::fmt::LowerExp), which are hidden below).
+///
+/// ```
+/// use num_derive::{Float, Num, NumCast, NumOps, One, ToPrimitive, Zero};
+/// use numtest::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+/// #[derive(Float, Num, NumCast, NumOps, One, ToPrimitive, Zero)]
+/// struct Meters(f64);
+/// # impl std::ops::Neg for Meters {
+/// #     type Output = Self;
+/// #     fn neg(self) -> Self {
+/// #         Meters(-self.0)
+/// #     }
+/// # }
+/// # impl std::fmt::Display for Meters {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         write!(f, "{} m", self.0)
+/// #     }
+/// # }
+/// # impl std::fmt::LowerExp for Meters {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         std::fmt::LowerExp::fmt(&self.0, f)
+/// #     }
+/// # }
+///
+/// impl Precision for Meters {
+///     const MAX_DECIMAL: u32 = f64::MAX_DECIMAL;
+///     const NUM_BITS: u32 = f64::NUM_BITS;
+///     fn max_decimal(&self) -> u32 {
+///         self.0.max_decimal()
+///     }
+///     fn max_10_exp(&self) -> i32 {
+///         self.0.max_10_exp()
+///     }
+///     fn min_10_exp(&self) -> i32 {
+///         self.0.min_10_exp()
+///     }
+///     fn epsilon(&self) -> Self {
+///         Meters(f64::EPSILON)
+///     }
+///     fn to_raw_bits(&self) -> u64 {
+///         self.0.to_raw_bits()
+///     }
+/// }
+///
+/// // `Meters` now implements `Compare`, so it works with the assertion macros.
+/// assert!(Meters(1.0).is_equal_to_atol(Meters(1.0001), Meters(1e-3)).0);
+/// assert_equal_to_atol!(Meters(1.0), Meters(1.0001), Meters(1e-3));
+/// assert_arrays_equal_to_ulps!(
+///     &[Meters(1.0), Meters(f64::NAN)],
+///     &[Meters(1.0 + f64::EPSILON), Meters(f64::NAN)],
+///     1
+/// );
+/// ```
 pub trait Precision {
     /// Maximum number of guarenteed correct decimal places for a floating-point number.
     const MAX_DECIMAL: u32;
 
+    /// Number of bits used to store a floating-point number (including the sign bit).
+    const NUM_BITS: u32;
+
     /// Method that can be called on an instance of a floating-point type to return the maximum
     /// number of correct decimal places for that floating-point type.
     ///
@@ -42,12 +117,22 @@ pub trait Precision {
     /// Machine epsilon for the type of this floating-point variable.
     #[must_use]
     fn epsilon(&self) -> Self;
+
+    /// Method that can be called on an instance of a floating-point type to return its raw
+    /// IEEE 754 binary representation.
+    ///
+    /// # Returns
+    ///
+    /// Bit pattern of this floating-point variable, zero-extended to 64 bits (the sign bit is bit
+    /// [`Precision::NUM_BITS`]` - 1`).
+    fn to_raw_bits(&self) -> u64;
 }
 
 // Implementing Precision trait for f32's.
 // https://en.wikipedia.org/wiki/Single-precision_floating-point_format
 impl Precision for f32 {
     const MAX_DECIMAL: u32 = 7;
+    const NUM_BITS: u32 = 32;
     fn max_decimal(&self) -> u32 {
         f32::MAX_DECIMAL
     }
@@ -60,12 +145,16 @@ impl Precision for f32 {
     fn epsilon(&self) -> Self {
         f32::EPSILON
     }
+    fn to_raw_bits(&self) -> u64 {
+        u64::from(self.to_bits())
+    }
 }
 
 // Implementing Precision trait for f64's.
 // https://en.wikipedia.org/wiki/Double-precision_floating-point_format
 impl Precision for f64 {
     const MAX_DECIMAL: u32 = 15;
+    const NUM_BITS: u32 = 64;
     fn max_decimal(&self) -> u32 {
         f64::MAX_DECIMAL
     }
@@ -78,6 +167,55 @@ impl Precision for f64 {
     fn epsilon(&self) -> Self {
         f64::EPSILON
     }
+    fn to_raw_bits(&self) -> u64 {
+        self.to_bits()
+    }
+}
+
+// Implementing Precision trait for f16's.
+// https://en.wikipedia.org/wiki/Half-precision_floating-point_format
+#[cfg(feature = "half")]
+impl Precision for half::f16 {
+    const MAX_DECIMAL: u32 = 3;
+    const NUM_BITS: u32 = 16;
+    fn max_decimal(&self) -> u32 {
+        half::f16::MAX_DECIMAL
+    }
+    fn max_10_exp(&self) -> i32 {
+        half::f16::MAX_10_EXP
+    }
+    fn min_10_exp(&self) -> i32 {
+        half::f16::MIN_10_EXP
+    }
+    fn epsilon(&self) -> Self {
+        half::f16::EPSILON
+    }
+    fn to_raw_bits(&self) -> u64 {
+        u64::from(self.to_bits())
+    }
+}
+
+// Implementing Precision trait for bf16's.
+// https://en.wikipedia.org/wiki/Bfloat16_floating-point_format
+#[cfg(feature = "half")]
+impl Precision for half::bf16 {
+    const MAX_DECIMAL: u32 = 2;
+    const NUM_BITS: u32 = 16;
+    fn max_decimal(&self) -> u32 {
+        half::bf16::MAX_DECIMAL
+    }
+    fn max_10_exp(&self) -> i32 {
+        half::bf16::MAX_10_EXP
+    }
+    fn min_10_exp(&self) -> i32 {
+        half::bf16::MIN_10_EXP
+    }
+    fn epsilon(&self) -> Self {
+        half::bf16::EPSILON
+    }
+    fn to_raw_bits(&self) -> u64 {
+        u64::from(self.to_bits())
+    }
 }
 
 #[cfg(test)]
@@ -93,6 +231,8 @@ mod tests {
         assert_eq!(0.0_f32.min_10_exp(), f32::MIN_10_EXP);
         assert_eq!(0.0_f32.min_10_exp(), -37_i32);
         assert_eq!(0.0_f32.epsilon(), f32::EPSILON);
+        assert_eq!(f32::NUM_BITS, 32_u32);
+        assert_eq!(1.0_f32.to_raw_bits(), 0x3f80_0000);
     }
 
     #[test]
@@ -104,5 +244,33 @@ mod tests {
         assert_eq!(0.0_f64.min_10_exp(), f64::MIN_10_EXP);
         assert_eq!(0.0_f64.min_10_exp(), -307_i32);
         assert_eq!(0.0_f64.epsilon(), f64::EPSILON);
+        assert_eq!(f64::NUM_BITS, 64_u32);
+        assert_eq!(1.0_f64.to_raw_bits(), 0x3ff0_0000_0000_0000);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn precision_f16() {
+        use half::f16;
+        assert_eq!(f16::MAX_DECIMAL, 3_u32);
+        assert_eq!(f16::ZERO.max_decimal(), f16::MAX_DECIMAL);
+        assert_eq!(f16::ZERO.max_10_exp(), 4_i32);
+        assert_eq!(f16::ZERO.min_10_exp(), -4_i32);
+        assert_eq!(f16::ZERO.epsilon(), f16::EPSILON);
+        assert_eq!(f16::NUM_BITS, 16_u32);
+        assert_eq!(f16::ONE.to_raw_bits(), 0x3c00);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn precision_bf16() {
+        use half::bf16;
+        assert_eq!(bf16::MAX_DECIMAL, 2_u32);
+        assert_eq!(bf16::ZERO.max_decimal(), bf16::MAX_DECIMAL);
+        assert_eq!(bf16::ZERO.max_10_exp(), 38_i32);
+        assert_eq!(bf16::ZERO.min_10_exp(), -37_i32);
+        assert_eq!(bf16::ZERO.epsilon(), bf16::EPSILON);
+        assert_eq!(bf16::NUM_BITS, 16_u32);
+        assert_eq!(bf16::ONE.to_raw_bits(), 0x3f80);
     }
 }
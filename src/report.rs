@@ -0,0 +1,1547 @@
+use crate::approx::Tolerance;
+use crate::color::use_color;
+use crate::compare::{Compare, real_to_f64};
+use crate::limit::report_limit;
+use crate::shape::{Layout, ShapedElements, format_multi_index};
+use num_traits::{Float, Zero};
+use std::fmt::{Display, LowerExp, Write};
+use std::path::{Path, PathBuf};
+
+/// Summary of a successful comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonReport {
+    /// Number of pairs of elements that were compared (`1` when comparing two floats).
+    pub num_elements: usize,
+
+    /// Worst value of the comparison metric over all pairs of elements.
+    ///
+    /// This is the smallest decimal precision for decimal comparisons, the largest absolute
+    /// difference for absolute tolerance comparisons, the largest relative difference for relative
+    /// tolerance comparisons, the largest ULP distance for ULP comparisons, and the largest excess
+    /// outside the bounds for ordered comparisons (e.g.
+    /// [`Compare::is_less_than`](crate::Compare::is_less_than)). For exact and
+    /// [`Compare::is_close`](crate::Compare::is_close) comparisons, it is the largest absolute
+    /// difference.
+    pub worst_difference: f64,
+}
+
+/// Kind of failure represented by a [`ComparisonError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ComparisonErrorKind {
+    /// The two array-like structs do not have the same shape (see [`ArrayShape`](crate::ArrayShape)).
+    ShapeMismatch {
+        /// Shape of the first array-like struct.
+        shape1: Vec<usize>,
+        /// Shape of the second array-like struct.
+        shape2: Vec<usize>,
+    },
+
+    /// The two array-like structs do not have the same number of elements.
+    CountMismatch {
+        /// Number of elements in the first array-like struct.
+        count1: usize,
+        /// Number of elements in the second array-like struct.
+        count2: usize,
+    },
+
+    /// The number of per-element tolerances does not match the number of elements in the
+    /// array-like structs (see [`Tolerances`](crate::Tolerances)).
+    ToleranceCountMismatch {
+        /// Number of elements in each array-like struct.
+        num_elements: usize,
+        /// Number of tolerances.
+        num_tolerances: usize,
+    },
+
+    /// At least one pair of elements failed the comparison.
+    ValueMismatch,
+
+    /// A reference file could not be read, parsed, or written (e.g. a golden file, see
+    /// [`assert_matches_golden`](crate::assert_matches_golden)).
+    ReferenceFile {
+        /// Path of the reference file.
+        path: PathBuf,
+        /// Description of the problem.
+        reason: String,
+    },
+
+    /// An array-like struct does not have the shape required by the comparison (e.g. the matrix
+    /// of a linear system is not square, see [`assert_solves`](crate::assert_solves)).
+    InvalidShape {
+        /// Name of the array-like struct.
+        name: String,
+        /// Shape of the array-like struct (its number of elements if its shape is not known).
+        shape: Vec<usize>,
+        /// Description of the required shape.
+        expected: String,
+    },
+}
+
+/// A pair of elements that failed a comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch<T> {
+    /// Index of the pair of elements (in the order in which the array-like structs are iterated
+    /// over). This is always `0` when comparing two floats.
+    pub index: usize,
+
+    /// Multi-dimensional index of the pair of elements (`None` if neither array-like struct
+    /// implements [`ArrayShape`](crate::ArrayShape)).
+    pub multi_index: Option<Vec<usize>>,
+
+    /// Element of the first array-like struct (or the first float).
+    pub a: T,
+
+    /// Element of the second array-like struct (or the second float). For ordered comparisons
+    /// (e.g. [`Compare::is_in_range`](crate::Compare::is_in_range)), this is the violated bound.
+    pub b: T,
+
+    /// Value of the comparison metric for this pair of elements (see
+    /// [`ComparisonReport::worst_difference`]).
+    pub difference: f64,
+}
+
+/// Error describing a failed comparison.
+///
+/// The [`Display`] implementation of this error prints the exact message that the corresponding
+/// `assert_*` macro panics with.
+///
+/// The kind, mismatches, and table are boxed so that a `Result` with this error stays small.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonError<T> {
+    /// Kind of failure.
+    pub kind: Box<ComparisonErrorKind>,
+
+    /// Number of pairs of elements that were compared (`1` when comparing two floats, `0` if the
+    /// number of elements did not match).
+    pub num_elements: usize,
+
+    /// Pairs of elements that failed the comparison.
+    pub mismatches: Box<[Mismatch<T>]>,
+
+    /// Worst value of the comparison metric over all pairs of elements (see
+    /// [`ComparisonReport::worst_difference`]).
+    pub worst_difference: f64,
+
+    /// Full failure message.
+    pub message: String,
+
+    /// Table printing the two array-like structs, with the mismatched elements highlighted (empty
+    /// when comparing two floats, or when the number of elements did not match). For array-like
+    /// structs with more elements than the report limit (see
+    /// [`set_report_limit`](crate::set_report_limit)), only the first mismatched elements and the
+    /// worst one are printed.
+    pub table: Box<str>,
+}
+
+impl<T> ComparisonError<T> {
+    /// Indices of the pairs of elements that failed the comparison.
+    ///
+    /// # Returns
+    ///
+    /// Indices of the mismatched pairs of elements.
+    #[must_use]
+    pub fn mismatched_indices(&self) -> Vec<usize> {
+        self.mismatches
+            .iter()
+            .map(|mismatch| mismatch.index)
+            .collect()
+    }
+
+    /// Creates an error describing a failed comparison between two floats.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first float.
+    /// * `b` - The second float.
+    /// * `difference` - Value of the comparison metric.
+    /// * `message` - Failure message.
+    ///
+    /// # Returns
+    ///
+    /// Comparison error.
+    #[doc(hidden)]
+    pub fn scalar(a: T, b: T, difference: f64, message: String) -> Self {
+        Self {
+            kind: Box::new(ComparisonErrorKind::ValueMismatch),
+            num_elements: 1,
+            mismatches: vec![Mismatch {
+                index: 0,
+                multi_index: None,
+                a,
+                b,
+                difference,
+            }]
+            .into(),
+            worst_difference: difference,
+            message,
+            table: Box::default(),
+        }
+    }
+
+    /// Creates an error describing two array-like structs with different numbers of elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `count1` - Number of elements in the first array-like struct.
+    /// * `count2` - Number of elements in the second array-like struct.
+    ///
+    /// # Returns
+    ///
+    /// Comparison error.
+    fn count_mismatch(count1: usize, count2: usize) -> Self {
+        Self {
+            kind: Box::new(ComparisonErrorKind::CountMismatch { count1, count2 }),
+            num_elements: 0,
+            mismatches: Box::default(),
+            worst_difference: f64::NAN,
+            message: format!(
+                "\nThe two arrays must have the same number of elements.\n --> arr1 num elements: \
+                {count1}\n --> arr2 num elements: {count2}\n"
+            ),
+            table: Box::default(),
+        }
+    }
+
+    /// Creates an error describing per-element tolerances whose number does not match the number of
+    /// elements in the array-like structs.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_elements` - Number of elements in each array-like struct.
+    /// * `num_tolerances` - Number of tolerances.
+    ///
+    /// # Returns
+    ///
+    /// Comparison error.
+    fn tolerance_count_mismatch(num_elements: usize, num_tolerances: usize) -> Self {
+        Self {
+            kind: Box::new(ComparisonErrorKind::ToleranceCountMismatch {
+                num_elements,
+                num_tolerances,
+            }),
+            num_elements: 0,
+            mismatches: Box::default(),
+            worst_difference: f64::NAN,
+            message: format!(
+                "\nThe tolerances must have the same number of elements as the arrays.\n --> arr \
+                num elements: {num_elements}\n --> tolerances num elements: {num_tolerances}\n"
+            ),
+            table: Box::default(),
+        }
+    }
+
+    /// Creates an error describing two array-like structs with different shapes.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape1` - Shape of the first array-like struct.
+    /// * `shape2` - Shape of the second array-like struct.
+    ///
+    /// # Returns
+    ///
+    /// Comparison error.
+    fn shape_mismatch(shape1: Vec<usize>, shape2: Vec<usize>) -> Self {
+        Self {
+            message: format!(
+                "\nThe two arrays must have the same shape.\n --> arr1 shape: {shape1:?}\n --> arr2 \
+                shape: {shape2:?}\n"
+            ),
+            kind: Box::new(ComparisonErrorKind::ShapeMismatch { shape1, shape2 }),
+            num_elements: 0,
+            mismatches: Box::default(),
+            worst_difference: f64::NAN,
+            table: Box::default(),
+        }
+    }
+
+    /// Creates an error describing a ragged array-like struct (see
+    /// [`ArrayShape::is_ragged`](crate::ArrayShape::is_ragged)).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the array-like struct.
+    /// * `num_elements` - Number of elements in the array-like struct.
+    ///
+    /// # Returns
+    ///
+    /// Comparison error.
+    pub(crate) fn ragged(name: &str, num_elements: usize) -> Self {
+        Self::invalid_shape(
+            name,
+            vec![num_elements],
+            String::from("an array-like struct whose rows all have the same length"),
+        )
+    }
+
+    /// Creates an error describing a reference file that could not be read, parsed, or written.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the reference file.
+    /// * `reason` - Description of the problem.
+    ///
+    /// # Returns
+    ///
+    /// Comparison error.
+    pub(crate) fn reference_file(path: &Path, reason: String) -> Self {
+        Self {
+            message: format!(
+                "\nThe reference file could not be used.\n --> path: {}\n --> reason: {reason}\n",
+                path.display()
+            ),
+            kind: Box::new(ComparisonErrorKind::ReferenceFile {
+                path: path.to_path_buf(),
+                reason,
+            }),
+            num_elements: 0,
+            mismatches: Box::default(),
+            worst_difference: f64::NAN,
+            table: Box::default(),
+        }
+    }
+
+    /// Creates an error describing an array-like struct that does not have the shape required by
+    /// the comparison.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the array-like struct.
+    /// * `shape` - Shape of the array-like struct.
+    /// * `expected` - Description of the required shape (e.g. `a square matrix`).
+    ///
+    /// # Returns
+    ///
+    /// Comparison error.
+    pub(crate) fn invalid_shape(name: &str, shape: Vec<usize>, expected: String) -> Self {
+        Self {
+            message: format!("\n{name} must be {expected}.\n --> {name} shape: {shape:?}\n"),
+            kind: Box::new(ComparisonErrorKind::InvalidShape {
+                name: name.to_string(),
+                shape,
+                expected,
+            }),
+            num_elements: 0,
+            mismatches: Box::default(),
+            worst_difference: f64::NAN,
+            table: Box::default(),
+        }
+    }
+}
+
+impl<T> Display for ComparisonError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl<T: std::fmt::Debug> std::error::Error for ComparisonError<T> {}
+
+/// Element-wise comparison of two array-like structs that have the same number of elements.
+#[doc(hidden)]
+pub struct ElementComparison<T> {
+    /// Pairs of elements.
+    pairs: Vec<(T, T)>,
+
+    /// Result of the comparison for each pair of elements, along with the value of the comparison
+    /// metric.
+    results: Vec<(bool, f64)>,
+
+    /// Layout used to convert flat positions into multi-dimensional indices.
+    layout: Option<Layout>,
+
+    /// Tolerance used for each pair of elements (`None` if a single tolerance was used).
+    tolerances: Option<Vec<f64>>,
+
+    /// Column headers of the two array-like structs in the failure table.
+    labels: (&'static str, &'static str),
+
+    /// Column header of the comparison metric in the failure table (`None` to omit the column).
+    metric_label: Option<&'static str>,
+}
+
+/// Pairs of elements of two array-like structs, along with the layout used to convert flat
+/// positions into multi-dimensional indices.
+type PairedElements<T> = (Vec<(T, T)>, Option<Layout>);
+
+/// Checks that two array-like structs are not ragged, and have the same shape (when both shapes
+/// are known) and the same number of elements, and pairs up their elements (by their
+/// multi-dimensional indices when both layouts are known, and in iteration order otherwise).
+///
+/// # Arguments
+///
+/// * `arr1` - Elements of the first array-like struct.
+/// * `arr2` - Elements of the second array-like struct.
+///
+/// # Returns
+///
+/// Pairs of elements, along with the layout used to convert flat positions into
+/// multi-dimensional indices.
+///
+/// # Errors
+///
+/// If either array-like struct is ragged, or if the two array-like structs do not have the same
+/// shape (when both shapes are known) or the same number of elements.
+fn pair_elements<T>(
+    arr1: ShapedElements<T>,
+    arr2: ShapedElements<T>,
+) -> Result<PairedElements<T>, ComparisonError<T>> {
+    // Check that neither array is ragged.
+    for (name, arr) in [("arr1", &arr1), ("arr2", &arr2)] {
+        if arr.ragged {
+            return Err(ComparisonError::ragged(name, arr.elements.len()));
+        }
+    }
+    let ShapedElements {
+        layout: layout1,
+        elements: elements1,
+        ..
+    } = arr1;
+    let ShapedElements {
+        layout: layout2,
+        elements: elements2,
+        ..
+    } = arr2;
+
+    // Check that the two arrays have the same shape (if both shapes are known).
+    if let (Some(layout1), Some(layout2)) = (&layout1, &layout2)
+        && layout1.squeezed_shape() != layout2.squeezed_shape()
+    {
+        return Err(ComparisonError::shape_mismatch(
+            layout1.shape.clone(),
+            layout2.shape.clone(),
+        ));
+    }
+
+    // Check that the two arrays have the same number of elements.
+    if elements1.len() != elements2.len() {
+        return Err(ComparisonError::count_mismatch(
+            elements1.len(),
+            elements2.len(),
+        ));
+    }
+
+    // Match up the elements by their multi-dimensional indices if the two arrays iterate over
+    // their elements in different orders.
+    if let (Some(layout1), Some(layout2)) = (&layout1, &layout2)
+        && layout1.column_major != layout2.column_major
+    {
+        let elements1 = layout1.reorder_row_major(elements1);
+        let elements2 = layout2.reorder_row_major(elements2);
+        let layout = Layout {
+            shape: layout1.shape.clone(),
+            column_major: false,
+        };
+        return Ok((elements1.into_iter().zip(elements2).collect(), Some(layout)));
+    }
+
+    Ok((
+        elements1.into_iter().zip(elements2).collect(),
+        layout1.or(layout2),
+    ))
+}
+
+/// Compares two array-like structs element-wise.
+///
+/// # Arguments
+///
+/// * `arr1` - Elements of the first array-like struct (see [`shaped_elements`](crate::shaped_elements)).
+/// * `arr2` - Elements of the second array-like struct (see [`shaped_elements`](crate::shaped_elements)).
+/// * `compare` - Closure comparing a pair of elements, returning whether the comparison passed and
+///   the value of the comparison metric.
+///
+/// # Returns
+///
+/// Element-wise comparison.
+///
+/// # Errors
+///
+/// If the two array-like structs do not have the same shape (when both shapes are known) or the
+/// same number of elements.
+#[doc(hidden)]
+pub fn compare_elements<T, F>(
+    arr1: ShapedElements<T>,
+    arr2: ShapedElements<T>,
+    mut compare: F,
+) -> Result<ElementComparison<T>, ComparisonError<T>>
+where
+    T: Copy,
+    F: FnMut(T, T) -> (bool, f64),
+{
+    let (pairs, layout) = pair_elements(arr1, arr2)?;
+
+    // Compare each pair of elements.
+    let results = pairs.iter().map(|&(a, b)| compare(a, b)).collect();
+    Ok(ElementComparison {
+        pairs,
+        results,
+        layout,
+        tolerances: None,
+        labels: ("arr1", "arr2"),
+        metric_label: None,
+    })
+}
+
+/// Compares two array-like structs element-wise, using a separate tolerance for each pair of
+/// elements.
+///
+/// # Arguments
+///
+/// * `arr1` - Elements of the first array-like struct (see [`shaped_elements`](crate::shaped_elements)).
+/// * `arr2` - Elements of the second array-like struct (see [`shaped_elements`](crate::shaped_elements)).
+/// * `tolerances` - Tolerance for each pair of elements (see
+///   [`shaped_tolerances`](crate::shaped_tolerances)).
+/// * `compare` - Closure comparing a pair of elements to within a tolerance, returning whether the
+///   comparison passed and the value of the comparison metric.
+///
+/// # Returns
+///
+/// Element-wise comparison.
+///
+/// # Errors
+///
+/// If the two array-like structs do not have the same shape (when both shapes are known) or the
+/// same number of elements, if the tolerances do not have the same shape as the array-like
+/// structs (when all shapes are known), or if the number of tolerances does not match the number
+/// of elements.
+#[doc(hidden)]
+pub fn compare_elements_with_tolerances<T, R, F>(
+    arr1: ShapedElements<T>,
+    arr2: ShapedElements<T>,
+    tolerances: ShapedElements<R>,
+    mut compare: F,
+) -> Result<ElementComparison<T>, ComparisonError<T>>
+where
+    T: Copy,
+    R: Float,
+    F: FnMut(T, T, R) -> (bool, f64),
+{
+    let (mut pairs, mut layout) = pair_elements(arr1, arr2)?;
+    if tolerances.ragged {
+        return Err(ComparisonError::ragged(
+            "tolerances",
+            tolerances.elements.len(),
+        ));
+    }
+    let ShapedElements {
+        layout: tolerance_layout,
+        elements: mut tolerances,
+        ..
+    } = tolerances;
+
+    // Match up the tolerances with the pairs of elements by their multi-dimensional indices,
+    // reordering both into row-major order if they are iterated over in different orders.
+    if let Some(tolerance_layout) = tolerance_layout {
+        match &layout {
+            Some(pair_layout) => {
+                if pair_layout.squeezed_shape() != tolerance_layout.squeezed_shape() {
+                    return Err(ComparisonError::invalid_shape(
+                        "tolerances",
+                        tolerance_layout.shape,
+                        format!("an array-like struct with shape {:?}", pair_layout.shape),
+                    ));
+                }
+                if pair_layout.column_major != tolerance_layout.column_major {
+                    pairs = pair_layout.reorder_row_major(pairs);
+                    tolerances = tolerance_layout.reorder_row_major(tolerances);
+                    layout = Some(Layout {
+                        shape: pair_layout.shape.clone(),
+                        column_major: false,
+                    });
+                }
+            }
+            None => tolerances = tolerance_layout.reorder_row_major(tolerances),
+        }
+    }
+
+    // Check that there is exactly one tolerance for each pair of elements.
+    if tolerances.len() != pairs.len() {
+        return Err(ComparisonError::tolerance_count_mismatch(
+            pairs.len(),
+            tolerances.len(),
+        ));
+    }
+
+    // Compare each pair of elements.
+    let results = pairs
+        .iter()
+        .zip(&tolerances)
+        .map(|(&(a, b), &tol)| compare(a, b, tol))
+        .collect();
+    Ok(ElementComparison {
+        pairs,
+        results,
+        layout,
+        tolerances: Some(tolerances.into_iter().map(real_to_f64).collect()),
+        labels: ("arr1", "arr2"),
+        metric_label: None,
+    })
+}
+
+/// Compares two array-like structs element-wise to within a [`Tolerance`], and finishes the
+/// comparison.
+///
+/// # Arguments
+///
+/// * `arr1` - Elements of the first array-like struct.
+/// * `arr2` - Elements of the second array-like struct.
+/// * `tolerance` - Tolerance.
+/// * `labels` - Column headers of the two array-like structs in the failure table.
+/// * `header` - Closure that, given the number of mismatched elements, the total number of
+///   elements, and a line describing the worst value of the comparison metric (e.g.
+///   ` --> Largest Relative Difference: 0.1`), returns the header of the failure message.
+///
+/// # Returns
+///
+/// Report summarizing the comparison.
+///
+/// # Errors
+///
+/// If the two array-like structs do not have the same shape (when both shapes are known) or the
+/// same number of elements, or if any of the element-wise comparisons fail.
+pub(crate) fn compare_elements_to_tolerance<T, H>(
+    arr1: ShapedElements<T>,
+    arr2: ShapedElements<T>,
+    tolerance: Tolerance,
+    labels: (&'static str, &'static str),
+    header: H,
+) -> Result<ComparisonReport, ComparisonError<T>>
+where
+    T: Compare + Copy + LowerExp,
+    H: FnOnce(usize, usize, String) -> String,
+{
+    // Variable to track the worst value of the comparison metric.
+    let mut worst_difference = match tolerance {
+        Tolerance::Decimal(_) => f64::INFINITY,
+        _ => 0.0,
+    };
+
+    // Compare the two arrays element-wise.
+    let comparison = compare_elements(arr1, arr2, |a, b| {
+        let (passed, difference) = tolerance.compare(a, b);
+        worst_difference = match tolerance {
+            Tolerance::Decimal(_) => worst_difference.min(difference),
+            _ => worst_difference.max(difference),
+        };
+        (passed, difference)
+    })?
+    .with_labels(labels.0, labels.1);
+    let (comparison, worst_label) = match tolerance {
+        Tolerance::Atol(_) => (comparison, "Largest Absolute Difference"),
+        Tolerance::Rtol(_) => (comparison, "Largest Relative Difference"),
+        Tolerance::Decimal(_) => (
+            comparison.with_metric_column("decimal"),
+            "Maximum Decimal Places of Precision",
+        ),
+        Tolerance::Ulps(_) => (
+            comparison.with_metric_column("ulps"),
+            "Largest ULP Distance",
+        ),
+    };
+    comparison.finish(worst_difference, |num_mismatched, count| {
+        header(
+            num_mismatched,
+            count,
+            format!(" --> {worst_label}: {worst_difference}"),
+        )
+    })
+}
+
+/// Maps `NaN` to infinity, so that it is treated as larger than any other value when sorting.
+///
+/// # Arguments
+///
+/// * `value` - Value to map.
+///
+/// # Returns
+///
+/// Infinity if `value` is `NaN`, otherwise `value`.
+fn nan_as_largest(value: f64) -> f64 {
+    if value.is_nan() { f64::INFINITY } else { value }
+}
+
+/// Computes the absolute and relative difference between two elements.
+///
+/// # Arguments
+///
+/// * `a` - First element.
+/// * `b` - Second element.
+///
+/// # Returns
+///
+/// Tuple:
+///
+/// 1. Absolute difference (see [`Compare::is_equal_to_atol`]).
+/// 2. Relative difference (see [`Compare::is_equal_to_rtol`]).
+fn differences<T: Compare + Copy>(a: T, b: T) -> (f64, f64) {
+    let zero = T::Real::zero();
+    let (_, abs_diff) = a.is_equal_to_atol(b, zero);
+    let (_, rel_diff) = a.is_equal_to_rtol(b, zero);
+    (real_to_f64(abs_diff), real_to_f64(rel_diff))
+}
+
+impl<T: Compare + Copy + LowerExp> ElementComparison<T> {
+    /// Formats the index of a pair of elements for printing.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Flat position of the pair of elements.
+    ///
+    /// # Returns
+    ///
+    /// Multi-dimensional index (e.g. `(1, 2)`) if the compared array-like structs are
+    /// multi-dimensional, otherwise the flat position.
+    #[must_use]
+    pub fn format_index(&self, index: usize) -> String {
+        match &self.layout {
+            Some(layout) if layout.is_multi_dimensional() => {
+                format_multi_index(&layout.multi_index(index))
+            }
+            _ => index.to_string(),
+        }
+    }
+
+    /// Sets the column headers of the two array-like structs in the failure table (`arr1` and
+    /// `arr2` by default).
+    ///
+    /// # Arguments
+    ///
+    /// * `label1` - Column header of the first array-like struct.
+    /// * `label2` - Column header of the second array-like struct.
+    ///
+    /// # Returns
+    ///
+    /// Element-wise comparison with the new column headers.
+    #[must_use]
+    pub fn with_labels(mut self, label1: &'static str, label2: &'static str) -> Self {
+        self.labels = (label1, label2);
+        self
+    }
+
+    /// Adds a column printing the value of the comparison metric for each pair of elements to the
+    /// failure table.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Column header of the comparison metric.
+    ///
+    /// # Returns
+    ///
+    /// Element-wise comparison printing the comparison metric.
+    #[must_use]
+    pub fn with_metric_column(mut self, label: &'static str) -> Self {
+        self.metric_label = Some(label);
+        self
+    }
+
+    /// Marks the pairs of elements with the largest values of the comparison metric as failed
+    /// (treating `NaN` as larger than any other value), and all other pairs as passed. Pairs whose
+    /// metric is `0` are only marked as failed if every pair's metric is `0` (in which case only
+    /// the first pair is marked), so that at least one pair is always marked.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of pairs of elements to mark as failed.
+    ///
+    /// # Returns
+    ///
+    /// Element-wise comparison with the worst pairs of elements marked as failed.
+    #[must_use]
+    pub fn flag_worst(mut self, count: usize) -> Self {
+        let mut order: Vec<usize> = (0..self.results.len()).collect();
+        order.sort_by(|&i, &j| {
+            nan_as_largest(self.results[j].1).total_cmp(&nan_as_largest(self.results[i].1))
+        });
+        for (rank, index) in order.into_iter().enumerate() {
+            let difference = self.results[index].1;
+            self.results[index].0 = rank >= count || (rank > 0 && difference == 0.0);
+        }
+        self
+    }
+
+    /// Selects the rows of the failure table to print when the number of pairs of elements exceeds
+    /// the report limit (see [`set_report_limit`](crate::set_report_limit)), and summarizes the
+    /// differences of the mismatched pairs of elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `mismatches` - Mismatched pairs of elements.
+    /// * `differences` - Absolute and relative difference of each pair of elements.
+    /// * `limit` - Report limit.
+    ///
+    /// # Returns
+    ///
+    /// Tuple:
+    ///
+    /// 1. Positions of the first `limit` mismatched pairs of elements, along with the position of
+    ///    the mismatched pair of elements with the largest absolute difference.
+    /// 2. Summary statistics of the mismatched pairs of elements.
+    fn summarize(
+        &self,
+        mismatches: &[Mismatch<T>],
+        differences: &[(f64, f64)],
+        limit: usize,
+    ) -> (Vec<usize>, String) {
+        let (abs_diffs, rel_diffs): (Vec<f64>, Vec<f64>) = mismatches
+            .iter()
+            .map(|mismatch| differences[mismatch.index])
+            .unzip();
+        let worst = (0..mismatches.len())
+            .reduce(|worst, i| {
+                if nan_as_largest(abs_diffs[i]) > nan_as_largest(abs_diffs[worst]) {
+                    i
+                } else {
+                    worst
+                }
+            })
+            .unwrap_or_default();
+        let mut rows: Vec<usize> = mismatches
+            .iter()
+            .take(limit)
+            .map(|mismatch| mismatch.index)
+            .collect();
+        if !rows.contains(&mismatches[worst].index) {
+            rows.push(mismatches[worst].index);
+        }
+        let summary = summary_str(
+            &abs_diffs,
+            &rel_diffs,
+            &self.format_index(mismatches[worst].index),
+            rows.len(),
+        );
+        (rows, summary)
+    }
+
+    /// Finishes the element-wise comparison.
+    ///
+    /// # Arguments
+    ///
+    /// * `worst_difference` - Worst value of the comparison metric over all pairs of elements.
+    /// * `header` - Closure that, given the number of mismatched elements and the total number of
+    ///   elements, returns the header of the failure message.
+    ///
+    /// # Returns
+    ///
+    /// Report summarizing the comparison.
+    ///
+    /// # Errors
+    ///
+    /// If any of the element-wise comparisons failed.
+    pub fn finish<H>(
+        self,
+        worst_difference: f64,
+        header: H,
+    ) -> Result<ComparisonReport, ComparisonError<T>>
+    where
+        H: FnOnce(usize, usize) -> String,
+    {
+        let num_elements = self.pairs.len();
+
+        // Collect the mismatched pairs of elements.
+        let mismatches: Vec<Mismatch<T>> = self
+            .pairs
+            .iter()
+            .zip(&self.results)
+            .enumerate()
+            .filter(|(_, (_, (passed, _)))| !passed)
+            .map(|(index, (&(a, b), &(_, difference)))| Mismatch {
+                index,
+                multi_index: self.layout.as_ref().map(|layout| layout.multi_index(index)),
+                a,
+                b,
+                difference,
+            })
+            .collect();
+
+        // Comparison passed.
+        if mismatches.is_empty() {
+            return Ok(ComparisonReport {
+                num_elements,
+                worst_difference,
+            });
+        }
+
+        // Comparison failed. For large array-like structs, only the first mismatched elements
+        // (along with the worst one) are printed, and the differences of all mismatched elements
+        // are summarized.
+        let is_mismatched: Vec<bool> = self.results.iter().map(|(passed, _)| !passed).collect();
+        let differences: Vec<(f64, f64)> =
+            self.pairs.iter().map(|&(a, b)| differences(a, b)).collect();
+        let (rows, summary) = match report_limit().filter(|&limit| num_elements > limit) {
+            None => ((0..num_elements).collect(), String::new()),
+            Some(limit) => self.summarize(&mismatches, &differences, limit),
+        };
+        let indices: Vec<String> = rows.iter().map(|&row| self.format_index(row)).collect();
+        let metrics: Option<(&str, Vec<f64>)> = self.metric_label.map(|label| {
+            (
+                label,
+                self.results.iter().map(|&(_, metric)| metric).collect(),
+            )
+        });
+        let table = MismatchTable {
+            pairs: &self.pairs,
+            is_mismatched: &is_mismatched,
+            rows: &rows,
+            indices: &indices,
+            labels: self.labels,
+            tolerances: self.tolerances.as_deref(),
+            differences: &differences,
+            metrics: metrics
+                .as_ref()
+                .map(|(label, metrics)| (*label, metrics.as_slice())),
+        }
+        .render(use_color());
+        let message = format!(
+            "{}\n\n{}{}",
+            header(mismatches.len(), num_elements),
+            table,
+            summary
+        );
+        Err(ComparisonError {
+            kind: Box::new(ComparisonErrorKind::ValueMismatch),
+            num_elements,
+            mismatches: mismatches.into(),
+            worst_difference,
+            message,
+            table: table.into(),
+        })
+    }
+}
+
+/// Column of the table printing the mismatched elements.
+struct Column<'a> {
+    /// Column header.
+    header: &'a str,
+
+    /// Formatted value of each printed row.
+    cells: Vec<String>,
+}
+
+impl Column<'_> {
+    /// Width of the column (the length of its longest cell or header).
+    ///
+    /// # Returns
+    ///
+    /// Width of the column.
+    fn width(&self) -> usize {
+        self.cells
+            .iter()
+            .map(String::len)
+            .chain(std::iter::once(self.header.len()))
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// Table printing the mismatched elements between two array-like structs.
+struct MismatchTable<'a, T> {
+    /// Pairs of elements of the two array-like structs.
+    pairs: &'a [(T, T)],
+
+    /// Whether each pair of elements is mismatched.
+    is_mismatched: &'a [bool],
+
+    /// Positions of the pairs of elements to print (in increasing order). Runs of pairs that are
+    /// not printed are elided with `...`.
+    rows: &'a [usize],
+
+    /// Index of each printed pair of elements.
+    indices: &'a [String],
+
+    /// Column headers of the two array-like structs.
+    labels: (&'a str, &'a str),
+
+    /// Tolerance used for each pair of elements (`None` to omit the tolerance column).
+    tolerances: Option<&'a [f64]>,
+
+    /// Absolute and relative difference of each pair of elements.
+    differences: &'a [(f64, f64)],
+
+    /// Column header and value of the comparison metric for each pair of elements, printed instead
+    /// of the relative difference (`None` to print the relative difference).
+    metrics: Option<(&'a str, &'a [f64])>,
+}
+
+/// Formats a difference or comparison metric for printing.
+///
+/// # Arguments
+///
+/// * `value` - Difference or comparison metric.
+///
+/// # Returns
+///
+/// Value formatted as an integer if it is a (not too large) whole number, otherwise formatted in
+/// scientific notation to (at most) 4 significant figures.
+pub(crate) fn format_metric(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        return format!("{value}");
+    }
+    let formatted = format!("{value:.3e}");
+    match formatted.split_once('e') {
+        Some((mantissa, exponent)) if mantissa.contains('.') => {
+            let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+            format!("{mantissa}e{exponent}")
+        }
+        _ => formatted,
+    }
+}
+
+impl<T: LowerExp> MismatchTable<'_, T> {
+    /// Formats the columns of the table.
+    ///
+    /// # Returns
+    ///
+    /// Columns of the table.
+    fn columns(&self) -> Vec<Column<'_>> {
+        // Formats a value of each printed row. The values are formatted up front, since not every
+        // float type honors the width flag in its LowerExp implementation (e.g. half::f16).
+        let format_rows = |value: &dyn Fn(usize) -> String| -> Vec<String> {
+            self.rows.iter().map(|&row| value(row)).collect()
+        };
+        let mut columns = vec![
+            Column {
+                header: "index",
+                cells: self.indices.to_vec(),
+            },
+            Column {
+                header: self.labels.0,
+                cells: format_rows(&|row| format!("{:e}", self.pairs[row].0)),
+            },
+            Column {
+                header: self.labels.1,
+                cells: format_rows(&|row| format!("{:e}", self.pairs[row].1)),
+            },
+        ];
+        if let Some(tolerances) = self.tolerances {
+            columns.push(Column {
+                header: "tol",
+                cells: format_rows(&|row| format!("{:e}", tolerances[row])),
+            });
+        }
+        columns.push(Column {
+            header: "abs diff",
+            cells: format_rows(&|row| format_metric(self.differences[row].0)),
+        });
+        columns.push(match self.metrics {
+            Some((header, metrics)) => Column {
+                header,
+                cells: format_rows(&|row| format_metric(metrics[row])),
+            },
+            None => Column {
+                header: "rel diff",
+                cells: format_rows(&|row| format_metric(self.differences[row].1)),
+            },
+        });
+        columns.push(Column {
+            header: "result",
+            cells: format_rows(&|row| {
+                if self.is_mismatched[row] {
+                    "FAIL"
+                } else {
+                    "pass"
+                }
+                .to_string()
+            }),
+        });
+        columns
+    }
+
+    /// Renders the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - `true` to also show the mismatched rows in bolded red.
+    ///
+    /// # Returns
+    ///
+    /// Table printing the two array-like structs, with the mismatched elements highlighted.
+    fn render(&self, color: bool) -> String {
+        let columns = self.columns();
+        let widths: Vec<usize> = columns.iter().map(Column::width).collect();
+
+        // Formats a line of the table, given the contents of each cell.
+        let line = |cell: &dyn Fn(usize) -> String| -> String {
+            widths
+                .iter()
+                .enumerate()
+                .map(|(column, &width)| format!("{:>width$}", cell(column)))
+                .collect::<Vec<String>>()
+                .join("  ")
+        };
+
+        // Add the header.
+        let mut output = String::new();
+        let _ = writeln!(
+            output,
+            "{}",
+            line(&|column| columns[column].header.to_string())
+        );
+        let _ = writeln!(output, "{}", line(&|column| "-".repeat(widths[column])));
+
+        // Iterate over the printed rows.
+        let ellipsis = line(&|_| "...".to_string());
+        let mut next_row = 0;
+        for (position, &row) in self.rows.iter().enumerate() {
+            // Elide the rows that are not printed.
+            if row != next_row {
+                let _ = writeln!(output, "{ellipsis}");
+            }
+            next_row = row + 1;
+
+            // Define ANSI escape codes for red and bold text.
+            let (red_bold_start, reset) = if color && self.is_mismatched[row] {
+                ("\x1b[31;1m", "\x1b[0m")
+            } else {
+                ("", "")
+            };
+
+            // Append formatted output to the string.
+            let cells = line(&|column| columns[column].cells[position].clone());
+            let _ = writeln!(output, "{red_bold_start}{cells}{reset}");
+        }
+        if next_row != self.pairs.len() {
+            let _ = writeln!(output, "{ellipsis}");
+        }
+        output
+    }
+}
+
+/// Renders a plain table (without highlighted rows), laid out like the tables printing the
+/// mismatched elements.
+///
+/// # Arguments
+///
+/// * `columns` - Header and cells of each column (every column must have the same number of
+///   cells).
+///
+/// # Returns
+///
+/// Table with right-aligned columns.
+pub(crate) fn plain_table(columns: &[(&str, Vec<String>)]) -> String {
+    let columns: Vec<Column<'_>> = columns
+        .iter()
+        .map(|(header, cells)| Column {
+            header,
+            cells: cells.clone(),
+        })
+        .collect();
+    let widths: Vec<usize> = columns.iter().map(Column::width).collect();
+    let num_rows = columns.first().map_or(0, |column| column.cells.len());
+
+    // Formats a line of the table, given the contents of each cell.
+    let line = |cell: &dyn Fn(usize) -> String| -> String {
+        widths
+            .iter()
+            .enumerate()
+            .map(|(column, &width)| format!("{:>width$}", cell(column)))
+            .collect::<Vec<String>>()
+            .join("  ")
+    };
+
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "{}",
+        line(&|column| columns[column].header.to_string())
+    );
+    let _ = writeln!(output, "{}", line(&|column| "-".repeat(widths[column])));
+    for row in 0..num_rows {
+        let _ = writeln!(
+            output,
+            "{}",
+            line(&|column| columns[column].cells[row].clone())
+        );
+    }
+    output
+}
+
+/// Get a string that can be used to print the mismatched elements between two array-like structs
+/// (see [`get_mismatched_elements_str`](crate::get_mismatched_elements_str)).
+///
+/// # Arguments
+///
+/// * `pairs` - Pairs of elements of the two array-like structs.
+/// * `is_mismatched` - Whether each pair of elements is mismatched.
+///
+/// # Returns
+///
+/// Table printing the two array-like structs, with the mismatched elements highlighted.
+#[doc(hidden)]
+pub fn mismatched_elements_str<T: Compare + Copy + LowerExp>(
+    pairs: &[(T, T)],
+    is_mismatched: &[bool],
+) -> String {
+    let rows: Vec<usize> = (0..pairs.len()).collect();
+    let indices: Vec<String> = rows.iter().map(usize::to_string).collect();
+    let differences: Vec<(f64, f64)> = pairs.iter().map(|&(a, b)| differences(a, b)).collect();
+    MismatchTable {
+        pairs,
+        is_mismatched,
+        rows: &rows,
+        indices: &indices,
+        labels: ("arr1", "arr2"),
+        tolerances: None,
+        differences: &differences,
+        metrics: None,
+    }
+    .render(use_color())
+}
+
+/// Get a string summarizing the absolute and relative differences of the mismatched elements.
+///
+/// # Arguments
+///
+/// * `abs_diffs` - Absolute difference of each mismatched pair of elements.
+/// * `rel_diffs` - Relative difference of each mismatched pair of elements.
+/// * `worst_index` - Index of the mismatched pair of elements with the largest absolute
+///   difference.
+/// * `num_shown` - Number of mismatched pairs of elements printed in the table.
+///
+/// # Returns
+///
+/// Summary statistics of the mismatched elements.
+fn summary_str(
+    abs_diffs: &[f64],
+    rel_diffs: &[f64],
+    worst_index: &str,
+    num_shown: usize,
+) -> String {
+    // Computes the maximum, mean, and median of a set of values (treating NaN as larger than any
+    // other value).
+    let stats = |values: &[f64]| {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let max = if sorted.iter().any(|value| value.is_nan()) {
+            f64::NAN
+        } else {
+            sorted.last().copied().unwrap_or(f64::NAN)
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let mean = sorted.iter().sum::<f64>() / sorted.len() as f64;
+        let middle = sorted.len() / 2;
+        let median = match sorted.len() {
+            0 => f64::NAN,
+            len if len % 2 == 0 => f64::midpoint(sorted[middle - 1], sorted[middle]),
+            _ => sorted[middle],
+        };
+        (max, mean, median)
+    };
+    let (max_abs, mean_abs, median_abs) = stats(abs_diffs);
+    let (max_rel, mean_rel, median_rel) = stats(rel_diffs);
+    format!(
+        "\nSummary of the {} mismatched elements ({num_shown} shown):\n --> Max Abs Diff: \
+        {max_abs}\n --> Mean Abs Diff: {mean_abs}\n --> Median Abs Diff: {median_abs}\n --> Max \
+        Rel Diff: {max_rel}\n --> Mean Rel Diff: {mean_rel}\n --> Median Rel Diff: {median_rel}\n \
+        --> Worst Element (Largest Abs Diff): {worst_index}\n",
+        abs_diffs.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorChoice, with_color_choice};
+
+    fn flat(elements: &[f64]) -> ShapedElements<f64> {
+        ShapedElements {
+            layout: None,
+            elements: elements.to_vec(),
+            ragged: false,
+        }
+    }
+
+    fn shaped(shape: &[usize], elements: &[f64]) -> ShapedElements<f64> {
+        ShapedElements {
+            layout: Some(Layout {
+                shape: shape.to_vec(),
+                column_major: false,
+            }),
+            elements: elements.to_vec(),
+            ragged: false,
+        }
+    }
+
+    #[test]
+    fn compare_elements_count_mismatch() {
+        let error = compare_elements(flat(&[1.0, 2.0]), flat(&[1.0]), |a, b| (a == b, 0.0))
+            .err()
+            .unwrap();
+        assert_eq!(
+            *error.kind,
+            ComparisonErrorKind::CountMismatch {
+                count1: 2,
+                count2: 1
+            }
+        );
+        assert!(error.mismatches.is_empty());
+        assert!(error.table.is_empty());
+        assert_eq!(
+            error.to_string(),
+            "\nThe two arrays must have the same number of elements.\n --> arr1 num elements: 2\n \
+            --> arr2 num elements: 1\n"
+        );
+    }
+
+    #[test]
+    fn compare_elements_pass() {
+        let report = compare_elements(flat(&[1.0, 2.0]), flat(&[1.0, 2.5]), |a, b| {
+            (true, (a - b).abs())
+        })
+        .unwrap()
+        .finish(0.5, |_, _| String::new())
+        .unwrap();
+        assert_eq!(
+            report,
+            ComparisonReport {
+                num_elements: 2,
+                worst_difference: 0.5
+            }
+        );
+    }
+
+    #[test]
+    fn compare_elements_fail() {
+        let error = compare_elements(flat(&[1.0, 2.0, 3.0]), flat(&[1.0, 2.5, 4.0]), |a, b| {
+            (a == b, (a - b).abs())
+        })
+        .unwrap()
+        .finish(1.0, |num_mismatched, count| {
+            format!("{num_mismatched}/{count} mismatched")
+        })
+        .err()
+        .unwrap();
+        assert_eq!(*error.kind, ComparisonErrorKind::ValueMismatch);
+        assert_eq!(error.num_elements, 3);
+        assert_eq!(error.mismatched_indices(), vec![1, 2]);
+        assert_eq!(
+            error.mismatches[1],
+            Mismatch {
+                index: 2,
+                multi_index: None,
+                a: 3.0,
+                b: 4.0,
+                difference: 1.0
+            }
+        );
+        assert!(error.message.starts_with("2/3 mismatched\n\n"));
+        assert!(error.message.ends_with(&*error.table));
+    }
+
+    #[test]
+    fn compare_elements_shape_mismatch() {
+        let error = compare_elements(
+            shaped(&[2, 3], &[0.0; 6]),
+            shaped(&[3, 2], &[0.0; 6]),
+            |a, b| (a == b, 0.0),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            *error.kind,
+            ComparisonErrorKind::ShapeMismatch {
+                shape1: vec![2, 3],
+                shape2: vec![3, 2]
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "\nThe two arrays must have the same shape.\n --> arr1 shape: [2, 3]\n --> arr2 shape: \
+            [3, 2]\n"
+        );
+    }
+
+    #[test]
+    fn compare_elements_shape_squeezed() {
+        assert!(
+            compare_elements(
+                shaped(&[3, 1], &[0.0; 3]),
+                shaped(&[3], &[0.0; 3]),
+                |a, b| { (a == b, 0.0) }
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn compare_elements_mixed_layouts() {
+        let column_major = ShapedElements {
+            layout: Some(Layout {
+                shape: vec![2, 3],
+                column_major: true,
+            }),
+            elements: vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0],
+            ragged: false,
+        };
+        let error = compare_elements(
+            column_major,
+            shaped(&[2, 3], &[1.0, 2.0, 3.0, 4.0, 5.0, 6.5]),
+            |a, b| (a == b, (a - b).abs()),
+        )
+        .unwrap()
+        .finish(0.5, |_, _| String::new())
+        .err()
+        .unwrap();
+        assert_eq!(error.mismatched_indices(), vec![5]);
+        assert_eq!(error.mismatches[0].multi_index, Some(vec![1, 2]));
+    }
+
+    #[test]
+    fn compare_elements_multi_index() {
+        let error = with_color_choice(ColorChoice::Always, || {
+            compare_elements(
+                shaped(&[2, 2], &[1.0, 2.0, 3.0, 4.0]),
+                flat(&[1.0, 2.0, 3.5, 4.0]),
+                |a, b| (a == b, (a - b).abs()),
+            )
+            .unwrap()
+            .finish(0.5, |_, _| String::new())
+            .unwrap_err()
+        });
+        assert_eq!(error.mismatches[0].index, 2);
+        assert_eq!(error.mismatches[0].multi_index, Some(vec![1, 0]));
+        assert_eq!(
+            &*error.table,
+            concat!(
+                " index  arr1   arr2  abs diff  rel diff  result\n",
+                "------  ----  -----  --------  --------  ------\n",
+                "(0, 0)   1e0    1e0         0         0    pass\n",
+                "(0, 1)   2e0    2e0         0         0    pass\n",
+                "\x1b[31;1m(1, 0)   3e0  3.5e0      5e-1  1.429e-1    FAIL\x1b[0m\n",
+                "(1, 1)   4e0    4e0         0         0    pass\n",
+            )
+        );
+    }
+
+    #[test]
+    fn flag_worst_marks_largest_differences() {
+        let comparison = compare_elements(
+            flat(&[1.0, 2.0, 3.0, 4.0]),
+            flat(&[1.5, 2.0, f64::NAN, 4.1]),
+            |a: f64, b: f64| (true, (a - b).abs()),
+        )
+        .unwrap()
+        .flag_worst(2);
+        let error = comparison.finish(0.0, |_, _| String::new()).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![0, 2]);
+
+        // Pairs with no difference are not flagged (unless all pairs have no difference).
+        let comparison = compare_elements(flat(&[1.0, 2.0]), flat(&[1.0, 2.5]), |a, b| {
+            (true, (a - b).abs())
+        })
+        .unwrap()
+        .flag_worst(5);
+        let error = comparison.finish(0.0, |_, _| String::new()).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![1]);
+        let comparison = compare_elements(flat(&[1.0, 2.0]), flat(&[1.0, 2.0]), |a, b| {
+            (true, (a - b).abs())
+        })
+        .unwrap()
+        .flag_worst(5);
+        let error = comparison.finish(0.0, |_, _| String::new()).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![0]);
+    }
+
+    #[test]
+    fn mismatched_elements_str_without_color() {
+        let table = MismatchTable {
+            pairs: &[(1.0, 1.0), (2.0, 2.5)],
+            is_mismatched: &[false, true],
+            rows: &[0, 1],
+            indices: &["(0, 0)".to_string(), "(0, 1)".to_string()],
+            labels: ("arr1", "arr2"),
+            tolerances: None,
+            differences: &[(0.0, 0.0), (0.5, 0.2)],
+            metrics: None,
+        }
+        .render(false);
+        assert_eq!(
+            table,
+            concat!(
+                " index  arr1   arr2  abs diff  rel diff  result\n",
+                "------  ----  -----  --------  --------  ------\n",
+                "(0, 0)   1e0    1e0         0         0    pass\n",
+                "(0, 1)   2e0  2.5e0      5e-1      2e-1    FAIL\n",
+            )
+        );
+    }
+
+    #[test]
+    fn mismatched_elements_str_elided_rows() {
+        let table = MismatchTable {
+            pairs: &[(1.0, 1.0), (2.0, 2.5), (3.0, 3.0), (4.0, 4.0), (5.0, 6.0)],
+            is_mismatched: &[false, true, false, false, true],
+            rows: &[1, 4],
+            indices: &["1".to_string(), "4".to_string()],
+            labels: ("arr1", "arr2"),
+            tolerances: None,
+            differences: &[
+                (0.0, 0.0),
+                (0.5, 0.2),
+                (0.0, 0.0),
+                (0.0, 0.0),
+                (1.0, 1.0 / 6.0),
+            ],
+            metrics: Some(("decimal", &[17.0, 0.0, 17.0, 17.0, -1.0])),
+        }
+        .render(false);
+        assert_eq!(
+            table,
+            concat!(
+                "index  arr1   arr2  abs diff  decimal  result\n",
+                "-----  ----  -----  --------  -------  ------\n",
+                "  ...   ...    ...       ...      ...     ...\n",
+                "    1   2e0  2.5e0      5e-1        0    FAIL\n",
+                "  ...   ...    ...       ...      ...     ...\n",
+                "    4   5e0    6e0         1       -1    FAIL\n",
+            )
+        );
+    }
+
+    #[test]
+    fn summary_statistics() {
+        let summary = summary_str(&[1.0, 4.0, 2.0, 3.0], &[0.5, 0.25, 1.0, 0.5], "(1, 2)", 2);
+        assert_eq!(
+            summary,
+            concat!(
+                "\nSummary of the 4 mismatched elements (2 shown):\n",
+                " --> Max Abs Diff: 4\n",
+                " --> Mean Abs Diff: 2.5\n",
+                " --> Median Abs Diff: 2.5\n",
+                " --> Max Rel Diff: 1\n",
+                " --> Mean Rel Diff: 0.5625\n",
+                " --> Median Rel Diff: 0.5\n",
+                " --> Worst Element (Largest Abs Diff): (1, 2)\n",
+            )
+        );
+    }
+
+    #[test]
+    fn summarize_selects_first_and_worst_mismatches() {
+        let elements1: Vec<f64> = (0..10).map(f64::from).collect();
+        let mut elements2 = elements1.clone();
+        elements2[2] += 1.0;
+        elements2[3] += 1.0;
+        elements2[5] += 1.0;
+        elements2[8] = f64::NAN;
+        let comparison = compare_elements(flat(&elements1), flat(&elements2), |a, b| {
+            (a == b, (a - b).abs())
+        })
+        .unwrap();
+        let mismatches = comparison
+            .finish(0.0, |_, _| String::new())
+            .unwrap_err()
+            .mismatches;
+        let comparison =
+            compare_elements(flat(&elements1), flat(&elements2), |a, b| (a == b, 0.0)).unwrap();
+        let differences: Vec<(f64, f64)> = elements1
+            .iter()
+            .zip(&elements2)
+            .map(|(&a, &b)| differences(a, b))
+            .collect();
+        let (rows, summary) = comparison.summarize(&mismatches, &differences, 2);
+        assert_eq!(rows, vec![2, 3, 8]);
+        assert!(summary.starts_with("\nSummary of the 4 mismatched elements (3 shown):\n"));
+        assert!(summary.contains(" --> Max Abs Diff: NaN\n"));
+        assert!(summary.ends_with(" --> Worst Element (Largest Abs Diff): 8\n"));
+    }
+}
@@ -0,0 +1,495 @@
+/// Trait for array-like structs that know their own shape.
+///
+/// The array assertion macros use this trait (when it is implemented) to reject comparisons
+/// between array-like structs of different shapes, and to print mismatched elements using their
+/// multi-dimensional indices (e.g. `(row, col)` or `[i, j, k]`) instead of their flat positions.
+/// Array-like structs that do not implement this trait are still compared element-wise, using
+/// only their number of elements.
+///
+/// This trait is implemented for:
+///
+/// * `[[T; N]; M]` (shape `[M, N]`)
+/// * `Vec<Vec<T>>` (shape `[rows, cols]`, where all rows must have the same length; see
+///   [`ArrayShape::is_ragged`])
+/// * [`ndarray::ArrayBase`](https://docs.rs/ndarray/latest/ndarray/struct.ArrayBase.html)
+///   (requires the `ndarray` feature)
+/// * [`nalgebra::Matrix`](https://docs.rs/nalgebra/latest/nalgebra/base/struct.Matrix.html)
+///   (requires the `nalgebra` feature)
+///
+/// # Note
+///
+/// Two shapes are considered to be the same if they are equal after removing all dimensions of
+/// length 1. This way, a 1D `ndarray` array can still be compared against a `nalgebra` column
+/// vector (which is a matrix with a single column).
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let arr = [[1.1, 2.2, 3.3], [4.4, 5.5, 6.6]];
+/// assert_eq!(arr.shape(), vec![2, 3]);
+/// assert!(!arr.is_column_major());
+/// assert_eq!(arr.elements().count(), 6);
+/// ```
+pub trait ArrayShape {
+    /// Type of the elements of the array-like struct.
+    type Element;
+
+    /// Shape of the array-like struct (i.e. the number of elements along each dimension).
+    ///
+    /// # Returns
+    ///
+    /// Shape of the array-like struct.
+    fn shape(&self) -> Vec<usize>;
+
+    /// Determines whether [`ArrayShape::elements`] iterates over the elements in column-major
+    /// order (as opposed to row-major order).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the elements are iterated over in column-major order, `false` otherwise.
+    fn is_column_major(&self) -> bool {
+        false
+    }
+
+    /// Determines whether the array-like struct is ragged (i.e. its rows do not all have the same
+    /// length), in which case [`ArrayShape::shape`] does not describe it. The array assertion
+    /// macros reject ragged array-like structs.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the array-like struct is ragged, `false` otherwise.
+    fn is_ragged(&self) -> bool {
+        false
+    }
+
+    /// Iterates over all elements of the array-like struct.
+    ///
+    /// # Returns
+    ///
+    /// Iterator over all elements of the array-like struct, in row-major order (or column-major
+    /// order if [`ArrayShape::is_column_major`] returns `true`).
+    fn elements(&self) -> impl Iterator<Item = &Self::Element>;
+}
+
+impl<A: ArrayShape + ?Sized> ArrayShape for &A {
+    type Element = A::Element;
+    fn shape(&self) -> Vec<usize> {
+        (**self).shape()
+    }
+    fn is_column_major(&self) -> bool {
+        (**self).is_column_major()
+    }
+    fn is_ragged(&self) -> bool {
+        (**self).is_ragged()
+    }
+    fn elements(&self) -> impl Iterator<Item = &Self::Element> {
+        (**self).elements()
+    }
+}
+
+// Implementing ArrayShape trait for nested std arrays.
+impl<T, const N: usize, const M: usize> ArrayShape for [[T; N]; M] {
+    type Element = T;
+    fn shape(&self) -> Vec<usize> {
+        vec![M, N]
+    }
+    fn elements(&self) -> impl Iterator<Item = &T> {
+        self.iter().flatten()
+    }
+}
+
+// Implementing ArrayShape trait for nested std vectors.
+impl<T> ArrayShape for Vec<Vec<T>> {
+    type Element = T;
+    fn shape(&self) -> Vec<usize> {
+        vec![self.len(), self.first().map_or(0, Vec::len)]
+    }
+    fn is_ragged(&self) -> bool {
+        self.windows(2).any(|rows| rows[0].len() != rows[1].len())
+    }
+    fn elements(&self) -> impl Iterator<Item = &T> {
+        self.iter().flatten()
+    }
+}
+
+// Implementing ArrayShape trait for ndarray arrays.
+#[cfg(feature = "ndarray")]
+impl<S, D> ArrayShape for ndarray::ArrayBase<S, D>
+where
+    S: ndarray::Data,
+    D: ndarray::Dimension,
+{
+    type Element = S::Elem;
+    fn shape(&self) -> Vec<usize> {
+        ndarray::ArrayBase::shape(self).to_vec()
+    }
+    fn elements(&self) -> impl Iterator<Item = &S::Elem> {
+        self.iter()
+    }
+}
+
+// Implementing ArrayShape trait for nalgebra matrices.
+#[cfg(feature = "nalgebra")]
+impl<T, R, C, S> ArrayShape for nalgebra::Matrix<T, R, C, S>
+where
+    T: nalgebra::Scalar,
+    R: nalgebra::Dim,
+    C: nalgebra::Dim,
+    S: nalgebra::RawStorage<T, R, C>,
+{
+    type Element = T;
+    fn shape(&self) -> Vec<usize> {
+        vec![self.nrows(), self.ncols()]
+    }
+    fn is_column_major(&self) -> bool {
+        true
+    }
+    fn elements(&self) -> impl Iterator<Item = &T> {
+        self.iter()
+    }
+}
+
+/// Shape of an array-like struct, along with the order in which its elements are iterated over.
+#[doc(hidden)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    /// Shape of the array-like struct.
+    pub shape: Vec<usize>,
+
+    /// Whether the elements are iterated over in column-major order.
+    pub column_major: bool,
+}
+
+impl Layout {
+    /// Shape with all dimensions of length 1 removed.
+    ///
+    /// # Returns
+    ///
+    /// Squeezed shape.
+    pub(crate) fn squeezed_shape(&self) -> Vec<usize> {
+        self.shape.iter().copied().filter(|&len| len != 1).collect()
+    }
+
+    /// Determines whether the array-like struct has more than one dimension of length greater
+    /// than 1.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the array-like struct is multi-dimensional, `false` otherwise.
+    pub(crate) fn is_multi_dimensional(&self) -> bool {
+        self.squeezed_shape().len() > 1
+    }
+
+    /// Converts a flat position into a multi-dimensional index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Flat position of the element (in iteration order).
+    ///
+    /// # Returns
+    ///
+    /// Multi-dimensional index of the element.
+    pub(crate) fn multi_index(&self, mut index: usize) -> Vec<usize> {
+        let mut multi_index = vec![0; self.shape.len()];
+        let dims: Box<dyn Iterator<Item = usize>> = if self.column_major {
+            Box::new(0..self.shape.len())
+        } else {
+            Box::new((0..self.shape.len()).rev())
+        };
+        for dim in dims {
+            let len = self.shape[dim].max(1);
+            multi_index[dim] = index % len;
+            index /= len;
+        }
+        multi_index
+    }
+
+    /// Reorders elements from iteration order into row-major order.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Elements of the array-like struct, in iteration order.
+    ///
+    /// # Returns
+    ///
+    /// Elements of the array-like struct, in row-major order.
+    pub(crate) fn reorder_row_major<T>(&self, elements: Vec<T>) -> Vec<T> {
+        if !self.column_major {
+            return elements;
+        }
+        let mut indexed: Vec<(usize, T)> = elements
+            .into_iter()
+            .enumerate()
+            .map(|(index, element)| {
+                let offset = self
+                    .multi_index(index)
+                    .iter()
+                    .zip(&self.shape)
+                    .fold(0, |offset, (&i, &len)| offset * len + i);
+                (offset, element)
+            })
+            .collect();
+        indexed.sort_by_key(|&(offset, _)| offset);
+        indexed.into_iter().map(|(_, element)| element).collect()
+    }
+}
+
+/// Formats a multi-dimensional index as `(row, col)` for 2D array-like structs and as
+/// `[i, j, k, ...]` otherwise.
+///
+/// # Arguments
+///
+/// * `multi_index` - Multi-dimensional index.
+///
+/// # Returns
+///
+/// Formatted multi-dimensional index.
+pub(crate) fn format_multi_index(multi_index: &[usize]) -> String {
+    let joined = multi_index
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join(", ");
+    if multi_index.len() == 2 {
+        format!("({joined})")
+    } else {
+        format!("[{joined}]")
+    }
+}
+
+/// Elements of an array-like struct, along with its layout (if known).
+#[doc(hidden)]
+pub struct ShapedElements<T> {
+    /// Layout of the array-like struct (`None` if it does not implement [`ArrayShape`] or is
+    /// ragged).
+    pub layout: Option<Layout>,
+
+    /// Elements of the array-like struct, in iteration order.
+    pub elements: Vec<T>,
+
+    /// Whether the array-like struct is ragged (see [`ArrayShape::is_ragged`]), or otherwise has a
+    /// shape that does not account for every element.
+    pub ragged: bool,
+}
+
+impl<T: Copy> ShapedElements<T> {
+    /// Collects the elements of an array-like struct that implements [`ArrayShape`], along with
+    /// its layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - Array-like struct.
+    ///
+    /// # Returns
+    ///
+    /// Elements of the array-like struct, along with its layout.
+    pub(crate) fn from_array_shape<A>(arr: &A) -> Self
+    where
+        A: ArrayShape<Element = T> + ?Sized,
+    {
+        let shape = arr.shape();
+        let elements: Vec<T> = arr.elements().copied().collect();
+
+        // The shape is invalid if it does not account for every element (e.g. ragged vectors).
+        let ragged = arr.is_ragged() || shape.iter().product::<usize>() != elements.len();
+        let layout = (!ragged).then(|| Layout {
+            shape,
+            column_major: arr.is_column_major(),
+        });
+        Self {
+            layout,
+            elements,
+            ragged,
+        }
+    }
+}
+
+/// Wrapper used by the array assertion macros to collect the elements of an array-like struct,
+/// using [`ArrayShape`] if it is implemented and the struct's `iter` method otherwise.
+#[doc(hidden)]
+pub struct ShapeProbe<'a, A: ?Sized>(pub &'a A);
+
+/// Collects elements using [`ArrayShape`] (selected by autoref-based specialization).
+#[doc(hidden)]
+pub trait ViaArrayShape<T> {
+    /// Collects the elements of the array-like struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `fallback` - Closure returning an iterator over the elements (unused).
+    ///
+    /// # Returns
+    ///
+    /// Elements of the array-like struct, along with its layout.
+    fn numtest_elements<F, I>(&self, fallback: F) -> ShapedElements<T>
+    where
+        F: FnOnce() -> I;
+}
+
+impl<A> ViaArrayShape<A::Element> for &ShapeProbe<'_, A>
+where
+    A: ArrayShape + ?Sized,
+    A::Element: Copy,
+{
+    fn numtest_elements<F, I>(&self, _fallback: F) -> ShapedElements<A::Element>
+    where
+        F: FnOnce() -> I,
+    {
+        ShapedElements::from_array_shape(self.0)
+    }
+}
+
+/// Collects elements using an iterator (fallback for structs that do not implement
+/// [`ArrayShape`]).
+#[doc(hidden)]
+pub trait ViaIter<T> {
+    /// Collects the elements of the array-like struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `fallback` - Closure returning an iterator over the elements.
+    ///
+    /// # Returns
+    ///
+    /// Elements of the array-like struct (without a layout).
+    fn numtest_elements<'b, F, I>(&self, fallback: F) -> ShapedElements<T>
+    where
+        F: FnOnce() -> I,
+        I: Iterator<Item = &'b T>,
+        T: Copy + 'b;
+}
+
+impl<A: ?Sized, T> ViaIter<T> for ShapeProbe<'_, A> {
+    fn numtest_elements<'b, F, I>(&self, fallback: F) -> ShapedElements<T>
+    where
+        F: FnOnce() -> I,
+        I: Iterator<Item = &'b T>,
+        T: Copy + 'b,
+    {
+        ShapedElements {
+            layout: None,
+            elements: fallback().copied().collect(),
+            ragged: false,
+        }
+    }
+}
+
+/// Collects the elements of an array-like struct (along with its layout, if the struct implements
+/// [`ArrayShape`]).
+///
+/// # Arguments
+///
+/// * `arr` - Array-like struct. Must either implement [`ArrayShape`] or have an `iter` method.
+///
+/// # Returns
+///
+/// [`ShapedElements`] holding the elements of the array-like struct.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! shaped_elements {
+    ($arr:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::{ViaArrayShape as _, ViaIter as _};
+        let arr = &$arr;
+        (&&$crate::ShapeProbe(arr)).numtest_elements(|| arr.iter())
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_multi_index_row_major() {
+        let layout = Layout {
+            shape: vec![2, 3, 4],
+            column_major: false,
+        };
+        assert_eq!(layout.multi_index(0), vec![0, 0, 0]);
+        assert_eq!(layout.multi_index(5), vec![0, 1, 1]);
+        assert_eq!(layout.multi_index(23), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn layout_multi_index_column_major() {
+        let layout = Layout {
+            shape: vec![3, 2],
+            column_major: true,
+        };
+        assert_eq!(layout.multi_index(1), vec![1, 0]);
+        assert_eq!(layout.multi_index(4), vec![1, 1]);
+    }
+
+    #[test]
+    fn layout_reorder_row_major() {
+        let layout = Layout {
+            shape: vec![2, 3],
+            column_major: true,
+        };
+        assert_eq!(
+            layout.reorder_row_major(vec![1, 4, 2, 5, 3, 6]),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        let layout = Layout {
+            shape: vec![2, 3],
+            column_major: false,
+        };
+        assert_eq!(layout.reorder_row_major(vec![1, 2, 3]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn layout_squeezed_shape() {
+        let layout = Layout {
+            shape: vec![3, 1],
+            column_major: true,
+        };
+        assert_eq!(layout.squeezed_shape(), vec![3]);
+    }
+
+    #[test]
+    fn format_multi_index_2d_and_3d() {
+        assert_eq!(format_multi_index(&[1, 2]), "(1, 2)");
+        assert_eq!(format_multi_index(&[1, 2, 3]), "[1, 2, 3]");
+        assert_eq!(format_multi_index(&[4]), "[4]");
+    }
+
+    #[test]
+    fn shaped_elements_nested() {
+        let nested = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+        let shaped = shaped_elements!(&nested);
+        assert_eq!(
+            shaped.layout,
+            Some(Layout {
+                shape: vec![2, 2],
+                column_major: false
+            })
+        );
+        assert_eq!(shaped.elements, vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn shaped_elements_flat() {
+        let flat = vec![1.0, 2.0, 3.0];
+        let shaped = shaped_elements!(&flat);
+        assert_eq!(shaped.layout, None);
+        assert_eq!(shaped.elements, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn shaped_elements_ragged() {
+        let ragged = vec![vec![1.0, 2.0], vec![3.0]];
+        let shaped = shaped_elements!(&ragged);
+        assert_eq!(shaped.layout, None);
+        assert_eq!(shaped.elements, vec![1.0, 2.0, 3.0]);
+        assert!(shaped.ragged);
+
+        // Ragged rows whose lengths still multiply out to the number of elements.
+        let ragged = vec![vec![1.0, 2.0], vec![3.0], vec![4.0, 5.0, 6.0]];
+        assert!(ragged.is_ragged());
+        assert!(shaped_elements!(&ragged).ragged);
+        assert!(!shaped_elements!(&vec![vec![1.0], vec![2.0]]).ragged);
+        assert!(!shaped_elements!(&[1.0, 2.0]).ragged);
+    }
+}
@@ -0,0 +1,713 @@
+use crate::compare::Compare;
+use crate::report::{ComparisonError, ComparisonReport};
+use std::fmt::{Debug, Display, LowerExp, Write};
+
+/// Collector of "soft" assertions, which records every failing comparison instead of panicking at
+/// the first one.
+///
+/// Each method mirrors one of the assertion macros (e.g. [`SoftAssert::rtol`] mirrors
+/// [`assert_equal_to_rtol`](crate::assert_equal_to_rtol)) and records a labelled failure if the
+/// comparison fails. Once all comparisons have been made, [`SoftAssert::finish`] panics with a
+/// consolidated report of all failures (using the same messages as the assertion macros). If a
+/// [`SoftAssert`] with recorded failures is dropped without calling [`SoftAssert::finish`], it
+/// panics on drop instead.
+///
+/// # Note
+///
+/// The array methods (e.g. [`SoftAssert::arrays_atol`]) accept anything that can be iterated over
+/// by reference (e.g. `&Vec<f64>`, `&[f64; 3]`, `&Array1<f64>`, or `&Matrix3<f64>`) and compare
+/// the elements in iteration order, without shape checks. For shape-aware comparisons, pass the
+/// result of the corresponding `check_*` macro to [`SoftAssert::record`] instead.
+///
+/// # Example
+///
+/// ```should_panic
+/// use numtest::*;
+///
+/// let position = [1.0, 2.0, 3.0];
+/// let attitude = [0.1, 0.2, 0.3];
+///
+/// let mut soft = SoftAssert::new();
+/// soft.rtol("energy", 100.0, 100.5, 1e-3)
+///     .arrays_atol("position", &position, &[1.0, 2.0, 3.1], 1e-2)
+///     .record(
+///         "attitude",
+///         check_arrays_equal_to_atol!(&attitude, &[0.1, 0.2, 0.3], 1e-6),
+///     );
+///
+/// // Panics, reporting both the "energy" and "position" failures.
+/// soft.finish();
+/// ```
+#[derive(Debug, Default)]
+pub struct SoftAssert {
+    /// Total number of comparisons made.
+    num_checks: usize,
+
+    /// Label and failure message of each failed comparison.
+    failures: Vec<(String, String)>,
+}
+
+impl SoftAssert {
+    /// Creates a new, empty collector.
+    ///
+    /// # Returns
+    ///
+    /// Collector with no recorded comparisons.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the result of a comparison (e.g. the result of one of the `check_*` macros).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `result` - Result of the comparison.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn record<T>(
+        &mut self,
+        label: impl Into<String>,
+        result: Result<ComparisonReport, ComparisonError<T>>,
+    ) -> &mut Self {
+        self.num_checks += 1;
+        if let Err(error) = result {
+            self.failures.push((label.into(), error.message));
+        }
+        self
+    }
+
+    /// Number of failed comparisons recorded so far.
+    ///
+    /// # Returns
+    ///
+    /// Number of failed comparisons.
+    #[must_use]
+    pub fn num_failures(&self) -> usize {
+        self.failures.len()
+    }
+
+    /// Consolidated report of all failed comparisons.
+    ///
+    /// # Returns
+    ///
+    /// Report listing the label and failure message of each failed comparison (`None` if no
+    /// comparison failed).
+    #[must_use]
+    pub fn report(&self) -> Option<String> {
+        if self.failures.is_empty() {
+            return None;
+        }
+        let mut report = format!(
+            "\n{}/{} soft assertions failed.\n",
+            self.failures.len(),
+            self.num_checks
+        );
+        for (idx, (label, message)) in self.failures.iter().enumerate() {
+            let _ = write!(report, "\n[{}] {label}:{message}", idx + 1);
+        }
+        Some(report)
+    }
+
+    /// Finishes the soft assertions.
+    ///
+    /// # Panics
+    ///
+    /// If any of the recorded comparisons failed. In this case, the consolidated report (see
+    /// [`SoftAssert::report`]) is printed.
+    pub fn finish(mut self) {
+        let report = self.report();
+        self.failures.clear();
+        if let Some(report) = report {
+            panic!("{report}");
+        }
+    }
+
+    /// Soft counterpart of [`assert_equal`](crate::assert_equal).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `a` - The first floating-point number to compare.
+    /// * `b` - The second floating-point number to compare against.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn equal<T>(&mut self, label: impl Into<String>, a: T, b: T) -> &mut Self
+    where
+        T: Compare + Copy + Debug,
+    {
+        self.record(label, crate::check_equal!(a, b))
+    }
+
+    /// Soft counterpart of [`assert_equal_to_decimal`](crate::assert_equal_to_decimal).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `a` - The first floating-point number to compare.
+    /// * `b` - The second floating-point number to compare against.
+    /// * `decimal` - Decimal precision to use for comparison.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn decimal<T>(&mut self, label: impl Into<String>, a: T, b: T, decimal: i32) -> &mut Self
+    where
+        T: Compare + Copy + Debug,
+    {
+        self.record(label, crate::check_equal_to_decimal!(a, b, decimal))
+    }
+
+    /// Soft counterpart of [`assert_equal_to_sigfigs`](crate::assert_equal_to_sigfigs).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `a` - The first floating-point number to compare.
+    /// * `b` - The second floating-point number to compare against.
+    /// * `sigfigs` - Number of significant figures to use for comparison.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn sigfigs<T>(&mut self, label: impl Into<String>, a: T, b: T, sigfigs: u32) -> &mut Self
+    where
+        T: Compare + Copy + Debug,
+    {
+        self.record(label, crate::check_equal_to_sigfigs!(a, b, sigfigs))
+    }
+
+    /// Soft counterpart of [`assert_equal_to_atol`](crate::assert_equal_to_atol).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `a` - The first floating-point number to compare.
+    /// * `b` - The second floating-point number to compare against.
+    /// * `atol` - Absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn atol<T>(&mut self, label: impl Into<String>, a: T, b: T, atol: T::Real) -> &mut Self
+    where
+        T: Compare + Copy + Debug,
+        T::Real: Display,
+    {
+        self.record(label, crate::check_equal_to_atol!(a, b, atol))
+    }
+
+    /// Soft counterpart of [`assert_equal_to_rtol`](crate::assert_equal_to_rtol).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `a` - The first floating-point number to compare.
+    /// * `b` - The second floating-point number to compare against.
+    /// * `rtol` - Relative tolerance.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn rtol<T>(&mut self, label: impl Into<String>, a: T, b: T, rtol: T::Real) -> &mut Self
+    where
+        T: Compare + Copy + Debug,
+        T::Real: Display,
+    {
+        self.record(label, crate::check_equal_to_rtol!(a, b, rtol))
+    }
+
+    /// Soft counterpart of [`assert_equal_to_ulps`](crate::assert_equal_to_ulps).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `a` - The first floating-point number to compare.
+    /// * `b` - The second floating-point number to compare against.
+    /// * `max_ulps` - Maximum number of ULPs between the two floats.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn ulps<T>(&mut self, label: impl Into<String>, a: T, b: T, max_ulps: u64) -> &mut Self
+    where
+        T: Compare + Copy + Debug,
+    {
+        self.record(label, crate::check_equal_to_ulps!(a, b, max_ulps))
+    }
+
+    /// Soft counterpart of [`assert_close`](crate::assert_close).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `a` - The first floating-point number to compare.
+    /// * `b` - The second floating-point number to compare against.
+    /// * `rtol` - Relative tolerance.
+    /// * `atol` - Absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn close<T>(
+        &mut self,
+        label: impl Into<String>,
+        a: T,
+        b: T,
+        rtol: T::Real,
+        atol: T::Real,
+    ) -> &mut Self
+    where
+        T: Compare + Copy + Debug,
+        T::Real: Display,
+    {
+        self.record(label, crate::check_close!(a, b, rtol, atol))
+    }
+
+    /// Soft counterpart of [`assert_less_than`](crate::assert_less_than).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `a` - The floating-point number to compare.
+    /// * `bound` - Upper bound.
+    /// * `atol` - Absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn less_than<T>(
+        &mut self,
+        label: impl Into<String>,
+        a: T,
+        bound: T,
+        atol: T::Real,
+    ) -> &mut Self
+    where
+        T: Compare + Copy + Debug,
+        T::Real: Display,
+    {
+        self.record(label, crate::check_less_than!(a, bound, atol))
+    }
+
+    /// Soft counterpart of [`assert_greater_than`](crate::assert_greater_than).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `a` - The floating-point number to compare.
+    /// * `bound` - Lower bound.
+    /// * `atol` - Absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn greater_than<T>(
+        &mut self,
+        label: impl Into<String>,
+        a: T,
+        bound: T,
+        atol: T::Real,
+    ) -> &mut Self
+    where
+        T: Compare + Copy + Debug,
+        T::Real: Display,
+    {
+        self.record(label, crate::check_greater_than!(a, bound, atol))
+    }
+
+    /// Soft counterpart of [`assert_in_range`](crate::assert_in_range).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `a` - The floating-point number to compare.
+    /// * `lower` - Lower bound of the interval.
+    /// * `upper` - Upper bound of the interval.
+    /// * `atol` - Absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn in_range<T>(
+        &mut self,
+        label: impl Into<String>,
+        a: T,
+        lower: T,
+        upper: T,
+        atol: T::Real,
+    ) -> &mut Self
+    where
+        T: Compare + Copy + Debug,
+        T::Real: Display,
+    {
+        self.record(label, crate::check_in_range!(a, lower, upper, atol))
+    }
+
+    /// Soft counterpart of [`assert_arrays_equal`](crate::assert_arrays_equal).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `arr1` - First array-like struct to compare (iterated over by reference).
+    /// * `arr2` - Second array-like struct to compare (iterated over by reference).
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn arrays_equal<'a, T, A1, A2>(
+        &mut self,
+        label: impl Into<String>,
+        arr1: A1,
+        arr2: A2,
+    ) -> &mut Self
+    where
+        T: Compare + Copy + LowerExp + 'a,
+        A1: IntoIterator<Item = &'a T>,
+        A2: IntoIterator<Item = &'a T>,
+    {
+        let (vec1, vec2) = collect_elements(arr1, arr2);
+        self.record(
+            label,
+            crate::check_arrays_equal!(vec1.as_slice(), vec2.as_slice()),
+        )
+    }
+
+    /// Soft counterpart of [`assert_arrays_equal_to_decimal`](crate::assert_arrays_equal_to_decimal).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `arr1` - First array-like struct to compare (iterated over by reference).
+    /// * `arr2` - Second array-like struct to compare (iterated over by reference).
+    /// * `decimal` - Decimal precision to use for comparison.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn arrays_decimal<'a, T, A1, A2>(
+        &mut self,
+        label: impl Into<String>,
+        arr1: A1,
+        arr2: A2,
+        decimal: i32,
+    ) -> &mut Self
+    where
+        T: Compare + Copy + LowerExp + 'a,
+        A1: IntoIterator<Item = &'a T>,
+        A2: IntoIterator<Item = &'a T>,
+    {
+        let (vec1, vec2) = collect_elements(arr1, arr2);
+        self.record(
+            label,
+            crate::check_arrays_equal_to_decimal!(vec1.as_slice(), vec2.as_slice(), decimal),
+        )
+    }
+
+    /// Soft counterpart of [`assert_arrays_equal_to_sigfigs`](crate::assert_arrays_equal_to_sigfigs).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `arr1` - First array-like struct to compare (iterated over by reference).
+    /// * `arr2` - Second array-like struct to compare (iterated over by reference).
+    /// * `sigfigs` - Number of significant figures to use for comparison.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn arrays_sigfigs<'a, T, A1, A2>(
+        &mut self,
+        label: impl Into<String>,
+        arr1: A1,
+        arr2: A2,
+        sigfigs: u32,
+    ) -> &mut Self
+    where
+        T: Compare + Copy + LowerExp + 'a,
+        A1: IntoIterator<Item = &'a T>,
+        A2: IntoIterator<Item = &'a T>,
+    {
+        let (vec1, vec2) = collect_elements(arr1, arr2);
+        self.record(
+            label,
+            crate::check_arrays_equal_to_sigfigs!(vec1.as_slice(), vec2.as_slice(), sigfigs),
+        )
+    }
+
+    /// Soft counterpart of [`assert_arrays_equal_to_atol`](crate::assert_arrays_equal_to_atol).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `arr1` - First array-like struct to compare (iterated over by reference).
+    /// * `arr2` - Second array-like struct to compare (iterated over by reference).
+    /// * `atol` - Absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn arrays_atol<'a, T, A1, A2>(
+        &mut self,
+        label: impl Into<String>,
+        arr1: A1,
+        arr2: A2,
+        atol: T::Real,
+    ) -> &mut Self
+    where
+        T: Compare + Copy + LowerExp + 'a,
+        T::Real: Display,
+        A1: IntoIterator<Item = &'a T>,
+        A2: IntoIterator<Item = &'a T>,
+    {
+        let (vec1, vec2) = collect_elements(arr1, arr2);
+        self.record(
+            label,
+            crate::check_arrays_equal_to_atol!(vec1.as_slice(), vec2.as_slice(), atol),
+        )
+    }
+
+    /// Soft counterpart of [`assert_arrays_equal_to_rtol`](crate::assert_arrays_equal_to_rtol).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `arr1` - First array-like struct to compare (iterated over by reference).
+    /// * `arr2` - Second array-like struct to compare (iterated over by reference).
+    /// * `rtol` - Relative tolerance.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn arrays_rtol<'a, T, A1, A2>(
+        &mut self,
+        label: impl Into<String>,
+        arr1: A1,
+        arr2: A2,
+        rtol: T::Real,
+    ) -> &mut Self
+    where
+        T: Compare + Copy + LowerExp + 'a,
+        T::Real: Display,
+        A1: IntoIterator<Item = &'a T>,
+        A2: IntoIterator<Item = &'a T>,
+    {
+        let (vec1, vec2) = collect_elements(arr1, arr2);
+        self.record(
+            label,
+            crate::check_arrays_equal_to_rtol!(vec1.as_slice(), vec2.as_slice(), rtol),
+        )
+    }
+
+    /// Soft counterpart of [`assert_arrays_equal_to_ulps`](crate::assert_arrays_equal_to_ulps).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `arr1` - First array-like struct to compare (iterated over by reference).
+    /// * `arr2` - Second array-like struct to compare (iterated over by reference).
+    /// * `max_ulps` - Maximum number of ULPs between each pair of elements.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn arrays_ulps<'a, T, A1, A2>(
+        &mut self,
+        label: impl Into<String>,
+        arr1: A1,
+        arr2: A2,
+        max_ulps: u64,
+    ) -> &mut Self
+    where
+        T: Compare + Copy + LowerExp + 'a,
+        A1: IntoIterator<Item = &'a T>,
+        A2: IntoIterator<Item = &'a T>,
+    {
+        let (vec1, vec2) = collect_elements(arr1, arr2);
+        self.record(
+            label,
+            crate::check_arrays_equal_to_ulps!(vec1.as_slice(), vec2.as_slice(), max_ulps),
+        )
+    }
+
+    /// Soft counterpart of [`assert_arrays_close`](crate::assert_arrays_close).
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label identifying the comparison in the consolidated report.
+    /// * `arr1` - First array-like struct to compare (iterated over by reference).
+    /// * `arr2` - Second array-like struct to compare (iterated over by reference).
+    /// * `rtol` - Relative tolerance.
+    /// * `atol` - Absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// The collector (for chaining).
+    pub fn arrays_close<'a, T, A1, A2>(
+        &mut self,
+        label: impl Into<String>,
+        arr1: A1,
+        arr2: A2,
+        rtol: T::Real,
+        atol: T::Real,
+    ) -> &mut Self
+    where
+        T: Compare + Copy + LowerExp + 'a,
+        T::Real: Display,
+        A1: IntoIterator<Item = &'a T>,
+        A2: IntoIterator<Item = &'a T>,
+    {
+        let (vec1, vec2) = collect_elements(arr1, arr2);
+        self.record(
+            label,
+            crate::check_arrays_close!(vec1.as_slice(), vec2.as_slice(), rtol, atol),
+        )
+    }
+}
+
+impl Drop for SoftAssert {
+    fn drop(&mut self) {
+        if !std::thread::panicking()
+            && let Some(report) = self.report()
+        {
+            panic!("{report}");
+        }
+    }
+}
+
+/// Collects the elements of two array-like structs.
+///
+/// # Arguments
+///
+/// * `arr1` - First array-like struct (iterated over by reference).
+/// * `arr2` - Second array-like struct (iterated over by reference).
+///
+/// # Returns
+///
+/// Elements of the two array-like structs.
+fn collect_elements<'a, T, A1, A2>(arr1: A1, arr2: A2) -> (Vec<T>, Vec<T>)
+where
+    T: Copy + 'a,
+    A1: IntoIterator<Item = &'a T>,
+    A2: IntoIterator<Item = &'a T>,
+{
+    (
+        arr1.into_iter().copied().collect(),
+        arr2.into_iter().copied().collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorChoice, with_color_choice};
+
+    #[test]
+    fn soft_assert_pass() {
+        let vec = vec![1.0, 2.0, 3.0];
+        let mut soft = SoftAssert::new();
+        soft.equal("equal", 1.0, 1.0)
+            .decimal("decimal", 1.0, 1.01, 1)
+            .sigfigs("sigfigs", 6.02214076e23, 6.022e23, 4)
+            .atol("atol", 1.0, 1.001, 0.01)
+            .rtol("rtol", 100.0, 100.5, 0.01)
+            .ulps("ulps", 1.0, 1.0 + f64::EPSILON, 1)
+            .close("close", 1e-10, 0.0, 1e-5, 1e-8)
+            .arrays_equal("arrays_equal", &vec, &[1.0, 2.0, 3.0])
+            .arrays_decimal("arrays_decimal", &vec, &[1.0, 2.01, 3.0], 1)
+            .arrays_sigfigs("arrays_sigfigs", &vec, &[1.0, 2.001, 3.0], 3)
+            .arrays_atol("arrays_atol", &vec, &[1.0, 2.01, 3.0], 0.1)
+            .arrays_rtol("arrays_rtol", &vec, &[1.0, 2.01, 3.0], 0.01)
+            .arrays_ulps("arrays_ulps", &vec, &vec, 0)
+            .arrays_close("arrays_close", &vec, &vec, 0.0, 0.0);
+        assert_eq!(soft.num_failures(), 0);
+        assert_eq!(soft.report(), None);
+        soft.finish();
+    }
+
+    #[test]
+    fn soft_assert_report() {
+        let mut soft = SoftAssert::new();
+        soft.atol("first", 1.0, 2.0, 0.5)
+            .equal("second", 1.0, 1.0)
+            .arrays_atol("third", &[1.0, 2.0], &[1.0, 2.5], 0.1)
+            .record("fourth", crate::check_arrays_equal!(&[1.0], &[1.0, 2.0]));
+        assert_eq!(soft.num_failures(), 3);
+        let report = soft.report().unwrap();
+        assert!(report.starts_with("\n3/4 soft assertions failed.\n\n[1] first:\nValues are not"));
+        assert!(report.contains("\n[2] third:\nThe two array-like structs are not equal"));
+        assert!(report.contains("\n[3] fourth:\nThe two arrays must have the same number"));
+
+        // Finishing with failures panics.
+        let result = std::panic::catch_unwind(move || soft.finish());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "\n2/2 soft assertions failed.\n\n[1] energy:\nValues are not equal to within a relative tolerance of 0.001. They ARE equal to within a relative tolerance of 0.009900990099009901.\n --> a: 100.0\n --> b: 101.0\n\n[2] position:\nThe two array-like structs are not equal to an absolute tolerance of 0.01.\n --> Mismatched Elements: 1/2\n --> Largest Absolute Difference: 0.10000000000000009\n\nindex  arr1   arr2  abs diff  rel diff  result\n-----  ----  -----  --------  --------  ------\n    0   1e0    1e0         0         0    pass\n\u{1b}[31;1m    1   2e0  2.1e0      1e-1  4.762e-2    FAIL\u{1b}[0m\n"
+    )]
+    fn soft_assert_panics_on_finish() {
+        with_color_choice(ColorChoice::Always, || {
+            let mut soft = SoftAssert::new();
+            soft.rtol("energy", 100.0, 101.0, 1e-3).arrays_atol(
+                "position",
+                &vec![1.0, 2.0],
+                &vec![1.0, 2.1],
+                1e-2,
+            );
+            soft.finish();
+        });
+    }
+
+    #[test]
+    fn soft_assert_less_than() {
+        let mut soft = SoftAssert::new();
+        soft.less_than("pass", 1e-9, 1e-8, 0.0)
+            .less_than("fail", 1.5, 1.0, 0.1);
+        assert_eq!(soft.num_failures(), 1);
+        assert!(soft.report().unwrap().contains(
+            "\n[1] fail:\nValue is not less than or equal to the upper bound to within an absolute \
+            tolerance of 0.1."
+        ));
+        soft.failures.clear();
+    }
+
+    #[test]
+    fn soft_assert_greater_than() {
+        let mut soft = SoftAssert::new();
+        soft.greater_than("pass", 1.0, 1.0 + 1e-12, 1e-9)
+            .greater_than("fail", 0.5, 1.0, 0.1);
+        assert_eq!(soft.num_failures(), 1);
+        assert!(soft.report().unwrap().contains(
+            "\n[1] fail:\nValue is not greater than or equal to the lower bound to within an \
+            absolute tolerance of 0.1."
+        ));
+        soft.failures.clear();
+    }
+
+    #[test]
+    fn soft_assert_in_range() {
+        let mut soft = SoftAssert::new();
+        soft.in_range("pass", 1.0 + 1e-12, 0.0, 1.0, 1e-9)
+            .in_range("fail", -0.25, 0.0, 1.0, 1e-9);
+        assert_eq!(soft.num_failures(), 1);
+        assert!(soft.report().unwrap().contains(
+            "\n[1] fail:\nValue is not in the range [0.0, 1.0] to within an absolute tolerance of \
+            0.000000001."
+        ));
+        soft.failures.clear();
+    }
+
+    #[test]
+    #[should_panic(expected = "1/1 soft assertions failed.")]
+    fn soft_assert_panics_on_drop() {
+        let mut soft = SoftAssert::new();
+        soft.equal("value", 1.0, 2.0);
+    }
+}
@@ -0,0 +1,616 @@
+use crate::approx::Tolerance;
+use crate::report::{ComparisonError, ComparisonReport, compare_elements_to_tolerance};
+use crate::shape::{ArrayShape, ShapedElements};
+use std::fmt::Display;
+use std::path::Path;
+
+/// Error describing a reference table that could not be loaded.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TableError {
+    /// The file could not be read.
+    Io(std::io::Error),
+
+    /// A field could not be parsed as a number.
+    InvalidValue {
+        /// Line number of the field (starting at 1).
+        line: usize,
+        /// Contents of the field.
+        field: String,
+    },
+
+    /// A row does not have the same number of columns as the first row (or the header).
+    RaggedRow {
+        /// Line number of the row (starting at 1).
+        line: usize,
+        /// Number of columns of the first row (or the header).
+        expected: usize,
+        /// Number of columns of the row.
+        found: usize,
+    },
+
+    /// The table does not have a column with the requested name.
+    MissingColumn {
+        /// Name of the requested column.
+        name: String,
+        /// Names of the columns of the table (empty if the table has no header).
+        available: Vec<String>,
+    },
+}
+
+impl Display for TableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableError::Io(error) => write!(f, "failed to read file: {error}"),
+            TableError::InvalidValue { line, field } => {
+                write!(f, "invalid number on line {line}: '{field}'")
+            }
+            TableError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} has {found} columns, but the table has {expected} columns"
+            ),
+            TableError::MissingColumn { name, available } if available.is_empty() => {
+                write!(f, "column '{name}' not found (the table has no header)")
+            }
+            TableError::MissingColumn { name, available } => write!(
+                f,
+                "column '{name}' not found (available columns: {})",
+                available.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TableError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TableError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for TableError {
+    fn from(error: std::io::Error) -> Self {
+        TableError::Io(error)
+    }
+}
+
+/// Numeric table loaded from a CSV or whitespace-delimited text file.
+///
+/// This struct implements [`ArrayShape`](crate::ArrayShape) (with shape `[rows, columns]`) and has
+/// an `iter` method (iterating over the elements in row-major order), so a whole table can be passed directly to the
+/// array assertion macros. Single columns can be extracted with [`ReferenceTable::column`] and
+/// [`ReferenceTable::column_at`].
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let table = parse_table("t, x\n0.0, 1.0\n0.5, 1.0D-01\n").unwrap();
+/// assert_eq!(table.shape(), vec![2, 2]);
+/// assert_arrays_equal!(&table.column("x").unwrap(), &[1.0, 0.1]);
+/// assert_arrays_equal!(&table, &[[0.0, 1.0], [0.5, 0.1]]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceTable {
+    /// Column names (`None` if the table has no header).
+    headers: Option<Vec<String>>,
+
+    /// Rows of the table.
+    rows: Vec<Vec<f64>>,
+}
+
+impl ReferenceTable {
+    /// Column names of the table.
+    ///
+    /// # Returns
+    ///
+    /// Column names (`None` if the table has no header).
+    #[must_use]
+    pub fn headers(&self) -> Option<&[String]> {
+        self.headers.as_deref()
+    }
+
+    /// Rows of the table.
+    ///
+    /// # Returns
+    ///
+    /// Rows of the table.
+    #[must_use]
+    pub fn rows(&self) -> &[Vec<f64>] {
+        &self.rows
+    }
+
+    /// Iterates over the elements of the table, in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// Iterator over the elements of the table.
+    pub fn iter(&self) -> impl Iterator<Item = &f64> {
+        self.rows.iter().flatten()
+    }
+
+    /// Extracts a column of the table by its position.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the column (starting at 0).
+    ///
+    /// # Returns
+    ///
+    /// Elements of the column (`None` if the table does not have this many columns).
+    #[must_use]
+    pub fn column_at(&self, index: usize) -> Option<Vec<f64>> {
+        self.rows
+            .iter()
+            .map(|row| row.get(index).copied())
+            .collect()
+    }
+
+    /// Extracts a column of the table by its name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the column (as written in the header).
+    ///
+    /// # Returns
+    ///
+    /// Elements of the column.
+    ///
+    /// # Errors
+    ///
+    /// If the table has no header, or no column with this name.
+    pub fn column(&self, name: &str) -> Result<Vec<f64>, TableError> {
+        self.headers
+            .iter()
+            .flatten()
+            .position(|header| header == name)
+            .and_then(|index| self.column_at(index))
+            .ok_or_else(|| TableError::MissingColumn {
+                name: name.to_string(),
+                available: self.headers.clone().unwrap_or_default(),
+            })
+    }
+}
+
+// Implementing ArrayShape trait for reference tables.
+impl ArrayShape for ReferenceTable {
+    type Element = f64;
+    fn shape(&self) -> Vec<usize> {
+        self.rows.shape()
+    }
+    fn is_ragged(&self) -> bool {
+        self.rows.is_ragged()
+    }
+    fn elements(&self) -> impl Iterator<Item = &f64> {
+        self.iter()
+    }
+}
+
+/// Removes a trailing comment from a line.
+///
+/// A comment starts with a `#` or `%` at the start of the line or after whitespace, so that these
+/// characters can still appear within column names (e.g. `error (%)`).
+///
+/// # Arguments
+///
+/// * `line` - Line of the table.
+///
+/// # Returns
+///
+/// Line without the comment.
+fn strip_comment(line: &str) -> &str {
+    line.char_indices()
+        .find(|&(start, c)| {
+            matches!(c, '#' | '%')
+                && line[..start]
+                    .chars()
+                    .next_back()
+                    .is_none_or(char::is_whitespace)
+        })
+        .map_or(line, |(start, _)| &line[..start])
+}
+
+/// Splits a line of the table into its fields.
+///
+/// # Arguments
+///
+/// * `line` - Line of the table (without comments).
+/// * `delimiter` - Delimiter of the table (`None` for whitespace-delimited tables).
+///
+/// # Returns
+///
+/// Trimmed fields of the line, without enclosing quotes.
+fn split_fields(line: &str, delimiter: Option<char>) -> Vec<&str> {
+    fn unquote(field: &str) -> &str {
+        let field = field.trim();
+        field
+            .strip_prefix('"')
+            .and_then(|field| field.strip_suffix('"'))
+            .unwrap_or(field)
+    }
+    match delimiter {
+        Some(delimiter) => line.split(delimiter).map(unquote).collect(),
+        None => line.split_whitespace().map(unquote).collect(),
+    }
+}
+
+/// Parses a field of the table as a number.
+///
+/// In addition to the formats accepted by [`f64::from_str`](std::str::FromStr), this accepts
+/// Fortran-style exponents (e.g. `1.0D+03`) and any capitalization of `NaN`, `Inf`, and
+/// `Infinity`. Empty fields (i.e. missing values) are parsed as `NaN`.
+///
+/// # Arguments
+///
+/// * `field` - Field of the table.
+///
+/// # Returns
+///
+/// Parsed number (`None` if the field is not a number).
+fn parse_value(field: &str) -> Option<f64> {
+    if field.is_empty() {
+        return Some(f64::NAN);
+    }
+    match field.to_ascii_lowercase().as_str() {
+        "nan" | "+nan" | "-nan" => Some(f64::NAN),
+        "inf" | "+inf" | "infinity" | "+infinity" => Some(f64::INFINITY),
+        "-inf" | "-infinity" => Some(f64::NEG_INFINITY),
+        field => field.replace('d', "e").parse().ok(),
+    }
+}
+
+/// Parses a numeric table from CSV or whitespace-delimited text.
+///
+/// * The delimiter is detected from the first line that is not blank or a comment: `,` if it
+///   contains a comma, `;` if it contains a semicolon, and any whitespace otherwise.
+/// * Comments start with `#` or `%` (at the start of a line or after whitespace) and extend to the
+///   end of the line. Blank lines are ignored.
+/// * If any field of the first line is not a number, that line is treated as the header (holding
+///   the column names). Column names may be enclosed in double quotes.
+/// * Numbers may use Fortran-style exponents (e.g. `1.0D+03`), and `NaN`, `Inf`, and `-Inf` are
+///   accepted in any capitalization. Empty fields of delimited tables are parsed as `NaN`.
+///
+/// # Arguments
+///
+/// * `text` - Contents of the table.
+///
+/// # Returns
+///
+/// Parsed table.
+///
+/// # Errors
+///
+/// If a field (other than in the header) is not a number, or if the rows do not all have the same
+/// number of columns.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let text = "% exported from MATLAB\n  1.0D+00   NaN\n  2.5D-01  -Inf\n";
+/// let table = parse_table(text).unwrap();
+/// assert_eq!(table.headers(), None);
+/// assert_eq!(table.column_at(0), Some(vec![1.0, 0.25]));
+/// assert_eq!(table.rows()[1][1], f64::NEG_INFINITY);
+/// ```
+pub fn parse_table(text: &str) -> Result<ReferenceTable, TableError> {
+    let mut delimiter = None;
+    let mut headers: Option<Vec<String>> = None;
+    let mut rows: Vec<Vec<f64>> = Vec::new();
+    for (line_number, line) in text.lines().enumerate().map(|(i, line)| (i + 1, line)) {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        let delimiter = *delimiter.get_or_insert_with(|| {
+            [',', ';']
+                .into_iter()
+                .find(|&delimiter| line.contains(delimiter))
+        });
+        let fields = split_fields(line, delimiter);
+
+        // The first line is the header if it contains anything other than numbers.
+        if headers.is_none()
+            && rows.is_empty()
+            && fields.iter().any(|field| parse_value(field).is_none())
+        {
+            headers = Some(fields.into_iter().map(str::to_string).collect());
+            continue;
+        }
+
+        // Parse a row of numbers.
+        let row = fields
+            .into_iter()
+            .map(|field| {
+                parse_value(field).ok_or_else(|| TableError::InvalidValue {
+                    line: line_number,
+                    field: field.to_string(),
+                })
+            })
+            .collect::<Result<Vec<f64>, TableError>>()?;
+        let expected = headers
+            .as_ref()
+            .map_or_else(|| rows.first().map_or(row.len(), Vec::len), Vec::len);
+        if row.len() != expected {
+            return Err(TableError::RaggedRow {
+                line: line_number,
+                expected,
+                found: row.len(),
+            });
+        }
+        rows.push(row);
+    }
+    Ok(ReferenceTable { headers, rows })
+}
+
+/// Reads a numeric table from a CSV or whitespace-delimited text file (see [`parse_table`] for
+/// the accepted format).
+///
+/// # Arguments
+///
+/// * `path` - Path of the file.
+///
+/// # Returns
+///
+/// Parsed table.
+///
+/// # Errors
+///
+/// If the file cannot be read, if a field (other than in the header) is not a number, or if the
+/// rows do not all have the same number of columns.
+///
+/// # Example
+///
+/// ```no_run
+/// use numtest::*;
+///
+/// let table = read_table("tests/data/trajectory.csv").unwrap();
+/// let x = table.column("x").unwrap();
+/// ```
+pub fn read_table(path: impl AsRef<Path>) -> Result<ReferenceTable, TableError> {
+    parse_table(&std::fs::read_to_string(path)?)
+}
+
+/// Compares an array-like struct against a named column of a reference table (see
+/// [`check_column_matches`](crate::check_column_matches)).
+///
+/// # Arguments
+///
+/// * `path` - Path of the reference table.
+/// * `name` - Name of the column.
+/// * `actual` - Elements of the array-like struct (see
+///   [`shaped_elements`](crate::shaped_elements)).
+/// * `tolerance` - Tolerance.
+///
+/// # Returns
+///
+/// Report summarizing the comparison.
+///
+/// # Errors
+///
+/// If the reference table cannot be read or does not have the column, if the array-like struct
+/// does not have the same number of elements as the column, or if any of the element-wise
+/// comparisons fail.
+#[doc(hidden)]
+pub fn check_reference_column(
+    path: &Path,
+    name: &str,
+    actual: ShapedElements<f64>,
+    tolerance: Tolerance,
+) -> Result<ComparisonReport, ComparisonError<f64>> {
+    let column = read_table(path)
+        .and_then(|table| table.column(name))
+        .map_err(|error| ComparisonError::reference_file(path, error.to_string()))?;
+    let reference = ShapedElements {
+        layout: None,
+        elements: column,
+        ragged: false,
+    };
+    compare_elements_to_tolerance(
+        actual,
+        reference,
+        tolerance,
+        ("computed", "reference"),
+        |num_mismatched, count, worst_line| {
+            format!(
+                "\nThe array-like struct does not match column '{name}' of the reference table \
+                ({tolerance}).\n --> Reference Table: {}\n --> Mismatched Elements: \
+                {num_mismatched}/{count}\n{worst_line}",
+                path.display()
+            )
+        },
+    )
+}
+
+/// Checks that an array-like struct matches a named column of a reference table.
+///
+/// This macro is the non-panicking counterpart of
+/// [`assert_column_matches`](crate::assert_column_matches).
+///
+/// # Arguments
+///
+/// * `arr` - Array-like struct of [`f64`]s to compare. Must either implement the [`Iterator`]
+///   trait or have an `iter` method.
+/// * `path` - Path of the reference table (see [`read_table`](crate::read_table)).
+/// * `column` - Name of the column.
+/// * `kind = tol` - Tolerance, where `kind` is one of `atol`, `rtol`, `decimal`, or `ulps` (see
+///   [`Tolerance`](crate::Tolerance)).
+///
+/// # Returns
+///
+/// [`ComparisonReport`](crate::ComparisonReport) if the array-like struct matches the column,
+/// otherwise a [`ComparisonError`](crate::ComparisonError) (with the column as the second
+/// array-like struct).
+///
+/// # Example
+///
+/// ```no_run
+/// use numtest::*;
+///
+/// let x = vec![1.0, 0.5, 0.25];
+/// let report = check_column_matches!(&x, "tests/data/decay.csv", "x", rtol = 1e-8).unwrap();
+/// assert_eq!(report.num_elements, 3);
+/// ```
+#[macro_export]
+macro_rules! check_column_matches {
+    ($arr:expr, $path:expr, $column:expr, $kind:ident = $tol:expr) => {
+        $crate::check_reference_column(
+            ::std::path::Path::new(&$path),
+            $column,
+            $crate::shaped_elements!($arr),
+            $crate::__tolerance!($kind, $tol),
+        )
+    };
+}
+
+/// Asserts that an array-like struct matches a named column of a reference table.
+///
+/// The reference table is read with [`read_table`](crate::read_table), so it can be a CSV or
+/// whitespace-delimited text file (e.g. exported from MATLAB or a spreadsheet), and it must have a
+/// header holding the column names. Relative paths are resolved against the current directory,
+/// which is the directory containing the `Cargo.toml` of the crate being tested when running
+/// `cargo test`.
+///
+/// # Arguments
+///
+/// * `arr` - Array-like struct of [`f64`]s to compare. Must either implement the [`Iterator`]
+///   trait or have an `iter` method.
+/// * `path` - Path of the reference table.
+/// * `column` - Name of the column.
+/// * `kind = tol` - Tolerance, where `kind` is one of `atol`, `rtol`, `decimal`, or `ulps` (see
+///   [`Tolerance`](crate::Tolerance)).
+/// * `...` - Optional custom message, with [`format!`]-style arguments, printed above the
+///   failure report.
+///
+/// # Panics
+///
+/// * If the reference table cannot be read, or does not have the column. In this case, the path
+///   of the reference table and the problem are also printed.
+/// * If the array-like struct does not have the same number of elements as the column. In this
+///   case, both numbers of elements are also printed.
+/// * If any of the element-wise comparisons fail. In this case, the array-like struct and the
+///   column will be printed side by side, with the mismatched elements shown in bolded red.
+///
+/// # Example
+///
+/// ```no_run
+/// use numtest::*;
+///
+/// let t = [0.0, 1.0, 2.0];
+/// let x: Vec<f64> = t.iter().map(|t: &f64| (-t).exp()).collect();
+/// assert_column_matches!(&x, "tests/data/decay.csv", "x", rtol = 1e-8);
+/// ```
+#[macro_export]
+macro_rules! assert_column_matches {
+    ($arr:expr, $path:expr, $column:expr, $kind:ident = $tol:expr) => {{
+        if let Err(error) = $crate::check_column_matches!($arr, $path, $column, $kind = $tol) {
+            panic!("{}", error);
+        }
+    }};
+    ($arr:expr, $path:expr, $column:expr, $kind:ident = $tol:expr, $($arg:tt)+) => {{
+        if let Err(error) = $crate::check_column_matches!($arr, $path, $column, $kind = $tol) {
+            panic!("{}{}", format_args!($($arg)+), error);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn parse_table_csv() {
+        let text =
+            "# reference results\n\"t\",\"x\",\"v\"\n0.0,1.0,0.0\n\n0.5,1.0D-01,-Inf\n1.0,,nan\n";
+        let table = parse_table(text).unwrap();
+        assert_eq!(table.headers().unwrap(), &["t", "x", "v"]);
+        assert_eq!(table.shape(), vec![3, 3]);
+        assert_eq!(table.column_at(0), Some(vec![0.0, 0.5, 1.0]));
+        let x = table.column("x").unwrap();
+        assert_eq!(x[..2], [1.0, 0.1]);
+        assert!(x[2].is_nan());
+        let v = table.column("v").unwrap();
+        assert_eq!(v[1], f64::NEG_INFINITY);
+        assert!(v[2].is_nan());
+        assert_eq!(table.column_at(3), None);
+    }
+
+    #[test]
+    fn parse_table_whitespace() {
+        let text = "%  time      position\n   time      position\n  0.0  1.0E+03 % start\n\t1.0\t-2.5d+02\n";
+        let table = parse_table(text).unwrap();
+        assert_eq!(table.headers().unwrap(), &["time", "position"]);
+        assert_arrays_equal!(&table, &[[0.0, 1000.0], [1.0, -250.0]]);
+    }
+
+    #[test]
+    fn parse_table_comment_markers() {
+        assert_eq!(strip_comment("# comment"), "");
+        assert_eq!(strip_comment("1.0, 2.0 % comment"), "1.0, 2.0 ");
+        assert_eq!(strip_comment("t, error (%)"), "t, error (%)");
+        assert_eq!(strip_comment("id#, x\t# comment"), "id#, x\t");
+        let table = parse_table("t, error (%) # percent\n0.0, 1.5\n1.0, 2.5 # last\n").unwrap();
+        assert_eq!(table.headers().unwrap(), &["t", "error (%)"]);
+        assert_eq!(table.column("error (%)").unwrap(), vec![1.5, 2.5]);
+    }
+
+    #[test]
+    fn parse_table_semicolon() {
+        let table = parse_table("a; b\n1; 2\n3; 4\n").unwrap();
+        assert_eq!(table.column("b").unwrap(), vec![2.0, 4.0]);
+    }
+
+    #[test]
+    fn parse_table_errors() {
+        let error = parse_table("x, y\n1.0, 2.0\n3.0, abc\n").unwrap_err();
+        assert_eq!(error.to_string(), "invalid number on line 3: 'abc'");
+        let error = parse_table("1.0 2.0\n3.0\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2 has 1 columns, but the table has 2 columns"
+        );
+        let error = parse_table("x, y\n1.0, 2.0\n")
+            .unwrap()
+            .column("z")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "column 'z' not found (available columns: x, y)"
+        );
+        let error = parse_table("1.0 2.0\n").unwrap().column("x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "column 'x' not found (the table has no header)"
+        );
+    }
+
+    #[test]
+    fn check_reference_column_file() {
+        let path = std::env::temp_dir().join(format!("numtest-table-{}.csv", std::process::id()));
+        std::fs::write(&path, "t,x\n0,1.0\n1,0.36787944117144233\n").unwrap();
+
+        assert_column_matches!(&[1.0, (-1.0_f64).exp()], &path, "x", rtol = 1e-12);
+        let error = check_column_matches!(&[1.0, 0.37], &path, "x", atol = 1e-3).unwrap_err();
+        assert_eq!(error.mismatched_indices(), vec![1]);
+        assert!(error.message.starts_with(
+            "\nThe array-like struct does not match column 'x' of the reference table (atol = \
+            0.001).\n"
+        ));
+        assert!(error.table.contains("computed"));
+        let error = check_column_matches!(&[1.0, 0.37], &path, "y", atol = 1e-3).unwrap_err();
+        assert!(matches!(
+            *error.kind,
+            ComparisonErrorKind::ReferenceFile { .. }
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
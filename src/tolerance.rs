@@ -0,0 +1,217 @@
+use crate::shape::{ArrayShape, ShapedElements, ViaArrayShape};
+
+/// Trait for per-element tolerances, used by the array assertion macros that accept a separate
+/// tolerance for each pair of elements (e.g.
+/// [`assert_arrays_equal_to_atols`](crate::assert_arrays_equal_to_atols)).
+///
+/// This trait is implemented for:
+///
+/// * closures `Fn(usize) -> T`, which are called with the flat position of each pair of elements
+///   (in row-major order if the two array-like structs iterate over their elements in different
+///   orders, and in the order in which they are iterated over otherwise)
+/// * slices `[T]` (and therefore `std` arrays and vectors)
+/// * `[[T; N]; M]` and `Vec<Vec<T>>` (flattened in row-major order)
+/// * [`ndarray::ArrayBase`](https://docs.rs/ndarray/latest/ndarray/struct.ArrayBase.html)
+///   (requires the `ndarray` feature, flattened in row-major order)
+/// * [`nalgebra::Matrix`](https://docs.rs/nalgebra/latest/nalgebra/base/struct.Matrix.html)
+///   (requires the `nalgebra` feature, flattened in column-major order)
+///
+/// Tolerances that implement [`ArrayShape`](crate::ArrayShape) must have the same shape as the
+/// array-like structs being compared (when their shapes are known), and are matched to their
+/// elements by their multi-dimensional indices, regardless of the order in which each of them
+/// iterates over its elements.
+///
+/// # Example
+///
+/// ```
+/// use numtest::*;
+///
+/// let atols = [1e-3, 1e-6];
+/// assert_eq!(atols.tolerances(2), vec![1e-3, 1e-6]);
+///
+/// let atol = |idx: usize| if idx == 0 { 1e-3 } else { 1e-6 };
+/// assert_eq!(atol.tolerances(2), vec![1e-3, 1e-6]);
+/// ```
+pub trait Tolerances<T> {
+    /// Collects the tolerance for each pair of elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_elements` - Number of pairs of elements being compared.
+    ///
+    /// # Returns
+    ///
+    /// Tolerance for each pair of elements. For array-like tolerances, this is simply every
+    /// element, so it may not have `num_elements` elements (in which case the comparison fails).
+    fn tolerances(&self, num_elements: usize) -> Vec<T>;
+}
+
+// Implementing Tolerances trait for closures.
+impl<T, F> Tolerances<T> for F
+where
+    F: Fn(usize) -> T,
+{
+    fn tolerances(&self, num_elements: usize) -> Vec<T> {
+        (0..num_elements).map(self).collect()
+    }
+}
+
+// Implementing Tolerances trait for slices (and, through unsizing, for std arrays and vectors).
+impl<T: Copy> Tolerances<T> for [T] {
+    fn tolerances(&self, _num_elements: usize) -> Vec<T> {
+        self.to_vec()
+    }
+}
+
+// Implementing Tolerances trait for nested std arrays.
+impl<T: Copy, const N: usize, const M: usize> Tolerances<T> for [[T; N]; M] {
+    fn tolerances(&self, _num_elements: usize) -> Vec<T> {
+        self.iter().flatten().copied().collect()
+    }
+}
+
+// Implementing Tolerances trait for nested std vectors.
+impl<T: Copy> Tolerances<T> for Vec<Vec<T>> {
+    fn tolerances(&self, _num_elements: usize) -> Vec<T> {
+        self.iter().flatten().copied().collect()
+    }
+}
+
+// Implementing Tolerances trait for ndarray arrays.
+#[cfg(feature = "ndarray")]
+impl<S, D> Tolerances<S::Elem> for ndarray::ArrayBase<S, D>
+where
+    S: ndarray::Data,
+    S::Elem: Copy,
+    D: ndarray::Dimension,
+{
+    fn tolerances(&self, _num_elements: usize) -> Vec<S::Elem> {
+        self.iter().copied().collect()
+    }
+}
+
+// Implementing Tolerances trait for nalgebra matrices.
+#[cfg(feature = "nalgebra")]
+impl<T, R, C, S> Tolerances<T> for nalgebra::Matrix<T, R, C, S>
+where
+    T: nalgebra::Scalar + Copy,
+    R: nalgebra::Dim,
+    C: nalgebra::Dim,
+    S: nalgebra::RawStorage<T, R, C>,
+{
+    fn tolerances(&self, _num_elements: usize) -> Vec<T> {
+        self.iter().copied().collect()
+    }
+}
+
+/// Wrapper used by the array assertion macros to collect per-element tolerances, using
+/// [`ArrayShape`] if it is implemented and [`Tolerances`] otherwise.
+///
+/// This is separate from [`ShapeProbe`](crate::ShapeProbe) so that the fallbacks for elements and
+/// tolerances never apply to the same wrapper.
+#[doc(hidden)]
+pub struct ToleranceProbe<'a, A: ?Sized>(pub &'a A);
+
+impl<A> ViaArrayShape<A::Element> for &ToleranceProbe<'_, A>
+where
+    A: ArrayShape + ?Sized,
+    A::Element: Copy,
+{
+    fn numtest_elements<F, I>(&self, _fallback: F) -> ShapedElements<A::Element>
+    where
+        F: FnOnce() -> I,
+    {
+        ShapedElements::from_array_shape(self.0)
+    }
+}
+
+/// Collects per-element tolerances using [`Tolerances`] (fallback for tolerances that do not
+/// implement [`ArrayShape`](crate::ArrayShape), selected by autoref-based specialization).
+#[doc(hidden)]
+pub trait ViaTolerances<T> {
+    /// Collects the per-element tolerances.
+    ///
+    /// # Arguments
+    ///
+    /// * `fallback` - Closure returning the tolerances (see [`Tolerances::tolerances`]).
+    ///
+    /// # Returns
+    ///
+    /// Per-element tolerances (without a layout).
+    fn numtest_elements<F>(&self, fallback: F) -> ShapedElements<T>
+    where
+        F: FnOnce() -> Vec<T>;
+}
+
+impl<A: ?Sized, T> ViaTolerances<T> for ToleranceProbe<'_, A> {
+    fn numtest_elements<F>(&self, fallback: F) -> ShapedElements<T>
+    where
+        F: FnOnce() -> Vec<T>,
+    {
+        ShapedElements {
+            layout: None,
+            elements: fallback(),
+            ragged: false,
+        }
+    }
+}
+
+/// Collects per-element tolerances (along with their layout, if they implement
+/// [`ArrayShape`](crate::ArrayShape)).
+///
+/// # Arguments
+///
+/// * `tolerances` - Per-element tolerances (see [`Tolerances`]).
+/// * `num_elements` - Number of pairs of elements being compared.
+///
+/// # Returns
+///
+/// [`ShapedElements`] holding the tolerances.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! shaped_tolerances {
+    ($tolerances:expr, $num_elements:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::{Tolerances as _, ViaArrayShape as _, ViaTolerances as _};
+        let tolerances = &$tolerances;
+        let num_elements = $num_elements;
+        (&&$crate::ToleranceProbe(tolerances))
+            .numtest_elements(|| tolerances.tolerances(num_elements))
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tolerances_closure() {
+        let rtol = |idx: usize| if idx == 0 { 0.1 } else { 0.2 };
+        assert_eq!(rtol.tolerances(3), vec![0.1, 0.2, 0.2]);
+    }
+
+    #[test]
+    fn tolerances_std() {
+        assert_eq!([1.0, 2.0].tolerances(5), vec![1.0, 2.0]);
+        assert_eq!([1.0_f32][..].tolerances(1), vec![1.0_f32]);
+        assert_eq!(
+            [[1.0, 2.0], [3.0, 4.0]].tolerances(4),
+            vec![1.0, 2.0, 3.0, 4.0]
+        );
+        assert_eq!(vec![vec![1.0], vec![2.0]].tolerances(2), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "ndarray")]
+    fn tolerances_ndarray() {
+        let atols = ndarray::array![[1.0, 2.0], [3.0, 4.0]];
+        assert_eq!(atols.tolerances(4), vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "nalgebra")]
+    fn tolerances_nalgebra() {
+        let atols = nalgebra::Matrix2::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(atols.tolerances(4), vec![1.0, 3.0, 2.0, 4.0]);
+    }
+}